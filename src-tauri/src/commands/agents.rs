@@ -0,0 +1,234 @@
+// Agent CLI profiles
+// Interactive launches, embedded terminals and headless runs all start the agent through a
+// profile: the one chosen for the project in .vibe/agent.json, else the default from
+// settings, else Claude Code. Custom profiles take precedence over built-ins with the same name.
+
+use std::fs;
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::commands::settings::read_settings_file;
+use crate::commands::spawn;
+use crate::models::{AgentProfile, ProjectAgentConfig, Settings};
+
+const DEFAULT_PROFILE: &str = "claude";
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+/// Profiles that are always available
+pub(crate) fn builtin_profiles() -> Vec<AgentProfile> {
+    // A multi-line prompt can't go on a Windows command line, so claude reads it from a file there
+    let (interactive_args, prompt_mode) = if cfg!(target_os = "windows") {
+        (args(&["@{prompt_file}"]), "file")
+    } else {
+        (args(&["{prompt}"]), "argument")
+    };
+
+    vec![AgentProfile {
+        name: DEFAULT_PROFILE.to_string(),
+        program: "claude".to_string(),
+        args: interactive_args,
+        prompt_mode: prompt_mode.to_string(),
+        headless_args: args(&["-p", "--permission-mode", "acceptEdits"]),
+        headless_prompt_mode: "stdin".to_string(),
+        env: Default::default(),
+    }]
+}
+
+/// The profile named for the project, or in settings, or the built-in default
+pub(crate) fn resolve_profile(settings: &Settings, project_profile: Option<&str>) -> Result<AgentProfile, String> {
+    let name = project_profile
+        .or(settings.agent_profile.as_deref())
+        .filter(|name| !name.is_empty())
+        .unwrap_or(DEFAULT_PROFILE);
+
+    settings
+        .agent_profiles
+        .iter()
+        .chain(builtin_profiles().iter())
+        .find(|p| p.name == name)
+        .cloned()
+        .ok_or_else(|| format!("Agent profile '{}' not found", name))
+}
+
+/// A resolved agent invocation, ready to run without a shell
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AgentCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub stdin: Option<String>, // Prompt to pipe in
+}
+
+impl AgentCommand {
+    /// With the program resolved to a full path, so .cmd shims can be spawned directly on Windows
+    pub(crate) fn spawnable(mut self) -> Self {
+        self.program = spawn::resolve_program(&self.program);
+        self
+    }
+}
+
+/// Fill in a profile's arguments for `prompt`, writing it to `prompt_file` if the profile
+/// reads it from a file. Arguments that mention the prompt are dropped when it's empty.
+pub(crate) fn build_command(profile: &AgentProfile, prompt: &str, headless: bool, prompt_file: &Path) -> Result<AgentCommand, String> {
+    let (template, mode) = if headless {
+        (&profile.headless_args, profile.headless_prompt_mode.as_str())
+    } else {
+        (&profile.args, profile.prompt_mode.as_str())
+    };
+
+    let (placeholder, value) = match mode {
+        "argument" => ("{prompt}", prompt.to_string()),
+        "file" => ("{prompt_file}", prompt_file.to_string_lossy().to_string()),
+        "stdin" => ("", String::new()),
+        other => return Err(format!("Unknown prompt mode '{}' in agent profile '{}'", other, profile.name)),
+    };
+
+    let mentions_prompt = |arg: &String| arg.contains("{prompt}") || arg.contains("{prompt_file}");
+    let args: Vec<String> = if prompt.is_empty() {
+        template.iter().filter(|arg| !mentions_prompt(arg)).cloned().collect()
+    } else if placeholder.is_empty() {
+        template.clone()
+    } else if template.iter().any(|arg| arg.contains(placeholder)) {
+        template.iter().map(|arg| arg.replace(placeholder, &value)).collect()
+    } else {
+        template.iter().cloned().chain(std::iter::once(value)).collect()
+    };
+
+    if mode == "file" && !prompt.is_empty() {
+        fs::write(prompt_file, prompt)
+            .map_err(|e| format!("Failed to write prompt file: {}", e))?;
+    }
+
+    Ok(AgentCommand {
+        program: profile.program.clone(),
+        args,
+        env: profile.env.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        stdin: (mode == "stdin" && !prompt.is_empty()).then(|| prompt.to_string()),
+    })
+}
+
+fn config_path(project_path: &Path) -> std::path::PathBuf {
+    project_path.join(".vibe").join("agent.json")
+}
+
+/// The profile chosen for a project, if any
+pub(crate) fn read_project_profile(project_path: &Path) -> Result<Option<String>, String> {
+    let path = config_path(project_path);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read agent config: {}", e))?;
+    let config: ProjectAgentConfig = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse agent config: {}", e))?;
+    Ok(config.profile.filter(|name| !name.is_empty()))
+}
+
+/// The command to start a project's agent with
+pub(crate) fn project_agent_command(
+    app: &AppHandle,
+    project_path: &str,
+    prompt: &str,
+    headless: bool,
+    prompt_file: &Path,
+) -> Result<AgentCommand, String> {
+    let settings = read_settings_file(app)?;
+    let project_profile = read_project_profile(Path::new(project_path))?;
+    let profile = resolve_profile(&settings, project_profile.as_deref())?;
+    build_command(&profile, prompt, headless, prompt_file)
+}
+
+/// Agent profiles that can be chosen: built-ins, then custom ones
+#[tauri::command]
+pub async fn get_agent_profiles(app: AppHandle) -> Result<Vec<AgentProfile>, String> {
+    let settings = read_settings_file(&app)?;
+    let mut profiles = builtin_profiles();
+    profiles.retain(|p| !settings.agent_profiles.iter().any(|custom| custom.name == p.name));
+    profiles.extend(settings.agent_profiles);
+    Ok(profiles)
+}
+
+#[tauri::command]
+pub async fn get_project_agent_profile(project_path: String) -> Result<Option<String>, String> {
+    read_project_profile(Path::new(&project_path))
+}
+
+/// Choose the project's agent profile, or go back to the default from settings with None
+#[tauri::command]
+pub async fn set_project_agent_profile(project_path: String, profile: Option<String>) -> Result<(), String> {
+    let path = config_path(Path::new(&project_path));
+    let Some(profile) = profile.filter(|name| !name.is_empty()) else {
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| format!("Failed to remove agent config: {}", e))?;
+        }
+        return Ok(());
+    };
+
+    fs::create_dir_all(Path::new(&project_path).join(".vibe"))
+        .map_err(|e| format!("Failed to create .vibe directory: {}", e))?;
+    let content = serde_json::to_string_pretty(&ProjectAgentConfig { profile: Some(profile) })
+        .map_err(|e| format!("Failed to serialize agent config: {}", e))?;
+    fs::write(path, content)
+        .map_err(|e| format!("Failed to write agent config: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapper_profile() -> AgentProfile {
+        AgentProfile {
+            name: "wrapper".to_string(),
+            program: "/opt/agents/run.sh".to_string(),
+            args: args(&["--verbose", "--task={prompt}"]),
+            prompt_mode: "argument".to_string(),
+            headless_args: args(&["--batch"]),
+            headless_prompt_mode: "file".to_string(),
+            env: [("AGENT_MODEL".to_string(), "large".to_string())].into(),
+        }
+    }
+
+    #[test]
+    fn test_prompt_is_passed_the_way_the_profile_says() {
+        let dir = std::env::temp_dir().join(format!("vibe-agents-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let prompt_file = dir.join("prompt.txt");
+        let profile = wrapper_profile();
+
+        let interactive = build_command(&profile, "Fix 'it'", false, &prompt_file).unwrap();
+        assert_eq!(interactive.args, vec!["--verbose", "--task=Fix 'it'"]);
+        assert_eq!(interactive.env, vec![("AGENT_MODEL".to_string(), "large".to_string())]);
+        assert_eq!(interactive.stdin, None);
+
+        // Without a placeholder the prompt file is appended
+        let headless = build_command(&profile, "Fix it", true, &prompt_file).unwrap();
+        assert_eq!(headless.args, vec!["--batch".to_string(), prompt_file.to_string_lossy().to_string()]);
+        assert_eq!(fs::read_to_string(&prompt_file).unwrap(), "Fix it");
+
+        let empty = build_command(&profile, "", false, &prompt_file).unwrap();
+        assert_eq!(empty.args, vec!["--verbose"]);
+
+        let claude = resolve_profile(&Settings::default(), None).unwrap();
+        let piped = build_command(&claude, "Fix it", true, &prompt_file).unwrap();
+        assert_eq!(piped.stdin.as_deref(), Some("Fix it"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_project_profile_overrides_settings() {
+        let mut settings = Settings::default();
+        settings.agent_profiles.push(wrapper_profile());
+        assert_eq!(resolve_profile(&settings, None).unwrap().name, "claude");
+
+        settings.agent_profile = Some("wrapper".to_string());
+        assert_eq!(resolve_profile(&settings, None).unwrap().name, "wrapper");
+        assert_eq!(resolve_profile(&settings, Some("claude")).unwrap().program, "claude");
+        assert!(resolve_profile(&settings, Some("missing")).is_err());
+    }
+}
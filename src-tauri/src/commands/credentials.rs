@@ -0,0 +1,564 @@
+// Credential storage for tracker tokens
+// Tokens live in the OS keyring when one is available, otherwise in a file encrypted with a key
+// derived from a user passphrase. settings.json never contains them and they are never sent to
+// the webview; commands only report whether a token is set.
+
+use argon2::Argon2;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use lazy_static::lazy_static;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::models::{CredentialStatus, Settings};
+
+const KEYRING_SERVICE: &str = "vibe-hub";
+const STORE_FILE: &str = "credentials.enc.json";
+const GITHUB_ACCOUNT: &str = "github";
+const LOCKED_ERROR: &str = "The credential store is locked. Enter your passphrase in Settings to unlock it.";
+
+/// Credential store account for a tracker host's token
+fn tracker_account(host: &str) -> String {
+    format!("tracker:{}", host.trim().to_lowercase())
+}
+
+trait SecretStore {
+    fn get(&self, account: &str) -> Result<Option<String>, String>;
+    fn set(&self, account: &str, secret: &str) -> Result<(), String>;
+    fn delete(&self, account: &str) -> Result<(), String>;
+}
+
+/// The platform keyring (Keychain, Credential Manager or Secret Service)
+struct KeyringStore;
+
+impl KeyringStore {
+    fn entry(account: &str) -> Result<keyring::Entry, String> {
+        keyring::Entry::new(KEYRING_SERVICE, account)
+            .map_err(|e| format!("Failed to open keyring entry: {}", e))
+    }
+
+    /// Whether a keyring service is reachable on this machine
+    fn is_available() -> bool {
+        let Ok(entry) = Self::entry("probe") else {
+            return false;
+        };
+        matches!(blocking(|| entry.get_password()), Ok(_) | Err(keyring::Error::NoEntry))
+    }
+}
+
+impl SecretStore for KeyringStore {
+    fn get(&self, account: &str) -> Result<Option<String>, String> {
+        let entry = Self::entry(account)?;
+        match blocking(|| entry.get_password()) {
+            Ok(secret) => Ok(Some(secret)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(format!("Failed to read from keyring: {}", e)),
+        }
+    }
+
+    fn set(&self, account: &str, secret: &str) -> Result<(), String> {
+        let entry = Self::entry(account)?;
+        blocking(|| entry.set_password(secret))
+            .map_err(|e| format!("Failed to write to keyring: {}", e))
+    }
+
+    fn delete(&self, account: &str) -> Result<(), String> {
+        let entry = Self::entry(account)?;
+        match blocking(|| entry.delete_credential()) {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(format!("Failed to delete from keyring: {}", e)),
+        }
+    }
+}
+
+/// Keyring calls may wait on D-Bus, so keep them off the async worker when possible
+fn blocking<T>(f: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(f)
+        }
+        _ => f(),
+    }
+}
+
+/// On-disk format of the encrypted store
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedFile {
+    version: u8,
+    salt: String,       // Base64 Argon2 salt
+    nonce: String,      // Base64 ChaCha20-Poly1305 nonce, fresh for every write
+    ciphertext: String, // Base64 JSON map of account -> secret
+}
+
+/// A passphrase-encrypted file holding every secret, unlocked with its derived key
+#[derive(Clone)]
+struct FileStore {
+    path: PathBuf,
+    salt: Vec<u8>,
+    key: [u8; 32],
+}
+
+fn encode(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, String> {
+    base64::engine::general_purpose::STANDARD
+        .decode(value)
+        .map_err(|e| format!("Corrupt credential store: {}", e))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive encryption key: {}", e))?;
+    Ok(key)
+}
+
+impl FileStore {
+    /// Unlock the store at `path`, creating it if this is the first passphrase
+    fn open(path: &Path, passphrase: &str) -> Result<Self, String> {
+        if passphrase.is_empty() {
+            return Err("Passphrase cannot be empty".to_string());
+        }
+
+        if !path.exists() {
+            let mut salt = vec![0u8; 16];
+            rand::rngs::OsRng.fill_bytes(&mut salt);
+            let store = Self { path: path.to_path_buf(), key: derive_key(passphrase, &salt)?, salt };
+            store.save(&BTreeMap::new())?;
+            return Ok(store);
+        }
+
+        let file = Self::read_file(path)?;
+        let salt = decode(&file.salt)?;
+        let store = Self { path: path.to_path_buf(), key: derive_key(passphrase, &salt)?, salt };
+        // Decrypting checks the passphrase
+        store.load()?;
+        Ok(store)
+    }
+
+    fn read_file(path: &Path) -> Result<EncryptedFile, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read credential store: {}", e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse credential store: {}", e))
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, String>, String> {
+        let file = Self::read_file(&self.path)?;
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.key));
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&decode(&file.nonce)?), decode(&file.ciphertext)?.as_slice())
+            .map_err(|_| "Incorrect passphrase".to_string())?;
+
+        serde_json::from_slice(&plaintext)
+            .map_err(|e| format!("Failed to parse credential store: {}", e))
+    }
+
+    fn save(&self, secrets: &BTreeMap<String, String>) -> Result<(), String> {
+        let plaintext = serde_json::to_vec(secrets)
+            .map_err(|e| format!("Failed to serialize credentials: {}", e))?;
+
+        let mut nonce = [0u8; 12];
+        rand::rngs::OsRng.fill_bytes(&mut nonce);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.key));
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| "Failed to encrypt credentials".to_string())?;
+
+        let file = EncryptedFile {
+            version: 1,
+            salt: encode(&self.salt),
+            nonce: encode(&nonce),
+            ciphertext: encode(&ciphertext),
+        };
+        let content = serde_json::to_string_pretty(&file)
+            .map_err(|e| format!("Failed to serialize credential store: {}", e))?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+        fs::write(&self.path, content)
+            .map_err(|e| format!("Failed to write credential store: {}", e))
+    }
+}
+
+impl SecretStore for FileStore {
+    fn get(&self, account: &str) -> Result<Option<String>, String> {
+        Ok(self.load()?.remove(account))
+    }
+
+    fn set(&self, account: &str, secret: &str) -> Result<(), String> {
+        let mut secrets = self.load()?;
+        secrets.insert(account.to_string(), secret.to_string());
+        self.save(&secrets)
+    }
+
+    fn delete(&self, account: &str) -> Result<(), String> {
+        let mut secrets = self.load()?;
+        if secrets.remove(account).is_some() {
+            self.save(&secrets)?;
+        }
+        Ok(())
+    }
+}
+
+lazy_static! {
+    // Probed once per run; None until the first credential access
+    static ref KEYRING_AVAILABLE: Mutex<Option<bool>> = Mutex::new(None);
+    // The encrypted file store once unlocked with its passphrase
+    static ref UNLOCKED_FILE: Mutex<Option<FileStore>> = Mutex::new(None);
+}
+
+fn keyring_available() -> bool {
+    let mut available = KEYRING_AVAILABLE.lock().unwrap();
+    *available.get_or_insert_with(KeyringStore::is_available)
+}
+
+fn config_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))
+}
+
+/// The store to use right now, or None while the encrypted file is locked
+fn active_store() -> Option<Box<dyn SecretStore>> {
+    if keyring_available() {
+        return Some(Box::new(KeyringStore));
+    }
+    UNLOCKED_FILE
+        .lock()
+        .unwrap()
+        .clone()
+        .map(|store| Box::new(store) as Box<dyn SecretStore>)
+}
+
+fn require_store() -> Result<Box<dyn SecretStore>, String> {
+    active_store().ok_or_else(|| LOCKED_ERROR.to_string())
+}
+
+/// Move tokens into the store and return settings.json without them
+///
+/// Works on the raw JSON so unknown fields survive. Returns None if there was nothing to move.
+fn extract_plaintext_tokens(
+    store: &dyn SecretStore,
+    mut settings: serde_json::Value,
+) -> Result<Option<serde_json::Value>, String> {
+    let mut moved = false;
+
+    if let Some(token) = settings.as_object_mut().and_then(|s| s.remove("githubToken")) {
+        if let Some(token) = token.as_str().filter(|t| !t.is_empty()) {
+            store.set(GITHUB_ACCOUNT, token)?;
+        }
+        moved = true;
+    }
+
+    if let Some(hosts) = settings.get_mut("trackerHosts").and_then(|h| h.as_array_mut()) {
+        for host in hosts.iter_mut().filter_map(|h| h.as_object_mut()) {
+            let name = host.get("host").and_then(|h| h.as_str()).unwrap_or_default().to_string();
+            if let Some(token) = host.remove("token") {
+                if let Some(token) = token.as_str().filter(|t| !t.is_empty()) {
+                    store.set(&tracker_account(&name), token)?;
+                }
+                moved = true;
+            }
+        }
+    }
+
+    Ok(moved.then_some(settings))
+}
+
+fn migrate_settings_file(store: &dyn SecretStore, settings_path: &Path) -> Result<bool, String> {
+    let Ok(content) = fs::read_to_string(settings_path) else {
+        return Ok(false);
+    };
+    let settings: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse settings: {}", e))?;
+
+    match extract_plaintext_tokens(store, settings)? {
+        Some(stripped) => {
+            let json = serde_json::to_string_pretty(&stripped)
+                .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+            fs::write(settings_path, json)
+                .map_err(|e| format!("Failed to write settings: {}", e))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Move plaintext tokens left in settings.json by older versions into the credential store
+///
+/// Does nothing while the encrypted file store is locked; it runs again once it is unlocked.
+pub(crate) fn migrate_plaintext_tokens(app: &AppHandle) -> Result<bool, String> {
+    let Some(store) = active_store() else {
+        return Ok(false);
+    };
+    migrate_settings_file(store.as_ref(), &config_dir(app)?.join("settings.json"))
+}
+
+/// Fill in the tokens of settings read from disk
+///
+/// Plaintext tokens that couldn't be migrated yet are kept as they are.
+pub(crate) fn load_tokens(settings: &mut Settings) -> Result<(), String> {
+    let Some(store) = active_store() else {
+        return Ok(());
+    };
+
+    if let Some(token) = store.get(GITHUB_ACCOUNT)? {
+        settings.github_token = Some(token);
+    }
+    for host in settings.tracker_hosts.iter_mut() {
+        if let Some(token) = store.get(&tracker_account(&host.host))? {
+            host.token = Some(token);
+        }
+    }
+    Ok(())
+}
+
+/// Save any tokens included in settings sent from the frontend
+fn store_tokens(store: Option<&dyn SecretStore>, settings: &Settings) -> Result<(), String> {
+    let tokens: Vec<(String, &str)> = settings
+        .github_token
+        .iter()
+        .map(|t| (GITHUB_ACCOUNT.to_string(), t.as_str()))
+        .chain(settings.tracker_hosts.iter().filter_map(|h| {
+            h.token.as_deref().map(|t| (tracker_account(&h.host), t))
+        }))
+        .filter(|(_, token)| !token.is_empty())
+        .collect();
+
+    if tokens.is_empty() {
+        return Ok(());
+    }
+    let store = store.ok_or_else(|| LOCKED_ERROR.to_string())?;
+    for (account, token) in tokens {
+        store.set(&account, token)?;
+    }
+    Ok(())
+}
+
+/// A plaintext token in an unmigrated settings.json: a tracker host's, or GitHub's with None
+fn plaintext_token<'a>(existing: &'a serde_json::Value, host: Option<&str>) -> Option<&'a serde_json::Value> {
+    match host {
+        None => existing.get("githubToken"),
+        Some(host) => existing
+            .get("trackerHosts")?
+            .as_array()?
+            .iter()
+            .find(|old| old.get("host").and_then(|h| h.as_str()) == Some(host))?
+            .get("token"),
+    }
+}
+
+/// Copy the plaintext tokens of an unmigrated settings.json into settings about to replace it
+fn keep_plaintext_tokens(existing: &serde_json::Value, settings: &mut serde_json::Value) {
+    if let (Some(token), Some(settings)) = (plaintext_token(existing, None), settings.as_object_mut()) {
+        settings.insert("githubToken".to_string(), token.clone());
+    }
+
+    if let Some(hosts) = settings.get_mut("trackerHosts").and_then(|h| h.as_array_mut()) {
+        for host in hosts.iter_mut().filter_map(|h| h.as_object_mut()) {
+            let name = host.get("host").and_then(|h| h.as_str()).map(str::to_string);
+            if let Some(token) = name.and_then(|name| plaintext_token(existing, Some(&name))) {
+                host.insert("token".to_string(), token.clone());
+            }
+        }
+    }
+}
+
+/// Write settings.json, with its tokens going to the store
+///
+/// Plaintext tokens still in the file are migrated first. While the store is locked they can't
+/// be, and as serializing Settings leaves tokens out they are carried over into the new file.
+fn write_settings_file(store: Option<&dyn SecretStore>, settings_path: &Path, settings: &Settings) -> Result<(), String> {
+    let unmigrated = match store {
+        Some(store) => {
+            migrate_settings_file(store, settings_path)?;
+            None
+        }
+        None => fs::read_to_string(settings_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok()),
+    };
+
+    // Plaintext tokens sent back as they were loaded are carried over below, not new ones
+    let mut new_tokens = settings.clone();
+    if let Some(existing) = &unmigrated {
+        let unchanged = |token: &Option<String>, host: Option<&str>| {
+            token.is_some() && plaintext_token(existing, host).and_then(|t| t.as_str()) == token.as_deref()
+        };
+        if unchanged(&new_tokens.github_token, None) {
+            new_tokens.github_token = None;
+        }
+        for host in new_tokens.tracker_hosts.iter_mut() {
+            if unchanged(&host.token, Some(&host.host)) {
+                host.token = None;
+            }
+        }
+    }
+    store_tokens(store, &new_tokens)?;
+
+    let mut json = serde_json::to_value(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    if let Some(existing) = unmigrated {
+        keep_plaintext_tokens(&existing, &mut json);
+    }
+
+    if let Some(parent) = settings_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(&json)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    fs::write(settings_path, content)
+        .map_err(|e| format!("Failed to write settings: {}", e))
+}
+
+/// Save settings sent from the frontend
+pub(crate) fn save_settings(app: &AppHandle, settings: &Settings) -> Result<(), String> {
+    let store = active_store();
+    write_settings_file(store.as_deref(), &config_dir(app)?.join("settings.json"), settings)
+}
+
+/// Report where tokens are stored and which are set
+#[tauri::command]
+pub async fn get_credential_status(app: AppHandle) -> Result<CredentialStatus, String> {
+    let store_path = config_dir(&app)?.join(STORE_FILE);
+    let settings = crate::commands::github::read_settings(&app)?;
+
+    let tracker_token_hosts = settings
+        .tracker_hosts
+        .iter()
+        .filter(|h| h.token.is_some())
+        .map(|h| h.host.clone())
+        .collect();
+
+    Ok(CredentialStatus {
+        backend: if keyring_available() { "keyring" } else { "encrypted-file" }.to_string(),
+        locked: active_store().is_none(),
+        has_encrypted_file: store_path.exists(),
+        github_token_set: settings.github_token.is_some(),
+        tracker_token_hosts,
+    })
+}
+
+/// Unlock the encrypted credential store (creating it on first use) and migrate plaintext tokens
+#[tauri::command]
+pub async fn unlock_credentials(app: AppHandle, passphrase: String) -> Result<CredentialStatus, String> {
+    if !keyring_available() {
+        let store = FileStore::open(&config_dir(&app)?.join(STORE_FILE), &passphrase)?;
+        *UNLOCKED_FILE.lock().unwrap() = Some(store);
+        migrate_plaintext_tokens(&app)?;
+    }
+    get_credential_status(app).await
+}
+
+/// Set or remove a token; `host` is a tracker host, or None for the GitHub token
+#[tauri::command]
+pub async fn set_credential_token(
+    app: AppHandle,
+    host: Option<String>,
+    token: Option<String>,
+) -> Result<CredentialStatus, String> {
+    let account = host.as_deref().map(tracker_account).unwrap_or_else(|| GITHUB_ACCOUNT.to_string());
+    let store = require_store()?;
+
+    match token.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        Some(token) => store.set(&account, token)?,
+        None => store.delete(&account)?,
+    }
+    get_credential_status(app).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vibe-hub-credentials-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_file_store_round_trip_and_wrong_passphrase() {
+        let path = temp_dir().join(STORE_FILE);
+
+        let store = FileStore::open(&path, "correct horse").unwrap();
+        store.set(GITHUB_ACCOUNT, "ghp_secret").unwrap();
+        assert!(!fs::read_to_string(&path).unwrap().contains("ghp_secret"));
+
+        let reopened = FileStore::open(&path, "correct horse").unwrap();
+        assert_eq!(reopened.get(GITHUB_ACCOUNT).unwrap().as_deref(), Some("ghp_secret"));
+        reopened.delete(GITHUB_ACCOUNT).unwrap();
+        assert_eq!(reopened.get(GITHUB_ACCOUNT).unwrap(), None);
+
+        assert_eq!(FileStore::open(&path, "wrong").err().as_deref(), Some("Incorrect passphrase"));
+    }
+
+    #[test]
+    fn test_migration_moves_tokens_out_of_settings() {
+        let dir = temp_dir();
+        let store = FileStore::open(&dir.join(STORE_FILE), "pass").unwrap();
+        let settings_path = dir.join("settings.json");
+        fs::write(&settings_path, json!({
+            "projectsDirectory": "/projects",
+            "githubToken": "ghp_plain",
+            "trackerHosts": [{ "host": "GitLab.Example.com", "kind": "gitlab", "token": "glpat" }],
+        }).to_string()).unwrap();
+
+        assert!(migrate_settings_file(&store, &settings_path).unwrap());
+        assert!(!migrate_settings_file(&store, &settings_path).unwrap());
+
+        let content = fs::read_to_string(&settings_path).unwrap();
+        assert!(!content.contains("ghp_plain") && !content.contains("glpat"));
+        assert_eq!(store.get(GITHUB_ACCOUNT).unwrap().as_deref(), Some("ghp_plain"));
+        assert_eq!(store.get("tracker:gitlab.example.com").unwrap().as_deref(), Some("glpat"));
+
+        // Tokens are never written back out with the settings
+        let settings: Settings = serde_json::from_str(&content).unwrap();
+        let settings = Settings { github_token: Some("ghp_plain".to_string()), ..settings };
+        assert!(!serde_json::to_string(&settings).unwrap().contains("ghp_plain"));
+    }
+
+    #[test]
+    fn test_saving_while_locked_keeps_plaintext_tokens() {
+        let dir = temp_dir();
+        let settings_path = dir.join("settings.json");
+        fs::write(&settings_path, json!({
+            "projectsDirectory": "/projects",
+            "githubToken": "ghp_plain",
+            "trackerHosts": [{ "host": "gitlab.example.com", "kind": "gitlab", "token": "glpat" }],
+        }).to_string()).unwrap();
+
+        // Locked: the settings are saved and the tokens stay until they can be migrated
+        let mut settings: Settings = serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        settings.projects_directory = "/elsewhere".to_string();
+        write_settings_file(None, &settings_path, &settings).unwrap();
+        let content = fs::read_to_string(&settings_path).unwrap();
+        assert!(content.contains("/elsewhere") && content.contains("ghp_plain") && content.contains("glpat"));
+
+        // A new token can't be saved while locked
+        let new_token = Settings { github_token: Some("ghp_new".to_string()), ..settings.clone() };
+        assert!(write_settings_file(None, &settings_path, &new_token).is_err());
+
+        // Unlocked: migrated on the next save
+        let store = FileStore::open(&dir.join(STORE_FILE), "pass").unwrap();
+        write_settings_file(Some(&store), &settings_path, &settings).unwrap();
+        let content = fs::read_to_string(&settings_path).unwrap();
+        assert!(!content.contains("ghp_plain") && !content.contains("glpat"));
+        assert_eq!(store.get(GITHUB_ACCOUNT).unwrap().as_deref(), Some("ghp_plain"));
+        assert_eq!(store.get("tracker:gitlab.example.com").unwrap().as_deref(), Some("glpat"));
+    }
+}
@@ -0,0 +1,120 @@
+// Reading dev server output
+// Dev tools announce where they serve in similar ways ("Local: http://localhost:5173/" from
+// Vite, Next and CRA, "listening on" from Node and Rust servers), so the first local URL a
+// server prints is taken as its address. A few tool-specific lines mark readiness without one.
+
+/// Hosts that mean "this machine"; LAN addresses (Vite's "Network:" line) are skipped
+const LOCAL_HOSTS: &[&str] = &["localhost", "127.0.0.1", "0.0.0.0", "[::1]", "[::]"];
+
+/// Lowercased text that means a server without a URL of its own has finished starting
+const READY_MARKERS: &[&str] = &[
+    "compiled successfully", // CRA, webpack
+    "ready in",              // Vite, Next ("✓ Ready in 1.2s")
+    "running `target",       // cargo run
+    "watching for file changes",
+];
+
+/// Lowercased text that marks an error line
+const ERROR_MARKERS: &[&str] = &[
+    "error:",
+    "error[",
+    "[error]",
+    "npm err!",
+    "failed to compile",
+    "panicked at",
+    "uncaught",
+    "exception",
+    "eaddrinuse",
+];
+
+/// Remove ANSI escape sequences (colours, cursor movement, hyperlinks)
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            if c != '\r' {
+                out.push(c);
+            }
+            continue;
+        }
+
+        match chars.next() {
+            // CSI: parameters up to a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: up to BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' || (c == '\u{1b}' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// The local URL a line announces, with 0.0.0.0 and [::] shown as localhost
+pub(crate) fn detect_url(line: &str) -> Option<String> {
+    line.split_whitespace()
+        .filter_map(|word| word.find("http://").or_else(|| word.find("https://")).map(|start| &word[start..]))
+        .map(|url| url.trim_end_matches([',', ';', ')', '*', '"', '\'']))
+        .find_map(|url| {
+            let (scheme, rest) = url.split_once("://")?;
+            let host_end = rest.find([':', '/']).unwrap_or(rest.len());
+            // A bracketed IPv6 host contains colons of its own
+            let host_end = if rest.starts_with('[') { rest.find(']')? + 1 } else { host_end };
+            let host = &rest[..host_end];
+            if !LOCAL_HOSTS.contains(&host) {
+                return None;
+            }
+
+            let host = if host == "0.0.0.0" || host == "[::]" { "localhost" } else { host };
+            Some(format!("{}://{}{}", scheme, host, &rest[host_end..]))
+        })
+}
+
+pub(crate) fn is_ready_line(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    READY_MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+pub(crate) fn is_error_line(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    ERROR_MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_urls_and_errors_are_found_in_common_tool_output() {
+        let vite = strip_ansi("  \u{1b}[32m➜\u{1b}[39m  \u{1b}[1mLocal\u{1b}[22m:   \u{1b}[36mhttp://localhost:\u{1b}[1m5173\u{1b}[22m/\u{1b}[39m\r");
+        assert_eq!(vite, "  ➜  Local:   http://localhost:5173/");
+        assert_eq!(detect_url(&vite).as_deref(), Some("http://localhost:5173/"));
+
+        assert_eq!(detect_url("   - Local:        http://localhost:3000").as_deref(), Some("http://localhost:3000"));
+        assert_eq!(detect_url("Listening on http://0.0.0.0:8080, press Ctrl+C").as_deref(), Some("http://localhost:8080"));
+        assert_eq!(detect_url("Server running at https://[::1]:4433/api").as_deref(), Some("https://[::1]:4433/api"));
+        assert_eq!(detect_url("  ➜  Network: http://192.168.1.20:5173/"), None);
+        assert_eq!(detect_url("See https://vitejs.dev/config for options"), None);
+
+        assert!(is_ready_line("  VITE v5.0.0  ready in 312 ms"));
+        assert!(is_ready_line("     Running `target/debug/my-app`"));
+        assert!(!is_ready_line("   Compiling my-app v0.1.0"));
+
+        assert!(is_error_line("error[E0425]: cannot find value `x` in this scope"));
+        assert!(is_error_line("Error: listen EADDRINUSE: address already in use :::3000"));
+        assert!(is_error_line("npm ERR! missing script: dev"));
+        assert!(!is_error_line("0 errors, 0 warnings"));
+    }
+}
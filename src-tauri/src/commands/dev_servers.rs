@@ -0,0 +1,499 @@
+// Supervised dev servers
+// Dev servers run as children of Vibe Hub, each in a process group of its own, so stopping
+// one takes down everything it started (bundlers, watchers) and nothing else. A project has
+// at most one; starting another replaces it. "dev-server-changed" reports every state change.
+// Output is kept in a ring buffer and .vibe/dev-server.log, streamed as "dev-server-output"
+// events and scanned for the server's URL (see dev_output.rs). Servers run on the project's
+// port from the registry in ports.rs.
+
+use chrono::Utc;
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use crate::commands::npm::PackageJson;
+use crate::commands::{dev_output, ports, spawn, tasks};
+use crate::models::{DevServerInfo, DevServerLine};
+
+// How long a server gets to shut down before it is killed
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+// Output lines kept in memory per server; the log file has all of them
+const MAX_OUTPUT_LINES: usize = 1000;
+const DEFAULT_OUTPUT_LINES: usize = 200;
+const LOG_FILE: &str = "dev-server.log";
+
+struct DevServer {
+    info: DevServerInfo,
+    stopping: bool, // Set by stop so the exit is reported as "stopped"
+    output: VecDeque<DevServerLine>,
+}
+
+impl DevServer {
+    fn new(info: DevServerInfo) -> Self {
+        Self { info, stopping: false, output: VecDeque::new() }
+    }
+
+    /// Keep a line of output, returning true if it revealed the URL or readiness
+    fn add_line(&mut self, line: DevServerLine) -> bool {
+        let mut changed = false;
+        if self.info.url.is_none() {
+            if let Some(url) = dev_output::detect_url(&line.text) {
+                self.info.url = Some(url);
+                self.info.ready = true;
+                changed = true;
+            }
+        }
+        if !self.info.ready && dev_output::is_ready_line(&line.text) {
+            self.info.ready = true;
+            changed = true;
+        }
+
+        self.output.push_back(line);
+        if self.output.len() > MAX_OUTPUT_LINES {
+            self.output.pop_front();
+        }
+        changed
+    }
+}
+
+// Dev servers by project path, kept after exit so their status can still be read
+lazy_static::lazy_static! {
+    static ref DEV_SERVERS: Mutex<HashMap<String, DevServer>> = Mutex::new(HashMap::new());
+}
+
+/// The command line of a package.json script
+fn npm_script(project_path: &str, script_name: &str) -> Option<String> {
+    let content = fs::read_to_string(Path::new(project_path).join("package.json")).ok()?;
+    let package: PackageJson = serde_json::from_str(&content).ok()?;
+    package.scripts?.remove(script_name)
+}
+
+/// Program and arguments for a script, and the port it will listen on. Tools known to take
+/// `--port` are given `port` unless the script sets one itself; every server also gets PORT.
+/// Custom dev commands ("command") run through the shell and may use a `{port}` placeholder.
+fn script_command(project_path: &str, script_name: &str, script_type: &str, port: u16) -> Result<(String, Vec<String>, u16), String> {
+    let port_flag = |command: &str| match ports::explicit_port(command) {
+        Some(own) => (None, own),
+        None => (ports::accepts_port_flag(command).then(|| port.to_string()), port),
+    };
+
+    match script_type {
+        "npm" => {
+            let manager = tasks::package_manager(Path::new(project_path));
+            let mut args = vec!["run".to_string(), script_name.to_string()];
+            let (flag, port) = port_flag(&npm_script(project_path, script_name).unwrap_or_default());
+            if let Some(flag) = flag {
+                // npm needs `--` before arguments for the script; the others pass them on as is
+                if manager == "npm" {
+                    args.push("--".to_string());
+                }
+                args.extend(["--port".to_string(), flag]);
+            }
+            Ok((manager.to_string(), args, port))
+        }
+        "sh" => Ok(("bash".to_string(), vec![script_name.to_string()], port)),
+        "bat" => Ok(("cmd".to_string(), vec!["/c".to_string(), script_name.to_string()], port)),
+        "command" => {
+            // An override that just runs a package.json script is treated as that script
+            if let [manager, "run", name] = script_name.split_whitespace().collect::<Vec<_>>()[..] {
+                if tasks::PACKAGE_MANAGERS.contains(&manager) {
+                    return script_command(project_path, name, "npm", port);
+                }
+            }
+
+            let (command, port) = if script_name.contains("{port}") {
+                (script_name.replace("{port}", &port.to_string()), port)
+            } else {
+                match port_flag(script_name) {
+                    (Some(flag), port) => (format!("{} --port {}", script_name, flag), port),
+                    (None, port) => (script_name.to_string(), port),
+                }
+            };
+            let shell = if cfg!(target_os = "windows") { ("cmd", "/c") } else { ("sh", "-c") };
+            Ok((shell.0.to_string(), vec![shell.1.to_string(), command], port))
+        }
+        other => Err(format!("Unknown script type '{}'", other)),
+    }
+}
+
+/// Start a command as the leader of a new process group
+fn spawn_supervised(program: &str, args: &[String], env: &[(String, String)], cwd: &str) -> Result<Child, String> {
+    let mut command = Command::new(spawn::resolve_program(program));
+    command
+        .args(args)
+        .envs(env.iter().map(|(k, v)| (k, v)))
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    #[cfg(target_os = "windows")]
+    command.creation_flags(0x00000200 | 0x08000000); // CREATE_NEW_PROCESS_GROUP | CREATE_NO_WINDOW
+
+    command
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", program, e))
+}
+
+#[cfg(unix)]
+pub(crate) fn signal_group(pgid: u32, signal: &str) -> bool {
+    Command::new("kill")
+        .args([signal, "--", &format!("-{}", pgid)])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Stop every process in a dev server's group, killing it if it hasn't exited within `timeout`
+pub(crate) fn stop_process_group(pgid: u32, timeout: Duration) {
+    #[cfg(unix)]
+    {
+        if !signal_group(pgid, "-TERM") {
+            return;
+        }
+        let deadline = std::time::Instant::now() + timeout;
+        while std::time::Instant::now() < deadline && signal_group(pgid, "-0") {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        signal_group(pgid, "-KILL");
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Console-less processes can't be asked to exit, so the tree is ended outright
+        let _ = timeout;
+        let _ = Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &pgid.to_string()])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output();
+    }
+}
+
+fn log_path(project_path: &str) -> PathBuf {
+    Path::new(project_path).join(".vibe").join(LOG_FILE)
+}
+
+/// The project's dev server log, started afresh for each server
+fn create_log(project_path: &str) -> Option<Arc<Mutex<File>>> {
+    let path = log_path(project_path);
+    let file = path
+        .parent()
+        .map(fs::create_dir_all)
+        .transpose()
+        .and_then(|_| File::create(&path));
+    match file {
+        Ok(file) => Some(Arc::new(Mutex::new(file))),
+        Err(e) => {
+            log::warn!(project = project_path; "Failed to create dev server log: {}", e);
+            None
+        }
+    }
+}
+
+/// Record one line of a server's output and pass it on to the frontend
+fn record_line(app: &AppHandle, project_path: &str, pid: u32, stream: &str, text: String, log_file: Option<&Arc<Mutex<File>>>) {
+    if let Some(file) = log_file {
+        let _ = writeln!(file.lock().unwrap(), "[{}] {}", stream, text);
+    }
+
+    let line = DevServerLine {
+        project_path: project_path.to_string(),
+        stream: stream.to_string(),
+        error: dev_output::is_error_line(&text),
+        text,
+        timestamp: Utc::now().to_rfc3339(),
+    };
+    let changed = {
+        let mut servers = DEV_SERVERS.lock().unwrap();
+        let Some(server) = servers.get_mut(project_path).filter(|s| s.info.pid == Some(pid)) else {
+            return;
+        };
+        server.add_line(line.clone()).then(|| server.info.clone())
+    };
+
+    let _ = app.emit("dev-server-output", &line);
+    if let Some(info) = changed {
+        log::info!(project = project_path; "Dev server {} is ready at {}", pid, info.url.as_deref().unwrap_or("an unknown URL"));
+        let _ = app.emit("dev-server-changed", &info);
+    }
+}
+
+/// Read one of a server's output streams line by line until it closes
+fn capture_output(app: AppHandle, reader: impl Read, stream: &'static str, project_path: String, pid: u32, log_file: Option<Arc<Mutex<File>>>) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        // Progress bars redraw the line after a carriage return; keep what would be left on screen
+        let raw = String::from_utf8_lossy(&buf);
+        let shown = raw.trim_end_matches(['\n', '\r']).rsplit('\r').next().unwrap_or_default();
+        record_line(&app, &project_path, pid, stream, dev_output::strip_ansi(shown), log_file.as_ref());
+    }
+}
+
+/// Wait for a server's main process to exit, then clean up what it left behind and report it
+fn supervise(app: AppHandle, mut child: Child, project_path: String) {
+    let pid = child.id();
+    let exit_code = child.wait().ok().and_then(|status| status.code());
+    // Whatever the main process started goes with it, so nothing is left holding the port
+    stop_process_group(pid, Duration::ZERO);
+
+    let info = {
+        let mut servers = DEV_SERVERS.lock().unwrap();
+        let Some(server) = servers.get_mut(&project_path).filter(|s| s.info.pid == Some(pid)) else {
+            return; // Replaced by a newer server
+        };
+        server.info.status = if server.stopping { "stopped" } else { "exited" }.to_string();
+        server.info.exit_code = exit_code;
+        server.info.clone()
+    };
+
+    log::info!(project = project_path.as_str(); "Dev server {} {} (exit code {:?})", pid, info.status, exit_code);
+    let _ = app.emit("dev-server-changed", &info);
+}
+
+/// Stop a project's running dev server, if it has one
+fn stop_server(project_path: &str) {
+    let pid = {
+        let mut servers = DEV_SERVERS.lock().unwrap();
+        match servers.get_mut(project_path) {
+            Some(server) if server.info.status == "running" => {
+                server.stopping = true;
+                server.info.pid
+            }
+            _ => None,
+        }
+    };
+
+    if let Some(pid) = pid {
+        log::info!(project = project_path; "Stopping dev server {}", pid);
+        stop_process_group(pid, STOP_TIMEOUT);
+    }
+}
+
+/// The project whose running dev server was given this port
+pub(crate) fn project_on_port(port: u16) -> Option<String> {
+    DEV_SERVERS
+        .lock()
+        .unwrap()
+        .values()
+        .find(|server| server.info.status == "running" && server.info.port == Some(port))
+        .map(|server| server.info.project_path.clone())
+}
+
+/// Stop every dev server; called when the app exits
+pub fn stop_all() {
+    let projects: Vec<String> = DEV_SERVERS.lock().unwrap().keys().cloned().collect();
+    for project_path in projects {
+        stop_server(&project_path);
+    }
+}
+
+fn start_server(app: &AppHandle, project_path: &str, script_name: &str, script_type: &str) -> Result<DevServerInfo, String> {
+    if !Path::new(project_path).is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+
+    stop_server(project_path);
+
+    let (program, args, port) = script_command(project_path, script_name, script_type, ports::project_port(app, project_path)?)?;
+    ports::ensure_port_free(port)?;
+    let env = [("PORT".to_string(), port.to_string()), ("VIBE_PORT".to_string(), port.to_string())];
+    let mut child = spawn_supervised(&program, &args, &env, project_path)?;
+    let pid = child.id();
+    let info = DevServerInfo {
+        project_path: project_path.to_string(),
+        script_name: script_name.to_string(),
+        script_type: script_type.to_string(),
+        command: format!("{} {}", program, args.join(" ")),
+        port: Some(port),
+        pid: Some(child.id()),
+        status: "running".to_string(),
+        exit_code: None,
+        started_at: Utc::now().to_rfc3339(),
+        url: None,
+        ready: false,
+    };
+    log::info!(project = project_path; "Started dev server {}: {}", pid, info.command);
+
+    DEV_SERVERS.lock().unwrap().insert(project_path.to_string(), DevServer::new(info.clone()));
+    let _ = app.emit("dev-server-changed", &info);
+
+    let log_file = create_log(project_path);
+    if let Some(stdout) = child.stdout.take() {
+        let (app, project, log_file) = (app.clone(), project_path.to_string(), log_file.clone());
+        std::thread::spawn(move || capture_output(app, stdout, "stdout", project, pid, log_file));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let (app, project) = (app.clone(), project_path.to_string());
+        std::thread::spawn(move || capture_output(app, stderr, "stderr", project, pid, log_file));
+    }
+
+    let thread_app = app.clone();
+    let thread_project = project_path.to_string();
+    std::thread::spawn(move || supervise(thread_app, child, thread_project));
+
+    Ok(info)
+}
+
+/// Start a project's dev server, replacing the one it already has running. `script_type` is
+/// "npm", "sh" or "bat" for detected scripts, or "command" with the project's dev command
+#[tauri::command]
+pub async fn start_dev_server(
+    app: AppHandle,
+    project_path: String,
+    script_name: String,
+    script_type: Option<String>,
+) -> Result<DevServerInfo, String> {
+    let script_type = script_type.unwrap_or_else(|| "npm".to_string());
+    tauri::async_runtime::spawn_blocking(move || start_server(&app, &project_path, &script_name, &script_type))
+        .await
+        .map_err(|e| format!("Failed to start dev server: {}", e))?
+}
+
+#[tauri::command]
+pub async fn stop_dev_server(project_path: String) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || stop_server(&project_path))
+        .await
+        .map_err(|e| format!("Failed to stop dev server: {}", e))
+}
+
+/// Start the project's last dev server again
+#[tauri::command]
+pub async fn restart_dev_server(app: AppHandle, project_path: String) -> Result<DevServerInfo, String> {
+    let (script_name, script_type) = {
+        let servers = DEV_SERVERS.lock().unwrap();
+        let server = servers
+            .get(&project_path)
+            .ok_or_else(|| "No dev server has been started for this project".to_string())?;
+        (server.info.script_name.clone(), server.info.script_type.clone())
+    };
+    start_dev_server(app, project_path, script_name, Some(script_type)).await
+}
+
+/// The project's dev server, running or not, if one was started since the app launched
+#[tauri::command]
+pub async fn get_dev_server_status(project_path: String) -> Result<Option<DevServerInfo>, String> {
+    Ok(DEV_SERVERS.lock().unwrap().get(&project_path).map(|server| server.info.clone()))
+}
+
+/// The last `lines` lines of the project's dev server output, oldest first
+#[tauri::command]
+pub async fn get_dev_server_output(project_path: String, lines: Option<usize>) -> Result<Vec<DevServerLine>, String> {
+    let servers = DEV_SERVERS.lock().unwrap();
+    let Some(server) = servers.get(&project_path) else {
+        return Ok(Vec::new());
+    };
+    let skip = server.output.len().saturating_sub(lines.unwrap_or(DEFAULT_OUTPUT_LINES));
+    Ok(server.output.iter().skip(skip).cloned().collect())
+}
+
+#[tauri::command]
+pub async fn list_dev_servers() -> Result<Vec<DevServerInfo>, String> {
+    Ok(DEV_SERVERS.lock().unwrap().values().map(|server| server.info.clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_os = "linux")]
+    use std::time::Instant;
+
+    fn line(text: &str) -> DevServerLine {
+        DevServerLine {
+            project_path: "/p".to_string(),
+            stream: "stdout".to_string(),
+            text: text.to_string(),
+            error: false,
+            timestamp: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_output_is_bounded_and_the_first_url_is_kept() {
+        let mut server = DevServer::new(DevServerInfo {
+            project_path: "/p".to_string(),
+            script_name: "dev".to_string(),
+            script_type: "npm".to_string(),
+            command: "npm run dev".to_string(),
+            port: Some(5200),
+            pid: Some(1),
+            status: "running".to_string(),
+            exit_code: None,
+            started_at: String::new(),
+            url: None,
+            ready: false,
+        });
+
+        assert!(!server.add_line(line("> vite")));
+        assert!(server.add_line(line("  ➜  Local:   http://localhost:5174/")));
+        assert!(!server.add_line(line("  ➜  Local:   http://localhost:9999/")));
+        assert_eq!(server.info.url.as_deref(), Some("http://localhost:5174/"));
+        assert!(server.info.ready);
+
+        for i in 0..MAX_OUTPUT_LINES {
+            server.add_line(line(&format!("line {}", i)));
+        }
+        assert_eq!(server.output.len(), MAX_OUTPUT_LINES);
+        assert_eq!(server.output.front().unwrap().text, "line 0");
+    }
+
+    /// Running and not a zombie waiting to be reaped
+    #[cfg(target_os = "linux")]
+    fn running(pid: u32) -> bool {
+        fs::read_to_string(format!("/proc/{}/stat", pid))
+            .map(|stat| !stat.rsplit(')').next().unwrap_or("").trim_start().starts_with('Z'))
+            .unwrap_or(false)
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_stop_ends_the_whole_group_and_nothing_else() {
+        let dir = std::env::temp_dir().join(format!("vibe-dev-server-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let cwd = dir.to_string_lossy().to_string();
+
+        // A server that starts a worker of its own, like npm starting vite
+        let script = "sleep 60 & echo $! > worker.pid; wait".to_string();
+        let mut server = spawn_supervised("sh", &["-c".to_string(), script], &[], &cwd).unwrap();
+        let mut bystander = Command::new("sleep").arg("60").spawn().unwrap();
+
+        let worker_file = dir.join("worker.pid");
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while fs::read_to_string(&worker_file).map_or(true, |s| s.trim().is_empty()) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        let worker: u32 = fs::read_to_string(&worker_file).unwrap().trim().parse().unwrap();
+        assert!(running(worker));
+
+        // Reaped as it exits, as supervise does
+        let pgid = server.id();
+        let waiter = std::thread::spawn(move || server.wait());
+        stop_process_group(pgid, Duration::from_secs(2));
+        waiter.join().unwrap().unwrap();
+        assert!(!running(worker));
+        assert!(running(bystander.id()));
+
+        bystander.kill().unwrap();
+        bystander.wait().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::{FeedbackItem, FeedbackFile, NewFeedbackItem, UpdateFeedbackItem};
+use crate::models::{Comment, FeedbackItem, FeedbackFile, NewFeedbackItem, UpdateFeedbackItem};
 use std::fs;
 use std::path::Path;
 use uuid::Uuid;
@@ -147,6 +147,13 @@ pub async fn add_feedback(
         related_issue_id: feedback.related_issue_id,
         github_issue_number: feedback.github_issue_number,
         github_issue_url: feedback.github_issue_url,
+        updated_at: None,
+        labels: feedback.labels,
+        comments: None,
+        github_synced_at: None,
+        github_updated_at: None,
+        github_discussion_number: None,
+        github_discussion_url: None,
     };
 
     feedback_file.feedback.push(new_feedback.clone());
@@ -207,6 +214,10 @@ pub async fn update_feedback(
     if let Some(github_issue_url) = updates.github_issue_url {
         item.github_issue_url = Some(github_issue_url);
     }
+    if let Some(labels) = updates.labels {
+        item.labels = Some(labels);
+    }
+    item.updated_at = Some(chrono::Utc::now().to_rfc3339());
 
     let new_status = item.status.clone();
     let status_changed = old_status != new_status;
@@ -255,6 +266,40 @@ pub async fn delete_feedback(project_path: String, feedback_id: String) -> Resul
     Ok(())
 }
 
+/// Add a local comment to a feedback item's thread.
+/// For GitHub-linked items the comment is posted on the next sync.
+#[tauri::command]
+pub async fn add_feedback_comment(
+    project_path: String,
+    feedback_id: String,
+    body: String,
+) -> Result<Comment, String> {
+    let path = Path::new(&project_path);
+    let mut pending_file = read_pending_feedback(path)?;
+    let mut completed_file = read_completed_feedback(path)?;
+
+    let item = pending_file.feedback.iter_mut()
+        .chain(completed_file.feedback.iter_mut())
+        .find(|f| f.id == feedback_id)
+        .ok_or("Feedback item not found")?;
+
+    let comment = Comment {
+        id: Uuid::new_v4().to_string(),
+        author: "me".to_string(),
+        body,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        github_comment_id: None,
+    };
+
+    item.comments.get_or_insert_with(Vec::new).push(comment.clone());
+    item.updated_at = Some(chrono::Utc::now().to_rfc3339());
+
+    write_pending_feedback(path, &pending_file)?;
+    write_completed_feedback(path, &completed_file)?;
+
+    Ok(comment)
+}
+
 fn read_archived_feedback(project_path: &Path) -> Result<FeedbackFile, String> {
     let feedback_path = project_path.join(VIBE_DIR).join(FEEDBACK_ARCHIVE_FILE);
     read_feedback_file_from_path(&feedback_path)
@@ -296,6 +341,7 @@ pub async fn move_feedback_to_archive(
     let mut feedback_item = pending_file.feedback.remove(feedback_index);
     feedback_item.refined_into_issue_ids = Some(refined_into_issue_ids);
     feedback_item.status = "refined".to_string();
+    feedback_item.updated_at = Some(chrono::Utc::now().to_rfc3339());
 
     // Add to archive
     archive_file.feedback.push(feedback_item);
@@ -319,7 +365,7 @@ pub async fn archive_and_close_github_feedback(
     project_path: String,
     feedback_id: String,
 ) -> Result<(), String> {
-    use crate::commands::github::close_github_issue;
+    use crate::commands::github::close_remote_issue;
 
     let path = Path::new(&project_path);
     let mut pending_file = read_pending_feedback(path)?;
@@ -339,6 +385,7 @@ pub async fn archive_and_close_github_feedback(
     let mut feedback_item = pending_file.feedback.remove(feedback_index);
     feedback_item.refined_into_issue_ids = Some(vec!["already-implemented".to_string()]);
     feedback_item.status = "refined".to_string();
+    feedback_item.updated_at = Some(chrono::Utc::now().to_rfc3339());
 
     // Add to archive
     archive_file.feedback.push(feedback_item);
@@ -349,14 +396,17 @@ pub async fn archive_and_close_github_feedback(
 
     // If this feedback has a linked GitHub issue, close it
     if let (Some(issue_number), Some(issue_url)) = (github_issue_number, github_issue_url) {
-        // Extract the GitHub URL (repo URL) from the issue URL
-        // Issue URL format: https://github.com/owner/repo/issues/123
+        // Extract the repo URL from the issue URL
+        // Issue URL format: https://github.com/owner/repo/issues/123 (GitLab: .../repo/-/issues/123)
         let repo_url = issue_url.split("/issues/").next()
+            .map(|url| url.trim_end_matches("/-"))
             .ok_or("Invalid GitHub issue URL format")?;
 
-        match close_github_issue(app, repo_url.to_string(), issue_number).await {
-            Ok(_) => println!("[archive_and_close_github_feedback] Successfully closed GitHub issue #{}", issue_number),
-            Err(e) => eprintln!("[archive_and_close_github_feedback] Failed to close GitHub issue #{}: {}", issue_number, e),
+        // Failed closes are queued and retried in the background (see outbound.rs)
+        match close_remote_issue(&app, Some(path), repo_url, issue_number).await {
+            Ok(true) => log::info!(project = project_path.as_str(); "Closed GitHub issue #{}", issue_number),
+            Ok(false) => log::warn!(project = project_path.as_str(); "Queued close of GitHub issue #{} for retry", issue_number),
+            Err(e) => log::error!(project = project_path.as_str(); "Failed to queue close of GitHub issue #{}: {}", issue_number, e),
         }
     }
 
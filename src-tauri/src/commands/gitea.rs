@@ -0,0 +1,246 @@
+// Gitea issue tracker backend (also Forgejo/Codeberg, REST API v1)
+// Gitea's issue and comment payloads follow GitHub's, so they decode straight into the sync models.
+
+use async_trait::async_trait;
+use reqwest::{Client, Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::commands::github::{IssueListing, IssuePatch, RemoteComment, RemoteIssue};
+use crate::commands::tracker::{read_json, IssueTracker};
+use crate::models::TrackerKind;
+
+/// Requested page size; instances may return fewer per page
+const PAGE_LIMIT: usize = 50;
+
+/// Body of PATCH /repos/:owner/:repo/issues/:index; labels are set through a separate endpoint
+#[derive(Debug, Serialize)]
+struct GiteaIssueUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+}
+
+/// A Gitea repository accessed with an access token
+pub(crate) struct GiteaTracker {
+    client: Client,
+    api_url: String,
+    token: String,
+    owner: String,
+    repo: String,
+}
+
+impl GiteaTracker {
+    pub fn new(api_url: &str, token: &str, owner: &str, repo: &str) -> Self {
+        Self {
+            client: Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{}/repos/{}/{}{}", self.api_url, self.owner, self.repo, path))
+            .header("Authorization", format!("token {}", self.token))
+    }
+}
+
+#[async_trait]
+impl IssueTracker for GiteaTracker {
+    fn kind(&self) -> TrackerKind {
+        TrackerKind::Gitea
+    }
+
+    async fn list_issues(&self, state: &str, since: Option<&str>) -> Result<IssueListing, String> {
+        let limit = PAGE_LIMIT.to_string();
+        let mut params = vec![("state", state), ("type", "issues"), ("limit", limit.as_str())];
+        if let Some(since) = since {
+            params.push(("since", since));
+        }
+
+        let mut listing = IssueListing::default();
+        let mut page = 1u32;
+        loop {
+            let response = self
+                .request(Method::GET, "/issues")
+                .query(&params)
+                .query(&[("page", page.to_string())])
+                .send()
+                .await;
+            let batch: Vec<RemoteIssue> = read_json(response, "fetch Gitea issues. Please check your token and repository access").await?;
+            // Instances clamp `limit` to their MAX_RESPONSE_ITEMS, so a short page isn't necessarily the last
+            if batch.is_empty() {
+                break;
+            }
+
+            for issue in batch {
+                if issue.is_pull_request() {
+                    listing.pull_requests_skipped += 1;
+                } else {
+                    listing.issues.push(issue);
+                }
+            }
+
+            page += 1;
+        }
+
+        Ok(listing)
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<RemoteIssue, String> {
+        let response = self.request(Method::GET, &format!("/issues/{}", number)).send().await;
+        read_json(response, &format!("fetch Gitea issue #{}", number)).await
+    }
+
+    async fn create_issue(&self, title: &str, body: &str, labels: &[String]) -> Result<RemoteIssue, String> {
+        // Creation only accepts label ids, so labels are applied by name afterwards
+        let response = self
+            .request(Method::POST, "/issues")
+            .json(&serde_json::json!({ "title": title, "body": body }))
+            .send()
+            .await;
+        let issue: RemoteIssue = read_json(response, "create Gitea issue").await?;
+        if labels.is_empty() {
+            return Ok(issue);
+        }
+
+        let patch = IssuePatch {
+            labels: Some(labels.to_vec()),
+            ..Default::default()
+        };
+        self.update_issue(issue.number, &patch).await
+    }
+
+    async fn update_issue(&self, number: u64, patch: &IssuePatch) -> Result<RemoteIssue, String> {
+        // Replace labels first so the issue returned by the PATCH reflects them
+        if let Some(labels) = &patch.labels {
+            let response = self
+                .request(Method::PUT, &format!("/issues/{}/labels", number))
+                .json(&serde_json::json!({ "labels": labels }))
+                .send()
+                .await;
+            read_json::<serde_json::Value>(response, &format!("update labels on Gitea issue #{}", number)).await?;
+        }
+
+        let update = GiteaIssueUpdate {
+            title: patch.title.clone(),
+            body: patch.body.clone(),
+            state: patch.state.clone(),
+        };
+        let response = self
+            .request(Method::PATCH, &format!("/issues/{}", number))
+            .json(&update)
+            .send()
+            .await;
+        read_json(response, &format!("update Gitea issue #{}", number)).await
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>, String> {
+        let response = self.request(Method::GET, &format!("/issues/{}/comments", number)).send().await;
+        read_json(response, &format!("fetch comments for Gitea issue #{}", number)).await
+    }
+
+    async fn post_comment(&self, number: u64, body: &str) -> Result<RemoteComment, String> {
+        let response = self
+            .request(Method::POST, &format!("/issues/{}/comments", number))
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await;
+        read_json(response, &format!("add comment to Gitea issue #{}", number)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn tracker(server: &MockServer) -> GiteaTracker {
+        GiteaTracker::new(&format!("{}/api/v1", server.uri()), "tea-token", "me", "notes")
+    }
+
+    fn issue_json(number: u64) -> serde_json::Value {
+        json!({
+            "number": number,
+            "title": format!("Issue {}", number),
+            "body": "",
+            "state": "open",
+            "html_url": format!("https://git.home.lan/me/notes/issues/{}", number),
+            "labels": [{ "name": "bug" }],
+            "milestone": null,
+            "comments": 0,
+            "updated_at": "2024-01-01T00:00:00Z",
+            "pull_request": null,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_reads_pages_until_empty_page() {
+        // The instance clamps the page size below PAGE_LIMIT
+        let server = MockServer::start().await;
+        let clamped_page: Vec<_> = (1..=30).map(issue_json).collect();
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/me/notes/issues"))
+            .and(header("Authorization", "token tea-token"))
+            .and(query_param("type", "issues"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(clamped_page))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/me/notes/issues"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([issue_json(31)])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/me/notes/issues"))
+            .and(query_param("page", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let listing = tracker(&server).list_issues("open", None).await.unwrap();
+
+        assert_eq!(listing.issues.len(), 31);
+        assert_eq!(listing.issues[0].label_names(), vec!["bug".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_sets_labels_then_patches() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v1/repos/me/notes/issues/7/labels"))
+            .and(body_json(json!({ "labels": ["P0"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "name": "P0" }])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/repos/me/notes/issues/7"))
+            .and(body_json(json!({ "state": "closed" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(issue_json(7)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let patch = IssuePatch {
+            state: Some("closed".to_string()),
+            labels: Some(vec!["P0".to_string()]),
+            ..Default::default()
+        };
+        let updated = tracker(&server).update_issue(7, &patch).await.unwrap();
+
+        assert_eq!(updated.number, 7);
+    }
+}
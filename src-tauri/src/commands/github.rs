@@ -1,59 +1,29 @@
 // GitHub integration commands for fetching and syncing issues with GitHub repositories
-// Provides bidirectional sync: import GitHub issues as feedback, close issues when completed
+// Imports GitHub issues as feedback and closes issues when completed; two-way sync lives in github_sync.rs
+// The commands work with any tracker in tracker.rs; GitHub itself is GithubTracker below.
 
-use octocrab::{Octocrab, OctocrabBuilder};
+use async_trait::async_trait;
+use octocrab::{Octocrab, OctocrabBuilder, Page};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashSet;
 use std::path::Path;
 use uuid::Uuid;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use crate::models::feedback::{FeedbackFile, FeedbackItem};
 use crate::models::settings::Settings;
+use crate::models::{GithubSyncState, OutboundAction, ProjectSyncResult, SyncReport, SyncSummary, TrackerKind};
 use std::fs;
 use serde_json;
-use tauri::{AppHandle, Manager};
-
-/// Parse a GitHub URL to extract owner and repo name
-/// Supports formats like:
-/// - https://github.com/owner/repo
-/// - https://github.com/owner/repo.git
-/// - git@github.com:owner/repo.git
-fn parse_github_url(url: &str) -> Result<(String, String), String> {
-    let url = url.trim();
-
-    // Handle HTTPS URLs
-    if url.starts_with("https://github.com/") {
-        let path = url.trim_start_matches("https://github.com/")
-            .trim_end_matches(".git")
-            .trim_end_matches('/');
-
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() >= 2 {
-            return Ok((parts[0].to_string(), parts[1].to_string()));
-        }
-    }
-
-    // Handle SSH URLs
-    if url.starts_with("git@github.com:") {
-        let path = url.trim_start_matches("git@github.com:")
-            .trim_end_matches(".git");
-
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() >= 2 {
-            return Ok((parts[0].to_string(), parts[1].to_string()));
-        }
-    }
-
-    Err(format!("Invalid GitHub URL format: {}", url))
-}
-
-/// Create an authenticated Octocrab instance using the provided GitHub token
-fn create_github_client(token: &str) -> Result<Octocrab, String> {
-    OctocrabBuilder::new()
-        .personal_token(token.to_string())
-        .build()
-        .map_err(|e| format!("Failed to create GitHub client: {}", e))
-}
-
-/// Get GitHub URL from git remote origin
+use tauri::AppHandle;
+use crate::commands::github_mapping::read_mapping_rules;
+use crate::commands::github_discussions;
+use crate::commands::github_sync::{self, sync_github_issues};
+use crate::commands::credentials;
+use crate::commands::settings;
+use crate::commands::outbound;
+use crate::commands::tracker::{self, IssueTracker};
+
+/// Get the repository URL from git remote origin (any host tracker.rs can parse)
 fn get_github_url_from_git(project_path: &Path) -> Option<String> {
     use std::process::Command;
 
@@ -78,20 +48,8 @@ fn get_github_url_from_git(project_path: &Path) -> Option<String> {
         .trim()
         .to_string();
 
-    if url.is_empty() || !url.contains("github.com") {
-        return None;
-    }
-
     // Convert SSH URLs to HTTPS
-    let https_url = if url.starts_with("git@github.com:") {
-        url.replace("git@github.com:", "https://github.com/")
-            .trim_end_matches(".git")
-            .to_string()
-    } else {
-        url.trim_end_matches(".git").to_string()
-    };
-
-    Some(https_url)
+    tracker::parse_remote_url(&url).ok().map(|location| location.web_url())
 }
 
 /// Read the feedback file from a project directory
@@ -133,200 +91,520 @@ fn write_feedback_file(project_path: &Path, feedback_file: &FeedbackFile) -> Res
         .map_err(|e| format!("Failed to write feedback file: {}", e))
 }
 
-/// Read settings to get GitHub token
-fn read_settings(app: &AppHandle) -> Result<Settings, String> {
-    let config_dir = app.path()
-        .app_config_dir()
-        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+/// Read the project's GitHub sync cursor
+pub(crate) fn read_sync_state(project_path: &Path) -> GithubSyncState {
+    let state_path = project_path.join(".vibe").join("github-sync.json");
+
+    fs::read_to_string(&state_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Write the project's GitHub sync cursor
+pub(crate) fn write_sync_state(project_path: &Path, state: &GithubSyncState) -> Result<(), String> {
+    let state_path = project_path.join(".vibe").join("github-sync.json");
 
-    let settings_path = config_dir.join("settings.json");
+    let content = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("Failed to serialize GitHub sync state: {}", e))?;
+
+    fs::write(&state_path, content)
+        .map_err(|e| format!("Failed to write GitHub sync state: {}", e))
+}
 
-    if !settings_path.exists() {
-        return Ok(Settings::default());
+/// Read settings, with tokens filled in from the credential store
+pub(crate) fn read_settings(app: &AppHandle) -> Result<Settings, String> {
+    let mut settings = settings::read_settings_file(app)?;
+    credentials::load_tokens(&mut settings)?;
+    Ok(settings)
+}
+
+/// Subset of the GitHub issue payload used for importing and syncing
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RemoteIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String, // "open" | "closed"
+    pub html_url: String,
+    #[serde(default)]
+    pub labels: Vec<RemoteLabel>,
+    #[serde(default)]
+    pub milestone: Option<RemoteMilestone>,
+    #[serde(default)]
+    pub comments: u64,
+    pub updated_at: DateTime<Utc>,
+    /// Present when the "issue" is actually a pull request
+    #[serde(default)]
+    pub pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RemoteLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RemoteMilestone {
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RemoteUser {
+    pub login: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RemoteComment {
+    pub id: u64,
+    #[serde(default)]
+    pub body: Option<String>,
+    pub user: Option<RemoteUser>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fields to change on a GitHub issue; unset fields are left untouched
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct IssuePatch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+}
+
+/// Issues returned by list_issues, with pull requests already filtered out
+#[derive(Debug, Default)]
+pub(crate) struct IssueListing {
+    pub issues: Vec<RemoteIssue>,
+    pub pull_requests_skipped: usize,
+}
+
+impl RemoteIssue {
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state == "open"
     }
 
-    let content = fs::read_to_string(&settings_path)
-        .map_err(|e| format!("Failed to read settings: {}", e))?;
+    pub fn body_text(&self) -> String {
+        self.body.as_deref().unwrap_or("").trim().to_string()
+    }
 
-    serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse settings: {}", e))
+    pub fn label_names(&self) -> Vec<String> {
+        self.labels.iter().map(|l| l.name.clone()).collect()
+    }
+
+    pub fn milestone_title(&self) -> Option<&str> {
+        self.milestone.as_ref().map(|m| m.title.as_str())
+    }
+
+    /// Feedback text for this issue: the title, followed by the body if there is one
+    pub fn feedback_text(&self) -> String {
+        let body = self.body_text();
+        if body.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{}\n\n{}", self.title, body)
+        }
+    }
 }
 
-/// Fetch open issues from a GitHub repository and import them as feedback items
+/// A GitHub (or GitHub Enterprise) repository accessed through Octocrab
+pub(crate) struct GithubTracker {
+    client: Octocrab,
+    owner: String,
+    repo: String,
+}
+
+/// Build an authenticated Octocrab client for a GitHub (or GitHub Enterprise) API URL
+pub(crate) fn build_client(api_url: &str, token: &str) -> Result<Octocrab, String> {
+    OctocrabBuilder::new()
+        .base_uri(api_url)
+        .map_err(|e| format!("Invalid GitHub API URL {}: {}", api_url, e))?
+        .personal_token(token.to_string())
+        .build()
+        .map_err(|e| format!("Failed to create GitHub client: {}", e))
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+/// One page of a GraphQL connection
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Connection<T> {
+    #[serde(default)]
+    pub page_info: Option<PageInfo>,
+    pub nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PageInfo {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
+impl<T> Connection<T> {
+    /// Cursor for the next page, if there is one
+    pub(crate) fn next_cursor(&self) -> Option<String> {
+        match &self.page_info {
+            Some(PageInfo { has_next_page: true, end_cursor }) => end_cursor.clone(),
+            _ => None,
+        }
+    }
+}
+
+/// Base URL for GraphQL calls: GitHub Enterprise serves it at /api/graphql rather than under /api/v3
+pub(crate) fn graphql_base_url(api_url: &str) -> &str {
+    api_url.trim_end_matches('/').trim_end_matches("/v3")
+}
+
+/// Run a GraphQL query or mutation, treating errors in the response body as failures
+pub(crate) async fn graphql<T: DeserializeOwned>(
+    client: &Octocrab,
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<T, String> {
+    let payload = serde_json::json!({ "query": query, "variables": variables });
+    let response: GraphqlResponse<T> = client
+        .graphql(&payload)
+        .await
+        .map_err(|e| format!("GitHub GraphQL request failed: {}", e))?;
+
+    if let Some(error) = response.errors.first() {
+        return Err(format!("GitHub GraphQL error: {}", error.message));
+    }
+    response.data.ok_or_else(|| "GitHub GraphQL response had no data".to_string())
+}
+
+impl GithubTracker {
+    pub fn new(api_url: &str, token: &str, owner: &str, repo: &str) -> Result<Self, String> {
+        Ok(Self::with_client(build_client(api_url, token)?, owner, repo))
+    }
+
+    pub fn with_client(client: Octocrab, owner: &str, repo: &str) -> Self {
+        Self {
+            client,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl IssueTracker for GithubTracker {
+    fn kind(&self) -> TrackerKind {
+        TrackerKind::Github
+    }
+
+    async fn list_issues(&self, state: &str, since: Option<&str>) -> Result<IssueListing, String> {
+        list_issues(&self.client, &self.owner, &self.repo, state, since).await
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<RemoteIssue, String> {
+        get_issue(&self.client, &self.owner, &self.repo, number).await
+    }
+
+    async fn create_issue(&self, title: &str, body: &str, labels: &[String]) -> Result<RemoteIssue, String> {
+        self.client
+            .post(
+                format!("/repos/{}/{}/issues", self.owner, self.repo),
+                Some(&serde_json::json!({ "title": title, "body": body, "labels": labels })),
+            )
+            .await
+            .map_err(|e| format!("Failed to create GitHub issue: {}", e))
+    }
+
+    async fn update_issue(&self, number: u64, patch: &IssuePatch) -> Result<RemoteIssue, String> {
+        patch_issue(&self.client, &self.owner, &self.repo, number, patch).await
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>, String> {
+        list_comments(&self.client, &self.owner, &self.repo, number).await
+    }
+
+    async fn post_comment(&self, number: u64, body: &str) -> Result<RemoteComment, String> {
+        post_comment(&self.client, &self.owner, &self.repo, number, body).await
+    }
+}
+
+/// List issues in a repository with the given state ("open", "closed" or "all")
 ///
-/// This command:
-/// 1. Reads the project's GitHub URL from vibe-hub.md
-/// 2. Authenticates with GitHub using the token from settings
-/// 3. Fetches all open issues from the repository
-/// 4. Creates or updates feedback items for each issue (marked with github_issue_number and github_issue_url)
-/// 5. Returns count of issues fetched
-#[tauri::command]
-pub async fn fetch_github_issues(
-    app: AppHandle,
-    project_path: String,
-    github_url: String,
-) -> Result<usize, String> {
-    println!("[fetch_github_issues] Starting fetch for: {}", github_url);
+/// Follows pagination until every page has been read. GitHub's issues endpoint also
+/// returns pull requests, which are dropped. With `since`, only issues updated at or
+/// after that time are returned.
+async fn list_issues(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    state: &str,
+    since: Option<&str>,
+) -> Result<IssueListing, String> {
+    let mut params = vec![("state", state), ("per_page", "100")];
+    if let Some(since) = since {
+        params.push(("since", since));
+    }
 
-    // Read settings to get GitHub token
-    let settings = read_settings(&app)?;
-    let token = settings.github_token
-        .ok_or_else(|| "GitHub token not configured. Please add your token in Settings.".to_string())?;
-
-    // Check if GitHub integration is enabled globally
-    if !settings.github_integration_enabled {
-        return Err("GitHub integration is disabled in settings. Please enable it in Settings.".to_string());
-    }
-
-    // Parse GitHub URL to extract owner and repo
-    let (owner, repo) = parse_github_url(&github_url)?;
-    println!("[fetch_github_issues] Parsed owner='{}', repo='{}'", owner, repo);
-
-    // Create GitHub client
-    let client = create_github_client(&token)?;
-    println!("[fetch_github_issues] GitHub client created successfully");
-
-    // Fetch open issues from GitHub
-    println!("[fetch_github_issues] Fetching open issues from GitHub API...");
-    let issues = client
-        .issues(&owner, &repo)
-        .list()
-        .state(octocrab::params::State::Open)
-        .per_page(100)
-        .send()
+    let map_err = |e: octocrab::Error| format!("Failed to fetch GitHub issues: {}. Please check your GitHub token and repository access.", e);
+
+    let mut page: Page<RemoteIssue> = client
+        .get(format!("/repos/{}/{}/issues", owner, repo), Some(&params))
         .await
-        .map_err(|e| format!("Failed to fetch GitHub issues: {}. Please check your GitHub token and repository access.", e))?;
+        .map_err(map_err)?;
 
-    println!("[fetch_github_issues] Found {} open issues on GitHub", issues.items.len());
+    let mut listing = IssueListing::default();
+    loop {
+        for issue in page.take_items() {
+            if issue.is_pull_request() {
+                listing.pull_requests_skipped += 1;
+            } else {
+                listing.issues.push(issue);
+            }
+        }
 
-    // Read existing feedback and issues
-    let path = Path::new(&project_path);
-    let mut feedback_file = read_feedback_file(path)?;
+        match client.get_page::<RemoteIssue>(&page.next).await.map_err(map_err)? {
+            Some(next) => page = next,
+            None => break,
+        }
+    }
 
-    // Also read issues and archived data to avoid re-importing already tracked issues
-    use crate::commands::issues::{read_issues_file, read_issues_archive_file, read_archived_feedback};
-    let issues_file = read_issues_file(path).unwrap_or_default();
-    let issues_archive = read_issues_archive_file(path).unwrap_or_default();
-    let feedback_archive = read_archived_feedback(path).unwrap_or_default();
+    Ok(listing)
+}
 
-    // Track how many new issues we import
-    let mut imported_count = 0;
+/// Fetch a single GitHub issue
+async fn get_issue(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+) -> Result<RemoteIssue, String> {
+    client
+        .get(format!("/repos/{}/{}/issues/{}", owner, repo, issue_number), None::<&()>)
+        .await
+        .map_err(|e| format!("Failed to fetch GitHub issue #{}: {}", issue_number, e))
+}
 
-    // Process each GitHub issue
-    for issue in issues.items {
-        let issue_number = issue.number;
-        let issue_url = issue.html_url.to_string();
+/// Apply a patch to a GitHub issue and return the updated issue
+async fn patch_issue(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+    patch: &IssuePatch,
+) -> Result<RemoteIssue, String> {
+    client
+        .patch(format!("/repos/{}/{}/issues/{}", owner, repo, issue_number), Some(patch))
+        .await
+        .map_err(|e| format!("Failed to update GitHub issue #{}: {}", issue_number, e))
+}
 
-        // Check if this issue already exists in:
-        // 1. Active feedback
-        let in_feedback = feedback_file.feedback.iter().any(|f| {
-            f.github_issue_number == Some(issue_number)
-        });
+/// List all comments on a GitHub issue, following pagination
+async fn list_comments(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+) -> Result<Vec<RemoteComment>, String> {
+    let map_err = |e: octocrab::Error| format!("Failed to fetch comments for GitHub issue #{}: {}", issue_number, e);
+
+    let mut page: Page<RemoteComment> = client
+        .get(
+            format!("/repos/{}/{}/issues/{}/comments", owner, repo, issue_number),
+            Some(&[("per_page", "100")]),
+        )
+        .await
+        .map_err(map_err)?;
 
-        // 2. Archived feedback
-        let in_feedback_archive = feedback_archive.feedback.iter().any(|f| {
-            f.github_issue_number == Some(issue_number)
-        });
+    let mut comments = Vec::new();
+    loop {
+        comments.extend(page.take_items());
 
-        // 3. Active issues
-        let in_issues = issues_file.issues.iter().any(|i| {
-            i.github_issue_number == Some(issue_number)
-        });
+        match client.get_page::<RemoteComment>(&page.next).await.map_err(map_err)? {
+            Some(next) => page = next,
+            None => break,
+        }
+    }
 
-        // 4. Archived issues
-        let in_issues_archive = issues_archive.issues.iter().any(|i| {
-            i.github_issue_number == Some(issue_number)
-        });
+    Ok(comments)
+}
 
-        let already_tracked = in_feedback || in_feedback_archive || in_issues || in_issues_archive;
+/// Post a comment on a GitHub issue
+async fn post_comment(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+    body: &str,
+) -> Result<RemoteComment, String> {
+    client
+        .post(
+            format!("/repos/{}/{}/issues/{}/comments", owner, repo, issue_number),
+            Some(&serde_json::json!({ "body": body })),
+        )
+        .await
+        .map_err(|e| format!("Failed to add comment to GitHub issue #{}: {}", issue_number, e))
+}
 
-        if already_tracked {
-            println!("[fetch_github_issues] Skipping issue #{} - already tracked in local data", issue_number);
+/// Import GitHub issues that aren't tracked anywhere in the project yet as pending feedback
+///
+/// Issues already linked from active/archived feedback or issues are skipped, as are
+/// issues carrying one of the project's skip labels. Priority and labels come from the
+/// project's mapping rules. Returns the number of newly imported issues.
+pub(crate) fn import_remote_issues(project_path: &Path, remote_issues: &[RemoteIssue]) -> Result<usize, String> {
+    let mut feedback_file = read_feedback_file(project_path)?;
+
+    // Also read issues and archived data to avoid re-importing already tracked issues
+    use crate::commands::issues::{read_issues_file, read_issues_archive_file, read_archived_feedback, read_feedback_file as read_named_feedback_file, FEEDBACK_COMPLETED_FILE};
+    let issues_file = read_issues_file(project_path).unwrap_or_default();
+    let issues_archive = read_issues_archive_file(project_path).unwrap_or_default();
+    let feedback_archive = read_archived_feedback(project_path).unwrap_or_default();
+    let feedback_completed = read_named_feedback_file(project_path, FEEDBACK_COMPLETED_FILE).unwrap_or_default();
+
+    let tracked: HashSet<u64> = feedback_file.feedback.iter()
+        .chain(feedback_archive.feedback.iter())
+        .chain(feedback_completed.feedback.iter())
+        .filter_map(|f| f.github_issue_number)
+        .chain(issues_file.issues.iter().chain(issues_archive.issues.iter()).filter_map(|i| i.github_issue_number))
+        .collect();
+
+    let rules = read_mapping_rules(project_path);
+
+    // Track how many new issues we import
+    let mut imported_count = 0;
+    let now = Utc::now().to_rfc3339();
+
+    for issue in remote_issues {
+        // Skip issues already tracked in local data, and closed ones
+        if tracked.contains(&issue.number) || !issue.is_open() {
             continue;
         }
 
-        if !already_tracked {
-            // Format the feedback text with title and body
-            let text = if let Some(body) = &issue.body {
-                let body_trimmed = body.trim();
-                if body_trimmed.is_empty() {
-                    issue.title.clone()
-                } else {
-                    format!("{}\n\n{}", issue.title, body_trimmed)
-                }
-            } else {
-                issue.title.clone()
-            };
-
-            println!("[fetch_github_issues] Importing issue #{}: {}", issue_number, issue.title);
-
-            // Create new feedback item from GitHub issue
-            let feedback_item = FeedbackItem {
-                id: Uuid::new_v4().to_string(),
-                text,
-                priority: 3, // Default to medium priority
-                status: "pending".to_string(),
-                created_at: Utc::now().to_rfc3339(),
-                completed_at: None,
-                refined_into_issue_ids: None,
-                review_notes: None,
-                related_issue_id: None,
-                github_issue_number: Some(issue_number),
-                github_issue_url: Some(issue_url),
-            };
-
-            feedback_file.feedback.push(feedback_item);
-            imported_count += 1;
+        let labels = issue.label_names();
+        if rules.should_skip(&labels) {
+            continue;
         }
+
+        // Create new feedback item from GitHub issue
+        feedback_file.feedback.push(FeedbackItem {
+            id: Uuid::new_v4().to_string(),
+            text: issue.feedback_text(),
+            priority: rules.priority_for(&labels, issue.milestone_title()).unwrap_or(rules.default_priority),
+            status: "pending".to_string(),
+            created_at: now.clone(),
+            completed_at: None,
+            refined_into_issue_ids: None,
+            review_notes: None,
+            related_issue_id: None,
+            github_issue_number: Some(issue.number),
+            github_issue_url: Some(issue.html_url.clone()),
+            updated_at: None,
+            labels: Some(rules.local_labels(&labels)),
+            comments: None,
+            github_synced_at: Some(now.clone()),
+            github_updated_at: Some(issue.updated_at.to_rfc3339()),
+            github_discussion_number: None,
+            github_discussion_url: None,
+        });
+        imported_count += 1;
     }
 
     // Save updated feedback file if we imported any issues
     if imported_count > 0 {
-        println!("[fetch_github_issues] Importing {} new issue(s) to feedback file", imported_count);
-        write_feedback_file(path, &feedback_file)?;
-        println!("[fetch_github_issues] Feedback file updated successfully");
-    } else {
-        println!("[fetch_github_issues] No new issues to import (all already exist in feedback)");
+        write_feedback_file(project_path, &feedback_file)?;
     }
 
     Ok(imported_count)
 }
 
+/// Fetch open issues from a GitHub repository and import them as feedback items
+///
+/// This command:
+/// 1. Authenticates with GitHub using the token from settings
+/// 2. Fetches every page of open issues, skipping pull requests. If the project has
+///    a sync cursor, only issues updated since then are requested
+/// 3. Creates feedback items for issues not tracked yet (marked with github_issue_number and github_issue_url)
+/// 4. Returns a report with the fetched and imported counts
+///
+/// The cursor is only advanced by the two-way sync, which also reconciles linked items.
+#[tauri::command]
+pub async fn fetch_github_issues(
+    app: AppHandle,
+    project_path: String,
+    github_url: String,
+) -> Result<SyncReport, String> {
+    let path = Path::new(&project_path);
+    let tracker = tracker::tracker_from_settings(&app, Some(path), &github_url)?;
+
+    let since = read_sync_state(path).since;
+    let listing = tracker.list_issues("open", since.as_deref()).await?;
+
+    let mut report = SyncReport {
+        tracker: Some(tracker.kind()),
+        since,
+        fetched: listing.issues.len(),
+        pull_requests_skipped: listing.pull_requests_skipped,
+        imported: import_remote_issues(path, &listing.issues)?,
+        synced_at: Utc::now().to_rfc3339(),
+        ..Default::default()
+    };
+    github_sync::pull_comment_threads(tracker.as_ref(), path, &listing.issues, &mut report).await?;
+    github_discussions::sync_discussions(&read_settings(&app)?, path, &github_url, &mut report).await;
+
+    Ok(report)
+}
+
+/// Whether .vibe/metadata.md turns on GitHub sync for a project (None if there's no metadata file)
+fn github_sync_enabled(project_path: &Path) -> Option<bool> {
+    let content = fs::read_to_string(project_path.join(".vibe").join("metadata.md")).ok()?;
+
+    Some(content.lines().any(|line| {
+        let trimmed = line.trim();
+        if trimmed.starts_with("GitHubSync:") || trimmed.starts_with("githubSync:") {
+            let value = trimmed.split(':').nth(1).unwrap_or("").trim().to_lowercase();
+            value == "true" || value == "enabled" || value == "yes"
+        } else {
+            false
+        }
+    }))
+}
+
 /// Sync GitHub issues for all projects with GitHub integration enabled
 ///
 /// This command:
 /// 1. Reads settings to check if global GitHub integration is enabled
 /// 2. Scans all projects in the projects directory
-/// 3. For each project with GitHub integration enabled, fetches and imports issues
-/// 4. Returns count of total issues synced across all projects
+/// 3. For each project with GitHub integration enabled, runs a two-way sync (see github_sync.rs)
+/// 4. Returns a summary with the outcome for every project
 #[tauri::command]
 pub async fn sync_all_github_issues(
     app: AppHandle,
     projects_dir: String,
-) -> Result<usize, String> {
-    use std::fs;
-
-    println!("[sync_all_github_issues] Starting sync for projects in: {}", projects_dir);
-
+) -> Result<SyncSummary, String> {
     // Read settings to check if GitHub integration is globally enabled
     let settings = read_settings(&app)?;
 
-    println!("[sync_all_github_issues] GitHub integration enabled: {}", settings.github_integration_enabled);
-    println!("[sync_all_github_issues] GitHub token present: {}", settings.github_token.is_some());
-
-    if !settings.github_integration_enabled {
-        println!("[sync_all_github_issues] GitHub integration is disabled globally, skipping sync");
-        return Ok(0); // Silently skip if globally disabled
+    // Silently skip if globally disabled or no token configured for any tracker
+    let has_token = settings.github_token.is_some() || settings.tracker_hosts.iter().any(|h| h.token.is_some());
+    if !settings.github_integration_enabled || !has_token {
+        return Ok(SyncSummary::default());
     }
 
-    let _token = match &settings.github_token {
-        Some(t) => t,
-        None => {
-            println!("[sync_all_github_issues] No GitHub token configured, skipping sync");
-            return Ok(0); // Silently skip if no token configured
-        }
-    };
-
     let projects_path = Path::new(&projects_dir);
     if !projects_path.exists() {
         return Err("Projects directory does not exist".to_string());
@@ -335,172 +613,230 @@ pub async fn sync_all_github_issues(
     let entries = fs::read_dir(projects_path)
         .map_err(|e| format!("Failed to read projects directory: {}", e))?;
 
-    let mut total_synced = 0;
-    let mut projects_scanned = 0;
-    let mut projects_with_metadata = 0;
-    let mut projects_with_github_url = 0;
-    let mut projects_with_sync_enabled = 0;
-
-    for entry in entries {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(e) => {
-                eprintln!("[sync_all_github_issues] Failed to read entry: {}", e);
-                continue;
-            }
-        };
+    let mut summary = SyncSummary::default();
 
+    for entry in entries.flatten() {
         let path = entry.path();
         if !path.is_dir() {
             continue;
         }
 
-        projects_scanned += 1;
-        let project_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        summary.projects_scanned += 1;
+        let mut result = ProjectSyncResult {
+            project_name: path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+            project_path: path.to_string_lossy().to_string(),
+            github_url: None,
+            status: "skipped".to_string(),
+            reason: None,
+            report: None,
+        };
 
-        // Check if project has GitHub integration enabled
         // Get GitHub URL from git remote
         let github_url = match get_github_url_from_git(&path) {
             Some(url) => url,
             None => {
-                println!("[sync_all_github_issues] Project '{}': No GitHub remote found", project_name);
+                result.reason = Some("No git remote found".to_string());
+                summary.projects.push(result);
                 continue;
             }
         };
-
-        projects_with_github_url += 1;
-        println!("[sync_all_github_issues] Project '{}': GitHub URL = '{}'", project_name, github_url);
+        summary.projects_with_github_url += 1;
+        result.github_url = Some(github_url.clone());
 
         // Check if GitHub sync is enabled for this project
-        let metadata_path = path.join(".vibe").join("metadata.md");
-        let github_enabled = if metadata_path.exists() {
-            projects_with_metadata += 1;
-            match fs::read_to_string(&metadata_path) {
-                Ok(content) => {
-                    let enabled = content.lines().any(|line| {
-                        let trimmed = line.trim();
-                        if trimmed.starts_with("GitHubSync:") || trimmed.starts_with("githubSync:") {
-                            let value = trimmed.split(':').nth(1).unwrap_or("").trim().to_lowercase();
-                            value == "true" || value == "enabled" || value == "yes"
-                        } else {
-                            false
-                        }
-                    });
-                    println!("[sync_all_github_issues] Project '{}': GitHubSync = {}", project_name, enabled);
-                    enabled
-                }
-                Err(_) => false
-            }
-        } else {
-            println!("[sync_all_github_issues] Project '{}': No .vibe/metadata.md found, defaulting to sync disabled", project_name);
-            false
-        };
-
-        if !github_enabled {
-            println!("[sync_all_github_issues] Project '{}': Skipping (GitHubSync not enabled)", project_name);
+        let sync_enabled = github_sync_enabled(&path);
+        if sync_enabled.is_some() {
+            summary.projects_with_metadata += 1;
+        }
+        if sync_enabled != Some(true) {
+            result.reason = Some(match sync_enabled {
+                Some(_) => "GitHubSync not enabled".to_string(),
+                None => "No .vibe/metadata.md found".to_string(),
+            });
+            summary.projects.push(result);
             continue;
         }
-
-        projects_with_sync_enabled += 1;
-
-        println!("[sync_all_github_issues] Project '{}': Attempting to sync from {}", project_name, github_url);
-
-        // Try to sync this project
-        match fetch_github_issues(app.clone(), path.to_string_lossy().to_string(), github_url.clone()).await {
-            Ok(count) => {
-                println!("[sync_all_github_issues] Project '{}': Successfully synced {} issue(s)", project_name, count);
-                total_synced += count;
-            },
+        summary.projects_with_sync_enabled += 1;
+
+        // Errors are recorded per project so one failure doesn't stop the rest
+        match sync_github_issues(app.clone(), result.project_path.clone(), github_url).await {
+            Ok(report) => {
+                summary.imported += report.imported;
+                result.status = "synced".to_string();
+                result.report = Some(report);
+            }
             Err(e) => {
-                // Log error but continue with other projects
-                eprintln!("[sync_all_github_issues] Project '{}': ERROR - {}", project_name, e);
+                result.status = "failed".to_string();
+                result.reason = Some(e);
             }
         }
+        summary.projects.push(result);
     }
 
-    println!("\n[sync_all_github_issues] ===== SYNC SUMMARY =====");
-    println!("[sync_all_github_issues] Projects scanned: {}", projects_scanned);
-    println!("[sync_all_github_issues] Projects with vibe-hub.md: {}", projects_with_metadata);
-    println!("[sync_all_github_issues] Projects with GitHub URL: {}", projects_with_github_url);
-    println!("[sync_all_github_issues] Projects with sync enabled: {}", projects_with_sync_enabled);
-    println!("[sync_all_github_issues] Total issues synced: {}", total_synced);
-    println!("[sync_all_github_issues] ========================\n");
+    Ok(summary)
+}
 
-    Ok(total_synced)
+/// Close an issue with a comment indicating it was completed in Vibe Hub
+///
+/// `project_path` lets the project's metadata choose the tracker, when known. If the
+/// tracker can't be reached the close is queued for retry; returns false in that case.
+pub(crate) async fn close_remote_issue(
+    app: &AppHandle,
+    project_path: Option<&Path>,
+    repo_url: &str,
+    issue_number: u64,
+) -> Result<bool, String> {
+    let action = OutboundAction::Close {
+        issue_number,
+        comment: Some("This issue was completed and closed via Vibe Hub.".to_string()),
+    };
+    outbound::submit(app, project_path, repo_url, action).await
 }
 
 /// Close a GitHub issue when the corresponding feedback item is marked as completed
 ///
 /// This command:
-/// 1. Authenticates with GitHub using the token from settings
+/// 1. Picks the tracker for the repository (GitHub, GitLab or Gitea) and its token from settings
 /// 2. Closes the specified issue with a comment indicating it was completed in Vibe Hub
-/// 3. Returns success or error
+/// 3. Queues the close for retry if it fails (see outbound.rs), so only queue errors are returned
 #[tauri::command]
 pub async fn close_github_issue(
     app: AppHandle,
     github_url: String,
     issue_number: u64,
 ) -> Result<(), String> {
-    // Read settings to get GitHub token
-    let settings = read_settings(&app)?;
-    let token = settings.github_token
-        .ok_or_else(|| "GitHub token not configured. Please add your token in Settings.".to_string())?;
-
-    // Parse GitHub URL to extract owner and repo
-    let (owner, repo) = parse_github_url(&github_url)?;
+    close_remote_issue(&app, None, &github_url, issue_number).await.map(|_| ())
+}
 
-    // Create GitHub client
-    let client = create_github_client(&token)?;
+/// Create a GitHub issue from a pending feedback item or issue and link it
+///
+/// Labels go through the project's mapping rules, so the local priority becomes a
+/// priority label. If the tracker can't be reached the create is queued for retry; while
+/// it is, calling this again leaves it to that entry rather than creating a second issue.
+/// Returns true if the issue was created immediately, false if it was queued.
+#[tauri::command]
+pub async fn create_github_issue(
+    app: AppHandle,
+    project_path: String,
+    github_url: String,
+    item_id: String,
+) -> Result<bool, String> {
+    use crate::commands::issues::{read_feedback_file as read_named_feedback_file, read_issues_file, FEEDBACK_FILE};
 
-    // Close the issue
-    use octocrab::models::IssueState;
-    client
-        .issues(&owner, &repo)
-        .update(issue_number)
-        .state(IssueState::Closed)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to close GitHub issue: {}", e))?;
+    let path = Path::new(&project_path);
+    let feedback = read_named_feedback_file(path, FEEDBACK_FILE)?;
+    let issues = read_issues_file(path)?;
+
+    let (title, body, labels, priority, linked) = if let Some(item) = feedback.feedback.iter().find(|f| f.id == item_id) {
+        let (title, body) = item.text.split_once('\n').unwrap_or((item.text.as_str(), ""));
+        (title.trim().to_string(), body.trim().to_string(), item.labels.clone(), item.priority, item.github_issue_number)
+    } else if let Some(issue) = issues.issues.iter().find(|i| i.id == item_id) {
+        (issue.title.clone(), issue.description.clone(), issue.labels.clone(), issue.priority, issue.github_issue_number)
+    } else {
+        return Err("Feedback item or issue not found".to_string());
+    };
 
-    // Add a comment indicating it was completed in Vibe Hub
-    let comment = "This issue was completed and closed via Vibe Hub.";
-    client
-        .issues(&owner, &repo)
-        .create_comment(issue_number, comment)
-        .await
-        .map_err(|e| format!("Failed to add comment to GitHub issue: {}", e))?;
+    if let Some(number) = linked {
+        return Err(format!("Already linked to GitHub issue #{}", number));
+    }
 
-    Ok(())
+    let rules = read_mapping_rules(path);
+    let action = OutboundAction::Create {
+        title,
+        body,
+        labels: rules.remote_labels(&labels.unwrap_or_default(), priority, &[]),
+        local_id: Some(item_id),
+    };
+    outbound::submit(&app, Some(path), &github_url, action).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_parse_github_url_https() {
-        let (owner, repo) = parse_github_url("https://github.com/owner/repo").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
+    fn issue_json(number: u64, is_pull_request: bool) -> serde_json::Value {
+        let mut issue = serde_json::json!({
+            "number": number,
+            "title": format!("Issue {}", number),
+            "body": null,
+            "state": "open",
+            "html_url": format!("https://github.com/owner/repo/issues/{}", number),
+            "labels": [],
+            "comments": 0,
+            "updated_at": "2024-01-01T00:00:00Z",
+        });
+        if is_pull_request {
+            issue["pull_request"] = serde_json::json!({ "url": "https://api.github.com/repos/owner/repo/pulls/1" });
+        }
+        issue
     }
 
-    #[test]
-    fn test_parse_github_url_https_with_git() {
-        let (owner, repo) = parse_github_url("https://github.com/owner/repo.git").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
+    #[tokio::test]
+    async fn test_list_issues_follows_pages_and_skips_pull_requests() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let next = format!("<{}/repos/owner/repo/issues?state=open&per_page=100&page=2>; rel=\"next\"", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([issue_json(3, false)])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param("since", "2024-01-01T00:00:00Z"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Link", next.as_str())
+                    .set_body_json(serde_json::json!([issue_json(1, false), issue_json(2, true)])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = OctocrabBuilder::new().base_uri(server.uri()).unwrap().build().unwrap();
+        let listing = list_issues(&client, "owner", "repo", "open", Some("2024-01-01T00:00:00Z")).await.unwrap();
+
+        let numbers: Vec<u64> = listing.issues.iter().map(|i| i.number).collect();
+        assert_eq!(numbers, vec![1, 3]);
+        assert_eq!(listing.pull_requests_skipped, 1);
     }
 
-    #[test]
-    fn test_parse_github_url_ssh() {
-        let (owner, repo) = parse_github_url("git@github.com:owner/repo.git").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
-    }
+    #[tokio::test]
+    async fn test_list_comments_follows_pages() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let comment_json = |id: u64| serde_json::json!({
+            "id": id,
+            "body": format!("Comment {}", id),
+            "user": { "login": "user" },
+            "created_at": "2024-01-01T00:00:00Z",
+        });
 
-    #[test]
-    fn test_parse_github_url_invalid() {
-        let result = parse_github_url("not-a-github-url");
-        assert!(result.is_err());
+        let server = MockServer::start().await;
+        let next = format!("<{}/repos/owner/repo/issues/7/comments?per_page=100&page=2>; rel=\"next\"", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/7/comments"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([comment_json(101)])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/7/comments"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Link", next.as_str())
+                    .set_body_json(serde_json::json!((1..=100).map(comment_json).collect::<Vec<_>>())),
+            )
+            .mount(&server)
+            .await;
+
+        let client = OctocrabBuilder::new().base_uri(server.uri()).unwrap().build().unwrap();
+        let comments = list_comments(&client, "owner", "repo", 7).await.unwrap();
+
+        assert_eq!(comments.len(), 101);
+        assert_eq!(comments.last().map(|c| c.id), Some(101));
     }
 }
@@ -0,0 +1,621 @@
+// Keeping issue statuses in step with a GitHub Projects (v2) board
+// The board's single-select status field is mapped onto Issue.status using the options in
+// .vibe/github-project.json. Projects v2 is only reachable through GraphQL.
+
+use chrono::{DateTime, Utc};
+use octocrab::Octocrab;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::commands::github::{self, read_settings, Connection};
+use crate::commands::issues::{read_issues_archive_file, read_issues_file, write_issues_archive_file, write_issues_file};
+use crate::commands::tracker::{self, TrackerConfig};
+use crate::models::{GithubBoardConfig, Issue, Settings, SyncChange, SyncReport, TrackerKind};
+
+const ISSUE_STATUSES: [&str; 5] = ["pending", "in-progress", "for-review", "needs-rework", "completed"];
+
+const BOARD_QUERY: &str = r#"
+query($owner: String!, $number: Int!, $field: String!, $cursor: String) {
+  repositoryOwner(login: $owner) {
+    ... on ProjectV2Owner {
+      projectV2(number: $number) {
+        id
+        field(name: $field) {
+          ... on ProjectV2SingleSelectField { id options { id name } }
+        }
+        items(first: 100, after: $cursor) {
+          pageInfo { hasNextPage endCursor }
+          nodes {
+            id
+            content {
+              ... on Issue { number repository { nameWithOwner } }
+            }
+            fieldValueByName(name: $field) {
+              ... on ProjectV2ItemFieldSingleSelectValue { name updatedAt }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const ISSUE_ID_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $number: Int!) {
+  repository(owner: $owner, name: $repo) { issue(number: $number) { id } }
+}
+"#;
+
+const ADD_ITEM_MUTATION: &str = r#"
+mutation($project: ID!, $content: ID!) {
+  addProjectV2ItemById(input: { projectId: $project, contentId: $content }) { item { id } }
+}
+"#;
+
+const SET_STATUS_MUTATION: &str = r#"
+mutation($project: ID!, $item: ID!, $field: ID!, $option: String!) {
+  updateProjectV2ItemFieldValue(
+    input: { projectId: $project, itemId: $item, fieldId: $field, value: { singleSelectOptionId: $option } }
+  ) { projectV2Item { id } }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnerData {
+    repository_owner: Option<ProjectOwner>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectOwner {
+    #[serde(default)]
+    project_v2: Option<BoardPage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BoardPage {
+    id: String,
+    field: Option<StatusField>,
+    items: Connection<BoardItem>,
+}
+
+// Fields that aren't single-select come back as an empty object
+#[derive(Debug, Deserialize)]
+struct StatusField {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    options: Vec<FieldOption>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FieldOption {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BoardItem {
+    id: String,
+    content: Option<ItemContent>,
+    field_value_by_name: Option<StatusValue>,
+}
+
+// Draft issues and pull requests come back as an empty object
+#[derive(Debug, Deserialize)]
+struct ItemContent {
+    #[serde(default)]
+    number: Option<u64>,
+    #[serde(default)]
+    repository: Option<ContentRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContentRepository {
+    name_with_owner: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusValue {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryIssueData {
+    repository: Option<RepositoryIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryIssue {
+    issue: Option<NodeId>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NodeId {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddItemData {
+    add_project_v2_item_by_id: AddedItem,
+}
+
+#[derive(Debug, Deserialize)]
+struct AddedItem {
+    item: NodeId,
+}
+
+/// The board's id, status field and every item on it
+struct Board {
+    id: String,
+    field_id: String,
+    options: Vec<FieldOption>,
+    items: Vec<BoardItem>,
+}
+
+impl BoardItem {
+    fn is_issue(&self, repo_name: &str, number: u64) -> bool {
+        self.content
+            .as_ref()
+            .map(|c| {
+                c.number == Some(number)
+                    && c.repository.as_ref().map(|r| r.name_with_owner.eq_ignore_ascii_case(repo_name)).unwrap_or(false)
+            })
+            .unwrap_or(false)
+    }
+
+    fn status(&self) -> Option<&str> {
+        self.field_value_by_name.as_ref().and_then(|v| v.name.as_deref())
+    }
+
+    fn status_updated_at(&self) -> Option<DateTime<Utc>> {
+        self.field_value_by_name.as_ref().and_then(|v| v.updated_at)
+    }
+}
+
+impl GithubBoardConfig {
+    /// Board option for a local issue status
+    pub(crate) fn option_for(&self, status: &str) -> Option<&str> {
+        self.status_options.iter().find(|m| m.status == status).map(|m| m.option.as_str())
+    }
+
+    /// Local issue status for a board option (option names are case-insensitive)
+    pub(crate) fn status_for(&self, option: &str) -> Option<&str> {
+        self.status_options
+            .iter()
+            .find(|m| m.option.eq_ignore_ascii_case(option))
+            .map(|m| m.status.as_str())
+    }
+}
+
+fn same_option(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Read the project's board config (None if the project has no board)
+pub(crate) fn read_board_config(project_path: &Path) -> Result<Option<GithubBoardConfig>, String> {
+    let config_path = project_path.join(".vibe").join("github-project.json");
+    if !config_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read GitHub project board config: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse GitHub project board config: {}", e))
+}
+
+/// Write the project's board config, or remove it when `config` is None
+pub(crate) fn write_board_config(project_path: &Path, config: Option<&GithubBoardConfig>) -> Result<(), String> {
+    let vibe_dir = project_path.join(".vibe");
+    let config_path = vibe_dir.join("github-project.json");
+
+    let Some(config) = config else {
+        if config_path.exists() {
+            fs::remove_file(&config_path)
+                .map_err(|e| format!("Failed to remove GitHub project board config: {}", e))?;
+        }
+        return Ok(());
+    };
+
+    fs::create_dir_all(&vibe_dir)
+        .map_err(|e| format!("Failed to create .vibe directory: {}", e))?;
+    let content = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize GitHub project board config: {}", e))?;
+    fs::write(config_path, content)
+        .map_err(|e| format!("Failed to write GitHub project board config: {}", e))
+}
+
+async fn load_board(client: &Octocrab, config: &GithubBoardConfig) -> Result<Board, String> {
+    let mut board: Option<Board> = None;
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let variables = serde_json::json!({
+            "owner": config.owner,
+            "number": config.number,
+            "field": config.status_field,
+            "cursor": cursor,
+        });
+        let data: OwnerData = github::graphql(client, BOARD_QUERY, variables)
+            .await
+            .map_err(|e| format!("Failed to fetch GitHub project board: {}", e))?;
+        let page = data
+            .repository_owner
+            .and_then(|o| o.project_v2)
+            .ok_or_else(|| format!("GitHub project {} not found for {}", config.number, config.owner))?;
+        let next = page.items.next_cursor();
+
+        match board.as_mut() {
+            Some(board) => board.items.extend(page.items.nodes),
+            None => {
+                let field = page.field.unwrap_or(StatusField { id: None, options: Vec::new() });
+                let field_id = field.id.ok_or_else(|| {
+                    format!("GitHub project {} has no single-select field named '{}'", config.number, config.status_field)
+                })?;
+                board = Some(Board { id: page.id, field_id, options: field.options, items: page.items.nodes });
+            }
+        }
+
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    board.ok_or_else(|| "GitHub project board was empty".to_string())
+}
+
+async fn set_item_status(client: &Octocrab, board: &Board, item_id: &str, option: &str) -> Result<(), String> {
+    let option_id = board
+        .options
+        .iter()
+        .find(|o| o.name.eq_ignore_ascii_case(option))
+        .map(|o| o.id.clone())
+        .ok_or_else(|| format!("GitHub project board has no '{}' status option", option))?;
+
+    let variables = serde_json::json!({
+        "project": board.id,
+        "item": item_id,
+        "field": board.field_id,
+        "option": option_id,
+    });
+    github::graphql::<serde_json::Value>(client, SET_STATUS_MUTATION, variables)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Failed to update GitHub project board item: {}", e))
+}
+
+async fn add_issue_to_board(
+    client: &Octocrab,
+    board: &Board,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    option: Option<&str>,
+) -> Result<(), String> {
+    let variables = serde_json::json!({ "owner": owner, "repo": repo, "number": number });
+    let data: RepositoryIssueData = github::graphql(client, ISSUE_ID_QUERY, variables)
+        .await
+        .map_err(|e| format!("Failed to look up issue #{}: {}", number, e))?;
+    let issue_id = data
+        .repository
+        .and_then(|r| r.issue)
+        .ok_or_else(|| format!("Issue #{} not found in {}/{}", number, owner, repo))?
+        .id;
+
+    let variables = serde_json::json!({ "project": board.id, "content": issue_id });
+    let added: AddItemData = github::graphql(client, ADD_ITEM_MUTATION, variables)
+        .await
+        .map_err(|e| format!("Failed to add issue #{} to the GitHub project board: {}", number, e))?;
+
+    match option {
+        Some(option) => set_item_status(client, board, &added.add_project_v2_item_by_id.item.id, option).await,
+        None => Ok(()),
+    }
+}
+
+fn set_issue_status(issue: &mut Issue, status: &str, now: &str) {
+    issue.status = status.to_string();
+    issue.completed_at = if status == "completed" { Some(now.to_string()) } else { None };
+    // Counts as a local edit, so the next issue sync closes or reopens the GitHub issue to match
+    issue.updated_at = Some(now.to_string());
+}
+
+/// Two-way sync of linked issues' statuses with the board
+///
+/// The status each item had at the last board sync tells which side changed. When both did,
+/// the more recent change wins. Linked issues missing from the board are added unless they
+/// were on it before (so items removed on GitHub stay removed) or are already completed.
+pub(crate) async fn sync_board(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    project_path: &Path,
+    config: &GithubBoardConfig,
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    let board = load_board(client, config).await?;
+    let repo_name = format!("{}/{}", owner, repo);
+    let now = Utc::now();
+    let now_str = now.to_rfc3339();
+
+    let mut sync_state = github::read_sync_state(project_path);
+    let mut issues_file = read_issues_file(project_path)?;
+    let mut issues_archive = read_issues_archive_file(project_path)?;
+    let mut issues: Vec<Issue> = issues_file.issues.drain(..).chain(issues_archive.issues.drain(..)).collect();
+
+    for issue in issues.iter_mut() {
+        let Some(number) = issue.github_issue_number else { continue };
+        let local_option = config.option_for(&issue.status).map(str::to_string);
+        let change = SyncChange {
+            issue_number: number,
+            local_id: issue.id.clone(),
+            kind: "issue".to_string(),
+            fields: vec!["status".to_string()],
+            winner: None,
+        };
+
+        let Some(item) = board.items.iter().find(|i| i.is_issue(&repo_name, number)) else {
+            if !config.add_missing_items || issue.status == "completed" || sync_state.board_statuses.contains_key(&number) {
+                continue;
+            }
+            match add_issue_to_board(client, &board, owner, repo, number, local_option.as_deref()).await {
+                Ok(()) => {
+                    report.board_items_added += 1;
+                    sync_state.board_statuses.insert(number, local_option.unwrap_or_default());
+                }
+                Err(e) => report.errors.push(e),
+            }
+            continue;
+        };
+
+        let board_option = item.status();
+        let last = sync_state.board_statuses.get(&number).map(String::as_str);
+        if same_option(board_option, local_option.as_deref()) {
+            if let Some(option) = board_option {
+                sync_state.board_statuses.insert(number, option.to_string());
+            }
+            continue;
+        }
+
+        let board_changed = !same_option(board_option, last);
+        let local_changed = !same_option(local_option.as_deref(), last);
+        let conflict = board_changed && local_changed;
+        let local_wins = if conflict {
+            let local_updated = issue.updated_at.as_deref().and_then(|t| DateTime::parse_from_rfc3339(t).ok());
+            match (local_updated, item.status_updated_at()) {
+                (Some(local), Some(remote)) => local > remote,
+                (Some(_), None) => true,
+                _ => false,
+            }
+        } else {
+            local_changed
+        };
+
+        let applied = if local_wins {
+            // Statuses without a board option leave the board alone
+            let Some(option) = local_option else { continue };
+            match set_item_status(client, &board, &item.id, &option).await {
+                Ok(()) => {
+                    sync_state.board_statuses.insert(number, option);
+                    true
+                }
+                Err(e) => {
+                    report.errors.push(e);
+                    false
+                }
+            }
+        } else {
+            let Some((option, status)) = board_option.and_then(|o| config.status_for(o).map(|s| (o, s))) else { continue };
+            set_issue_status(issue, status, &now_str);
+            sync_state.board_statuses.insert(number, option.to_string());
+            true
+        };
+
+        if !applied {
+            continue;
+        }
+        if conflict {
+            report.conflicts.push(SyncChange {
+                winner: Some(if local_wins { "local" } else { "remote" }.to_string()),
+                ..change
+            });
+        } else if local_wins {
+            report.pushed.push(change);
+        } else {
+            report.pulled.push(change);
+        }
+    }
+
+    // Completed issues live in the archive file
+    let (completed, pending): (Vec<_>, Vec<_>) = issues.into_iter().partition(|i| i.status == "completed");
+    issues_file.issues = pending;
+    issues_archive.issues = completed;
+    write_issues_file(project_path, &issues_file)?;
+    write_issues_archive_file(project_path, &issues_archive)?;
+    github::write_sync_state(project_path, &sync_state)
+}
+
+async fn sync_board_with_settings(
+    settings: &Settings,
+    project_path: &Path,
+    repo_url: &str,
+    config: &GithubBoardConfig,
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    let TrackerConfig { kind, api_url, token, location } = tracker::resolve_tracker(settings, Some(project_path), repo_url)?;
+    if kind != TrackerKind::Github {
+        return Err("GitHub project boards are only available for GitHub repositories".to_string());
+    }
+    let client = github::build_client(github::graphql_base_url(&api_url), &token)?;
+    sync_board(&client, &location.owner, &location.repo, project_path, config, report).await
+}
+
+/// Sync the project's board, if it has one, as part of a wider sync
+///
+/// Failures are recorded in the report so they don't undo the issue sync that ran before.
+pub(crate) async fn sync_configured_board(settings: &Settings, project_path: &Path, repo_url: &str, report: &mut SyncReport) {
+    let result = match read_board_config(project_path) {
+        Ok(Some(config)) => sync_board_with_settings(settings, project_path, repo_url, &config, report).await,
+        Ok(None) => Ok(()),
+        Err(e) => Err(e),
+    };
+
+    if let Err(e) = result {
+        report.errors.push(e);
+    }
+}
+
+/// Get the GitHub project board a project's issue statuses are synced with (None if there isn't one)
+#[tauri::command]
+pub async fn get_github_board_config(project_path: String) -> Result<Option<GithubBoardConfig>, String> {
+    read_board_config(Path::new(&project_path))
+}
+
+/// Set the GitHub project board for a project, or turn board sync off with None
+#[tauri::command]
+pub async fn update_github_board_config(
+    project_path: String,
+    config: Option<GithubBoardConfig>,
+) -> Result<(), String> {
+    if let Some(config) = &config {
+        if config.owner.trim().is_empty() || config.number == 0 {
+            return Err("A project board needs an owner and a project number".to_string());
+        }
+        if config.status_field.trim().is_empty() {
+            return Err("A project board needs a status field".to_string());
+        }
+        if let Some(mapping) = config.status_options.iter().find(|m| !ISSUE_STATUSES.contains(&m.status.as_str())) {
+            return Err(format!("Unknown issue status '{}'", mapping.status));
+        }
+    }
+
+    write_board_config(Path::new(&project_path), config.as_ref())
+}
+
+/// Sync issue statuses and board membership with the project's GitHub project board
+#[tauri::command]
+pub async fn sync_github_board(
+    app: AppHandle,
+    project_path: String,
+    github_url: String,
+) -> Result<SyncReport, String> {
+    let path = Path::new(&project_path);
+    let config = read_board_config(path)?
+        .ok_or_else(|| "No GitHub project board is configured for this project".to_string())?;
+    let settings = read_settings(&app)?;
+
+    let mut report = SyncReport {
+        tracker: Some(TrackerKind::Github),
+        ..Default::default()
+    };
+    sync_board_with_settings(&settings, path, &github_url, &config, &mut report).await?;
+    report.synced_at = Utc::now().to_rfc3339();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::IssueFile;
+    use serde_json::json;
+    use uuid::Uuid;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn linked_issue(number: u64, status: &str) -> Issue {
+        serde_json::from_value(json!({
+            "id": format!("issue-{}", number),
+            "title": format!("Issue {}", number),
+            "description": "",
+            "subtasks": [],
+            "complexity": 2,
+            "priority": 3,
+            "status": status,
+            "createdAt": "2024-01-01T00:00:00Z",
+            "githubIssueNumber": number,
+        }))
+        .unwrap()
+    }
+
+    fn board_item(number: u64, status: &str) -> serde_json::Value {
+        json!({
+            "id": format!("item-{}", number),
+            "content": { "number": number, "repository": { "nameWithOwner": "owner/repo" } },
+            "fieldValueByName": { "name": status, "updatedAt": "2024-02-01T00:00:00Z" },
+        })
+    }
+
+    async fn mount_graphql(server: &MockServer, marker: &str, data: serde_json::Value, calls: u64) {
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains(marker))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": data })))
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_board_statuses_are_pulled_pushed_and_missing_items_added() {
+        let project = std::env::temp_dir().join(format!("vibe-hub-board-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(project.join(".vibe")).unwrap();
+        let issues = vec![linked_issue(1, "in-progress"), linked_issue(2, "pending"), linked_issue(3, "pending")];
+        write_issues_file(&project, &IssueFile { issues }).unwrap();
+        let mut state = github::read_sync_state(&project);
+        state.board_statuses = [(1, "Todo".to_string()), (2, "Todo".to_string())].into();
+        github::write_sync_state(&project, &state).unwrap();
+
+        let server = MockServer::start().await;
+        mount_graphql(&server, "repositoryOwner", json!({ "repositoryOwner": { "projectV2": {
+            "id": "board",
+            "field": { "id": "status-field", "options": [
+                { "id": "opt-todo", "name": "Todo" },
+                { "id": "opt-progress", "name": "In Progress" },
+                { "id": "opt-done", "name": "Done" },
+            ] },
+            "items": {
+                "pageInfo": { "hasNextPage": false, "endCursor": null },
+                "nodes": [board_item(1, "Todo"), board_item(2, "Done")],
+            },
+        } } }), 1).await;
+        mount_graphql(&server, "issue(number", json!({ "repository": { "issue": { "id": "issue-node-3" } } }), 1).await;
+        mount_graphql(&server, "addProjectV2ItemById", json!({ "addProjectV2ItemById": { "item": { "id": "item-3" } } }), 1).await;
+        // Issue 1 moved locally, and the newly added issue 3 gets its status set
+        mount_graphql(&server, "updateProjectV2ItemFieldValue", json!({ "updateProjectV2ItemFieldValue": { "projectV2Item": { "id": "item" } } }), 2).await;
+
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        let config: GithubBoardConfig = serde_json::from_value(json!({ "owner": "owner", "number": 1 })).unwrap();
+        let mut report = SyncReport::default();
+        sync_board(&client, "owner", "repo", &project, &config, &mut report).await.unwrap();
+
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert_eq!(report.pushed.iter().map(|c| c.issue_number).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(report.pulled.iter().map(|c| c.issue_number).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(report.board_items_added, 1);
+
+        let archived = read_issues_archive_file(&project).unwrap().issues;
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].github_issue_number, Some(2));
+        let statuses = github::read_sync_state(&project).board_statuses;
+        assert_eq!(statuses.get(&1).map(String::as_str), Some("In Progress"));
+        assert_eq!(statuses.get(&3).map(String::as_str), Some("Todo"));
+    }
+}
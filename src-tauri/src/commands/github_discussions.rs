@@ -0,0 +1,479 @@
+// Importing GitHub Discussions as feedback
+// Discussions in the categories chosen in the project's mapping rules become pending feedback,
+// with their comments as the thread. Discussions are only reachable through GraphQL.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use octocrab::Octocrab;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+use uuid::Uuid;
+
+use crate::commands::github::{self, Connection, RemoteComment, RemoteUser};
+use crate::commands::github_mapping::read_mapping_rules;
+use crate::commands::github_sync::merge_remote_comments;
+use crate::commands::issues::{
+    read_archived_feedback, read_feedback_file, write_feedback_file, FEEDBACK_COMPLETED_FILE, FEEDBACK_FILE,
+};
+use crate::commands::tracker::{self, TrackerConfig};
+use crate::models::{FeedbackItem, GithubMappingRules, Settings, SyncChange, SyncReport, TrackerKind};
+
+const DISCUSSIONS_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    discussions(first: 50, after: $cursor, orderBy: { field: UPDATED_AT, direction: DESC }) {
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        number
+        title
+        body
+        url
+        closed
+        updatedAt
+        category { name }
+        labels(first: 20) { nodes { name } }
+        comments(first: 100) {
+          pageInfo { hasNextPage endCursor }
+          nodes { databaseId body createdAt author { login } }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const COMMENTS_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    discussion(number: $number) {
+      comments(first: 100, after: $cursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes { databaseId body createdAt author { login } }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+struct RepositoryData {
+    repository: Option<DiscussionsData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscussionsData {
+    discussions: Connection<Discussion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommentsRepositoryData {
+    repository: Option<CommentsDiscussionData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommentsDiscussionData {
+    discussion: Option<DiscussionComments>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscussionComments {
+    comments: Connection<DiscussionComment>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Discussion {
+    number: u64,
+    title: String,
+    body: String,
+    url: String,
+    #[serde(default)]
+    closed: bool,
+    updated_at: DateTime<Utc>,
+    category: Option<Named>,
+    labels: Option<Connection<Named>>,
+    comments: Connection<DiscussionComment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Named {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscussionComment {
+    database_id: u64,
+    body: String,
+    created_at: DateTime<Utc>,
+    author: Option<Login>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Login {
+    login: String,
+}
+
+impl Discussion {
+    fn in_categories(&self, categories: &[String]) -> bool {
+        self.category
+            .as_ref()
+            .map(|c| categories.iter().any(|wanted| wanted.eq_ignore_ascii_case(&c.name)))
+            .unwrap_or(false)
+    }
+
+    fn label_names(&self) -> Vec<String> {
+        self.labels
+            .as_ref()
+            .map(|l| l.nodes.iter().map(|n| n.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn feedback_text(&self) -> String {
+        let body = self.body.trim();
+        if body.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{}\n\n{}", self.title, body)
+        }
+    }
+
+    /// Comments in the shape the issue sync uses, so threads merge the same way
+    fn remote_comments(&self) -> Vec<RemoteComment> {
+        self.comments
+            .nodes
+            .iter()
+            .map(|c| RemoteComment {
+                id: c.database_id,
+                body: Some(c.body.clone()),
+                user: c.author.as_ref().map(|a| RemoteUser { login: a.login.clone() }),
+                created_at: c.created_at,
+            })
+            .collect()
+    }
+}
+
+/// Fetch the comments beyond the first page that came with a discussion
+async fn fetch_remaining_comments(client: &Octocrab, owner: &str, repo: &str, discussion: &mut Discussion) -> Result<(), String> {
+    while let Some(cursor) = discussion.comments.next_cursor() {
+        let variables = serde_json::json!({ "owner": owner, "repo": repo, "number": discussion.number, "cursor": cursor });
+        let data: CommentsRepositoryData = github::graphql(client, COMMENTS_QUERY, variables)
+            .await
+            .map_err(|e| format!("Failed to fetch comments of discussion #{}: {}", discussion.number, e))?;
+        let Some(page) = data.repository.and_then(|r| r.discussion).map(|d| d.comments) else {
+            return Err(format!("Discussion #{} not found", discussion.number));
+        };
+
+        discussion.comments.nodes.extend(page.nodes);
+        discussion.comments.page_info = page.page_info;
+    }
+    Ok(())
+}
+
+/// List discussions, newest activity first, stopping at those last updated before `since`
+///
+/// Discussions in `categories` get their full comment thread; the rest only the first page.
+async fn list_discussions(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    since: Option<DateTime<Utc>>,
+    categories: &[String],
+) -> Result<Vec<Discussion>, String> {
+    let mut discussions = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let variables = serde_json::json!({ "owner": owner, "repo": repo, "cursor": cursor });
+        let data: RepositoryData = github::graphql(client, DISCUSSIONS_QUERY, variables)
+            .await
+            .map_err(|e| format!("Failed to fetch GitHub Discussions: {}", e))?;
+        let Some(page) = data.repository.map(|r| r.discussions) else {
+            return Err(format!("Repository {}/{} not found", owner, repo));
+        };
+
+        let next = page.next_cursor();
+        let mut reached_since = false;
+        for mut discussion in page.nodes {
+            if since.map(|since| discussion.updated_at < since).unwrap_or(false) {
+                reached_since = true;
+                break;
+            }
+            if discussion.in_categories(categories) {
+                fetch_remaining_comments(client, owner, repo, &mut discussion).await?;
+            }
+            discussions.push(discussion);
+        }
+
+        match next {
+            Some(next) if !reached_since => cursor = Some(next),
+            _ => break,
+        }
+    }
+
+    Ok(discussions)
+}
+
+/// Import new discussions in the chosen categories and refresh the threads of imported ones
+fn apply_discussions(
+    project_path: &Path,
+    discussions: &[Discussion],
+    rules: &GithubMappingRules,
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    let mut feedback_file = read_feedback_file(project_path, FEEDBACK_FILE)?;
+    let completed = read_feedback_file(project_path, FEEDBACK_COMPLETED_FILE).unwrap_or_default();
+    let archived = read_archived_feedback(project_path).unwrap_or_default();
+
+    let tracked: HashSet<u64> = feedback_file
+        .feedback
+        .iter()
+        .chain(completed.feedback.iter())
+        .chain(archived.feedback.iter())
+        .filter_map(|f| f.github_discussion_number)
+        .collect();
+
+    let now = Utc::now().to_rfc3339();
+    let mut changed = false;
+
+    for item in feedback_file.feedback.iter_mut() {
+        let Some(discussion) = item.github_discussion_number.and_then(|n| discussions.iter().find(|d| d.number == n)) else {
+            continue;
+        };
+        let local_comments = item.comments.clone().unwrap_or_default();
+        let remote_comments = discussion.remote_comments();
+        let known: Vec<_> = local_comments.iter().filter_map(|c| c.github_comment_id).collect();
+        if known.len() == remote_comments.len() && remote_comments.iter().all(|rc| known.contains(&rc.id)) {
+            continue;
+        }
+
+        let mut thread = merge_remote_comments(&local_comments, &remote_comments);
+        thread.extend(local_comments.into_iter().filter(|c| c.github_comment_id.is_none()));
+        item.comments = if thread.is_empty() { None } else { Some(thread) };
+        changed = true;
+        report.pulled.push(SyncChange {
+            issue_number: discussion.number,
+            local_id: item.id.clone(),
+            kind: "feedback".to_string(),
+            fields: vec!["comments".to_string()],
+            winner: None,
+        });
+    }
+
+    for discussion in discussions {
+        if tracked.contains(&discussion.number) || discussion.closed || !discussion.in_categories(&rules.discussion_categories) {
+            continue;
+        }
+        let labels = discussion.label_names();
+        if rules.should_skip(&labels) {
+            continue;
+        }
+
+        let thread = merge_remote_comments(&[], &discussion.remote_comments());
+        feedback_file.feedback.push(FeedbackItem {
+            id: Uuid::new_v4().to_string(),
+            text: discussion.feedback_text(),
+            priority: rules.priority_for(&labels, None).unwrap_or(rules.default_priority),
+            status: "pending".to_string(),
+            created_at: now.clone(),
+            completed_at: None,
+            refined_into_issue_ids: None,
+            review_notes: None,
+            related_issue_id: None,
+            github_issue_number: None,
+            github_issue_url: None,
+            updated_at: None,
+            labels: Some(rules.local_labels(&labels)),
+            comments: if thread.is_empty() { None } else { Some(thread) },
+            github_synced_at: Some(now.clone()),
+            github_updated_at: Some(discussion.updated_at.to_rfc3339()),
+            github_discussion_number: Some(discussion.number),
+            github_discussion_url: Some(discussion.url.clone()),
+        });
+        report.discussions_imported += 1;
+        changed = true;
+    }
+
+    if changed {
+        write_feedback_file(project_path, FEEDBACK_FILE, &feedback_file)?;
+    }
+    Ok(())
+}
+
+/// Fetch discussions updated since the project's cursor and apply them
+pub(crate) async fn import_discussions(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    project_path: &Path,
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    let rules = read_mapping_rules(project_path);
+    if rules.discussion_categories.is_empty() {
+        return Ok(());
+    }
+
+    let mut sync_state = github::read_sync_state(project_path);
+    let since = sync_state
+        .discussions_since
+        .as_deref()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Utc));
+
+    let discussions = list_discussions(client, owner, repo, since, &rules.discussion_categories).await?;
+    apply_discussions(project_path, &discussions, &rules, report)?;
+
+    if let Some(latest) = discussions.iter().map(|d| d.updated_at).max() {
+        sync_state.discussions_since = Some(latest.to_rfc3339_opts(SecondsFormat::Secs, true));
+        github::write_sync_state(project_path, &sync_state)?;
+    }
+    Ok(())
+}
+
+/// Import discussions for a project if its mapping rules pick any categories
+///
+/// Only GitHub has Discussions; other trackers are skipped. Failures are recorded in the
+/// report so they don't undo the issue sync that ran before.
+pub(crate) async fn sync_discussions(settings: &Settings, project_path: &Path, repo_url: &str, report: &mut SyncReport) {
+    if read_mapping_rules(project_path).discussion_categories.is_empty() {
+        return;
+    }
+
+    let result = async {
+        let TrackerConfig { kind, api_url, token, location } = tracker::resolve_tracker(settings, Some(project_path), repo_url)?;
+        if kind != TrackerKind::Github {
+            return Ok(());
+        }
+        let client = github::build_client(github::graphql_base_url(&api_url), &token)?;
+        import_discussions(&client, &location.owner, &location.repo, project_path, report).await
+    }
+    .await;
+
+    if let Err(e) = result {
+        report.errors.push(e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::github_mapping::write_mapping_rules;
+    use serde_json::json;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn discussion(number: u64, category: &str, comments: &[(u64, &str)]) -> serde_json::Value {
+        json!({
+            "number": number,
+            "title": format!("Idea {}", number),
+            "body": "It would be nice if...",
+            "url": format!("https://github.com/owner/repo/discussions/{}", number),
+            "closed": false,
+            "updatedAt": "2024-02-01T00:00:00Z",
+            "category": { "name": category },
+            "labels": { "nodes": [] },
+            "comments": { "nodes": comments.iter().map(|(id, body)| json!({
+                "databaseId": id,
+                "body": body,
+                "createdAt": "2024-02-01T00:00:00Z",
+                "author": { "login": "user" },
+            })).collect::<Vec<_>>() },
+        })
+    }
+
+    async fn mount_discussions(server: &MockServer, nodes: Vec<serde_json::Value>) {
+        server.reset().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "repository": { "discussions": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": nodes,
+                } } }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_discussions_in_chosen_categories_are_imported_with_threads() {
+        let project = std::env::temp_dir().join(format!("vibe-hub-discussions-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(project.join(".vibe")).unwrap();
+        let rules = GithubMappingRules {
+            discussion_categories: vec!["ideas".to_string()],
+            ..Default::default()
+        };
+        write_mapping_rules(&project, &rules).unwrap();
+
+        let server = MockServer::start().await;
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        mount_discussions(&server, vec![
+            discussion(1, "Ideas", &[(10, "Steps to reproduce: ...")]),
+            discussion(2, "Q&A", &[]),
+        ]).await;
+
+        let mut report = SyncReport::default();
+        import_discussions(&client, "owner", "repo", &project, &mut report).await.unwrap();
+        assert_eq!(report.discussions_imported, 1);
+
+        // A later fetch adds the new comment to the thread without importing again
+        mount_discussions(&server, vec![discussion(1, "Ideas", &[(10, "Steps to reproduce: ..."), (11, "Same here")])]).await;
+        let mut report = SyncReport::default();
+        import_discussions(&client, "owner", "repo", &project, &mut report).await.unwrap();
+        assert_eq!(report.discussions_imported, 0);
+        assert_eq!(report.pulled.len(), 1);
+
+        let feedback = read_feedback_file(&project, FEEDBACK_FILE).unwrap().feedback;
+        assert_eq!(feedback.len(), 1);
+        assert_eq!(feedback[0].github_discussion_number, Some(1));
+        let thread = feedback[0].comments.as_ref().unwrap();
+        assert_eq!(thread.iter().map(|c| c.body.as_str()).collect::<Vec<_>>(), vec!["Steps to reproduce: ...", "Same here"]);
+    }
+
+    #[tokio::test]
+    async fn test_discussion_comments_beyond_the_first_page_are_fetched() {
+        let project = std::env::temp_dir().join(format!("vibe-hub-discussions-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(project.join(".vibe")).unwrap();
+        let rules = GithubMappingRules {
+            discussion_categories: vec!["ideas".to_string()],
+            ..Default::default()
+        };
+        write_mapping_rules(&project, &rules).unwrap();
+
+        let server = MockServer::start().await;
+        let mut first_page = discussion(1, "Ideas", &[(10, "First")]);
+        first_page["comments"]["pageInfo"] = json!({ "hasNextPage": true, "endCursor": "c1" });
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("discussions(first"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "repository": { "discussions": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": [first_page],
+                } } }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("discussion(number"))
+            .and(body_string_contains("\"cursor\":\"c1\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "repository": { "discussion": { "comments": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": [{ "databaseId": 11, "body": "Second", "createdAt": "2024-02-02T00:00:00Z", "author": null }],
+                } } } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        let mut report = SyncReport::default();
+        import_discussions(&client, "owner", "repo", &project, &mut report).await.unwrap();
+
+        let feedback = read_feedback_file(&project, FEEDBACK_FILE).unwrap().feedback;
+        let thread = feedback[0].comments.as_ref().unwrap();
+        assert_eq!(thread.iter().map(|c| c.body.as_str()).collect::<Vec<_>>(), vec!["First", "Second"]);
+    }
+}
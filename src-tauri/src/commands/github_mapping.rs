@@ -0,0 +1,189 @@
+// Per-project rules for translating GitHub labels and milestones into local fields
+// Rules live in .vibe/github-mapping.json and are applied on import, pull and (in reverse) push.
+
+use std::fs;
+use std::path::Path;
+
+use crate::models::GithubMappingRules;
+
+fn contains_label(labels: &[String], label: &str) -> bool {
+    labels.iter().any(|l| l.eq_ignore_ascii_case(label))
+}
+
+impl GithubMappingRules {
+    /// Whether a label is mapped to a priority (GitHub label names are case-insensitive)
+    pub(crate) fn is_priority_label(&self, label: &str) -> bool {
+        self.priority_labels.keys().any(|l| l.eq_ignore_ascii_case(label))
+    }
+
+    /// Whether a label is copied into local labels
+    pub(crate) fn is_copied_label(&self, label: &str) -> bool {
+        if self.is_priority_label(label) {
+            return false;
+        }
+        match &self.copy_labels {
+            Some(copy) => contains_label(copy, label),
+            None => true,
+        }
+    }
+
+    /// Whether an issue with these labels should be left out of imports
+    pub(crate) fn should_skip(&self, labels: &[String]) -> bool {
+        labels.iter().any(|l| contains_label(&self.skip_labels, l))
+    }
+
+    /// Priority implied by an issue's labels (most urgent wins), falling back to its milestone
+    pub(crate) fn priority_for(&self, labels: &[String], milestone: Option<&str>) -> Option<u8> {
+        let from_labels = self
+            .priority_labels
+            .iter()
+            .filter(|(label, _)| contains_label(labels, label))
+            .map(|(_, priority)| *priority)
+            .min();
+
+        from_labels.or_else(|| {
+            let milestone = milestone?;
+            self.milestone_priorities
+                .iter()
+                .find(|(title, _)| title.eq_ignore_ascii_case(milestone))
+                .map(|(_, priority)| *priority)
+        })
+    }
+
+    /// Label to put on GitHub for a local priority (alphabetically first if several map to it)
+    pub(crate) fn label_for_priority(&self, priority: u8) -> Option<&str> {
+        self.priority_labels
+            .iter()
+            .find(|(_, p)| **p == priority)
+            .map(|(label, _)| label.as_str())
+    }
+
+    /// Local labels for an issue with the given GitHub labels
+    pub(crate) fn local_labels(&self, remote_labels: &[String]) -> Vec<String> {
+        remote_labels.iter().filter(|l| self.is_copied_label(l)).cloned().collect()
+    }
+
+    /// GitHub labels for a local item, the reverse of local_labels and priority_for
+    ///
+    /// Labels the rules don't manage are kept as they are on GitHub. Priority labels are
+    /// only replaced when they no longer match the local priority.
+    pub(crate) fn remote_labels(&self, local_labels: &[String], priority: u8, remote_labels: &[String]) -> Vec<String> {
+        let mut labels: Vec<String> = remote_labels
+            .iter()
+            .filter(|l| !self.is_priority_label(l) && !self.is_copied_label(l))
+            .cloned()
+            .collect();
+
+        for label in local_labels.iter().filter(|l| self.is_copied_label(l)) {
+            if !contains_label(&labels, label) {
+                labels.push(label.clone());
+            }
+        }
+
+        if self.priority_for(remote_labels, None) == Some(priority) {
+            labels.extend(remote_labels.iter().filter(|l| self.is_priority_label(l)).cloned());
+        } else if let Some(label) = self.label_for_priority(priority) {
+            labels.push(label.to_string());
+        }
+
+        labels
+    }
+}
+
+/// Read the project's GitHub mapping rules, falling back to defaults (copy all labels, priority 3)
+pub(crate) fn read_mapping_rules(project_path: &Path) -> GithubMappingRules {
+    let rules_path = project_path.join(".vibe").join("github-mapping.json");
+
+    fs::read_to_string(&rules_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Write the project's GitHub mapping rules
+pub(crate) fn write_mapping_rules(project_path: &Path, rules: &GithubMappingRules) -> Result<(), String> {
+    let vibe_dir = project_path.join(".vibe");
+    fs::create_dir_all(&vibe_dir)
+        .map_err(|e| format!("Failed to create .vibe directory: {}", e))?;
+
+    let content = serde_json::to_string_pretty(rules)
+        .map_err(|e| format!("Failed to serialize GitHub mapping rules: {}", e))?;
+
+    fs::write(vibe_dir.join("github-mapping.json"), content)
+        .map_err(|e| format!("Failed to write GitHub mapping rules: {}", e))
+}
+
+/// Get the GitHub label/milestone mapping rules for a project
+#[tauri::command]
+pub async fn get_github_mapping_rules(project_path: String) -> Result<GithubMappingRules, String> {
+    Ok(read_mapping_rules(Path::new(&project_path)))
+}
+
+/// Save the GitHub label/milestone mapping rules for a project
+#[tauri::command]
+pub async fn update_github_mapping_rules(
+    project_path: String,
+    rules: GithubMappingRules,
+) -> Result<(), String> {
+    let out_of_range = rules
+        .priority_labels
+        .values()
+        .chain(rules.milestone_priorities.values())
+        .chain(std::iter::once(&rules.default_priority))
+        .any(|p| !(1..=5).contains(p));
+    if out_of_range {
+        return Err("Priorities must be between 1 and 5".to_string());
+    }
+
+    write_mapping_rules(Path::new(&project_path), &rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn rules() -> GithubMappingRules {
+        GithubMappingRules {
+            priority_labels: [("P0".to_string(), 1), ("P1".to_string(), 2), ("bug".to_string(), 2)].into(),
+            milestone_priorities: [("v1.0".to_string(), 2)].into(),
+            copy_labels: Some(labels(&["frontend", "backend"])),
+            skip_labels: labels(&["wontfix"]),
+            discussion_categories: Vec::new(),
+            default_priority: 3,
+        }
+    }
+
+    #[test]
+    fn test_priority_from_labels_then_milestone() {
+        let rules = rules();
+        assert_eq!(rules.priority_for(&labels(&["bug", "p0"]), None), Some(1));
+        assert_eq!(rules.priority_for(&labels(&["docs"]), Some("v1.0")), Some(2));
+        assert_eq!(rules.priority_for(&labels(&["docs"]), None), None);
+    }
+
+    #[test]
+    fn test_labels_are_filtered_and_skipped() {
+        let rules = rules();
+        assert_eq!(rules.local_labels(&labels(&["P0", "frontend", "docs"])), labels(&["frontend"]));
+        assert!(rules.should_skip(&labels(&["WontFix"])));
+        assert!(!rules.should_skip(&labels(&["bug"])));
+    }
+
+    #[test]
+    fn test_remote_labels_reverse_mapping() {
+        let rules = rules();
+        let remote = labels(&["docs", "P1", "backend"]);
+
+        // Priority raised locally to 1 and "backend" swapped for "frontend"
+        let pushed = rules.remote_labels(&labels(&["frontend"]), 1, &remote);
+        assert_eq!(pushed, labels(&["docs", "frontend", "P0"]));
+
+        // "bug" already implies priority 2, so it's kept rather than swapped for "P1"
+        let pushed = rules.remote_labels(&[], 2, &labels(&["bug"]));
+        assert_eq!(pushed, labels(&["bug"]));
+    }
+}
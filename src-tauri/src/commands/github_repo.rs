@@ -0,0 +1,280 @@
+// Publishing a newly created project to a new GitHub repository
+// Creates the repository through the API, commits and pushes the initial tree, then records the
+// repository in .vibe/metadata.md so sync picks it up.
+
+use base64::Engine;
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::commands::github;
+use crate::commands::projects::git_command;
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// How to publish a new project on GitHub
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubRepoOptions {
+    pub private: bool,
+}
+
+/// The fields of a created repository that publishing needs
+#[derive(Debug, Deserialize)]
+struct CreatedRepository {
+    html_url: String,
+    clone_url: String,
+}
+
+/// Create a repository for the authenticated user
+async fn create_repository(
+    client: &Octocrab,
+    name: &str,
+    description: Option<&str>,
+    private: bool,
+) -> Result<CreatedRepository, String> {
+    let mut body = serde_json::json!({ "name": name, "private": private });
+    if let Some(description) = description {
+        body["description"] = serde_json::Value::String(description.to_string());
+    }
+
+    client
+        .post("/user/repos", Some(&body))
+        .await
+        .map_err(|e| format!("Failed to create GitHub repository '{}': {}", name, e))
+}
+
+/// Run a git command in the project with extra environment variables, returning stderr as the error
+fn run_git_with_env(project_path: &Path, args: &[&str], envs: &[(&str, &str)]) -> Result<(), String> {
+    let output = git_command()
+        .args(args)
+        .envs(envs.iter().copied())
+        .current_dir(project_path)
+        .output()
+        .map_err(|e| format!("Failed to run git {}: {}", args[0], e))?;
+
+    if !output.status.success() {
+        return Err(format!("Git {} failed: {}", args[0], String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(())
+}
+
+/// Run a git command in the project, returning stderr as the error
+fn run_git(project_path: &Path, args: &[&str]) -> Result<(), String> {
+    run_git_with_env(project_path, args, &[])
+}
+
+/// Stage everything and make the initial commit
+fn commit_initial_tree(project_path: &Path) -> Result<(), String> {
+    run_git(project_path, &["add", "-A"])?;
+    run_git(project_path, &["commit", "--allow-empty", "-m", "Initial commit"])
+}
+
+/// Point origin at the new repository and push to it
+///
+/// The token is passed to the push as an HTTP header through git's environment config, so it
+/// never ends up in .git/config or on the process command line.
+fn push_to_origin(project_path: &Path, clone_url: &str, token: &str) -> Result<(), String> {
+    // A failed earlier attempt may have left origin behind
+    match run_git(project_path, &["remote", "get-url", "origin"]) {
+        Ok(()) => run_git(project_path, &["remote", "set-url", "origin", clone_url])?,
+        Err(_) => run_git(project_path, &["remote", "add", "origin", clone_url])?,
+    }
+
+    let credentials = base64::engine::general_purpose::STANDARD.encode(format!("x-access-token:{}", token));
+    let auth_header = format!("Authorization: Basic {}", credentials);
+    run_git_with_env(
+        project_path,
+        &["push", "-u", "origin", "HEAD"],
+        &[
+            ("GIT_CONFIG_COUNT", "1"),
+            ("GIT_CONFIG_KEY_0", "http.extraheader"),
+            ("GIT_CONFIG_VALUE_0", &auth_header),
+        ],
+    )
+}
+
+/// Record the repository in metadata.md, enabling sync for it
+fn write_github_metadata(project_path: &Path, web_url: &str) -> Result<(), String> {
+    let metadata_path = project_path.join(".vibe").join("metadata.md");
+    let content = fs::read_to_string(&metadata_path)
+        .map_err(|e| format!("Failed to read metadata: {}", e))?;
+
+    let mut updated_content = String::new();
+    let mut added = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("GitHub:") || trimmed.starts_with("GitHubSync:") {
+            continue;
+        }
+        updated_content.push_str(line);
+        updated_content.push('\n');
+
+        if !added && trimmed.starts_with("TextColor:") {
+            updated_content.push_str(&format!("GitHub: {}\nGitHubSync: true\n", web_url));
+            added = true;
+        }
+    }
+    if !added {
+        updated_content.push_str(&format!("GitHub: {}\nGitHubSync: true\n", web_url));
+    }
+
+    fs::write(&metadata_path, updated_content)
+        .map_err(|e| format!("Failed to write metadata: {}", e))
+}
+
+/// Create a GitHub repository for a freshly initialised project and push it
+///
+/// The repository is named after the project folder and described by its summary.
+/// Returns the repository's web URL.
+pub(crate) async fn publish_project(
+    client: &Octocrab,
+    token: &str,
+    project_path: &Path,
+    summary: Option<&str>,
+    options: &GithubRepoOptions,
+) -> Result<String, String> {
+    let name = project_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| "Invalid project path".to_string())?;
+
+    // Commit before creating the repository so a local failure doesn't leave an empty one behind
+    commit_initial_tree(project_path)?;
+    let repository = create_repository(client, name, summary, options.private).await?;
+    push_to_origin(project_path, &repository.clone_url, token)?;
+    write_github_metadata(project_path, &repository.html_url)?;
+
+    Ok(repository.html_url)
+}
+
+/// Publish a project with the GitHub token from settings
+pub(crate) async fn publish_project_from_settings(
+    app: &tauri::AppHandle,
+    project_path: &Path,
+    summary: Option<&str>,
+    options: &GithubRepoOptions,
+) -> Result<String, String> {
+    let token = github::read_settings(app)?
+        .github_token
+        .ok_or_else(|| "GitHub token not configured. Please add your token in Settings.".to_string())?;
+
+    let client = github::build_client(GITHUB_API_URL, &token)?;
+    publish_project(&client, &token, project_path, summary, options).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::path::PathBuf;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = git_command().args(args).current_dir(dir).output().unwrap();
+        assert!(output.status.success(), "git {:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn new_project(root: &Path) -> PathBuf {
+        let project = root.join("my-app");
+        fs::create_dir_all(project.join(".vibe")).unwrap();
+        fs::write(project.join(".vibe").join("metadata.md"), "Name: My App\nStatus: initialized\nColor: #fff\nTextColor: #000\n").unwrap();
+        fs::write(project.join(".gitignore"), ".vibe/\n").unwrap();
+        git(&project, &["init"]);
+        git(&project, &["config", "user.name", "Test"]);
+        git(&project, &["config", "user.email", "test@example.com"]);
+        project
+    }
+
+    #[tokio::test]
+    async fn test_publish_creates_repository_and_pushes_to_it() {
+        let root = std::env::temp_dir().join(format!("vibe-hub-publish-test-{}", uuid::Uuid::new_v4()));
+        let project = new_project(&root);
+        let remote = root.join("remote.git");
+        fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--bare"]);
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/user/repos"))
+            .and(body_json(json!({ "name": "my-app", "private": true, "description": "A tiny app" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "html_url": "https://github.com/me/my-app",
+                "clone_url": remote.to_string_lossy(),
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        let options = GithubRepoOptions { private: true };
+        let url = publish_project(&client, "test-token", &project, Some("A tiny app"), &options).await.unwrap();
+
+        assert_eq!(url, "https://github.com/me/my-app");
+        assert_eq!(git(&project, &["remote", "get-url", "origin"]), remote.to_string_lossy());
+        assert_eq!(git(&remote, &["log", "--format=%s"]), "Initial commit");
+        assert_eq!(git(&remote, &["ls-tree", "--name-only", "HEAD"]), ".gitignore");
+
+        let metadata = fs::read_to_string(project.join(".vibe").join("metadata.md")).unwrap();
+        assert!(metadata.contains("TextColor: #000\nGitHub: https://github.com/me/my-app\nGitHubSync: true\n"));
+    }
+
+    #[tokio::test]
+    async fn test_publish_stops_when_repository_creation_fails() {
+        let root = std::env::temp_dir().join(format!("vibe-hub-publish-test-{}", uuid::Uuid::new_v4()));
+        let project = new_project(&root);
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/user/repos"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(json!({ "message": "name already exists on this account" })))
+            .mount(&server)
+            .await;
+
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        let result = publish_project(&client, "test-token", &project, None, &GithubRepoOptions { private: false }).await;
+
+        assert!(result.is_err());
+        assert!(git(&project, &["remote"]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_publish_does_not_create_repository_when_commit_fails() {
+        let root = std::env::temp_dir().join(format!("vibe-hub-publish-test-{}", uuid::Uuid::new_v4()));
+        let project = new_project(&root);
+        // A missing objects directory makes git commit fail before anything reaches GitHub
+        fs::remove_dir_all(project.join(".git").join("objects")).unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/user/repos"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        let result = publish_project(&client, "test-token", &project, None, &GithubRepoOptions { private: false }).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_push_reuses_origin_left_by_a_failed_attempt() {
+        let root = std::env::temp_dir().join(format!("vibe-hub-publish-test-{}", uuid::Uuid::new_v4()));
+        let project = new_project(&root);
+        commit_initial_tree(&project).unwrap();
+
+        // The first push fails because the remote doesn't exist yet
+        let remote = root.join("remote.git");
+        assert!(push_to_origin(&project, &remote.to_string_lossy(), "test-token").is_err());
+
+        fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--bare"]);
+        push_to_origin(&project, &remote.to_string_lossy(), "test-token").unwrap();
+        assert_eq!(git(&remote, &["log", "--format=%s"]), "Initial commit");
+    }
+}
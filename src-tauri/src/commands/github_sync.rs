@@ -0,0 +1,1012 @@
+// Two-way sync between local issues/feedback and their linked GitHub issues
+// Title, body, state, labels and comments flow in both directions. When both sides
+// changed since the last sync, the most recent edit wins (last-writer-wins).
+// Labels and priority go through the project's mapping rules (see github_mapping.rs).
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::HashSet;
+use std::path::Path;
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::commands::github::{self, read_settings, IssuePatch, RemoteComment, RemoteIssue};
+use crate::commands::{github_board, github_discussions};
+use crate::commands::outbound::SyncOutbox;
+use crate::commands::tracker::{self, IssueTracker};
+use crate::commands::github_mapping::read_mapping_rules;
+use crate::commands::issues::{
+    read_feedback_file, read_issues_archive_file, read_issues_file, write_feedback_file,
+    write_issues_archive_file, write_issues_file, FEEDBACK_COMPLETED_FILE, FEEDBACK_FILE,
+};
+use crate::models::{Comment, FeedbackItem, GithubMappingRules, Issue, OutboundAction, SyncChange, SyncReport};
+
+/// A local item that can be mirrored onto a GitHub issue
+trait SyncTarget {
+    fn local_id(&self) -> &str;
+    fn kind(&self) -> &'static str;
+    fn github_issue_number(&self) -> Option<u64>;
+    fn title(&self) -> String;
+    fn body(&self) -> String;
+    fn set_content(&mut self, title: &str, body: &str);
+    fn is_open(&self) -> bool;
+    fn set_open(&mut self, open: bool);
+    fn priority(&self) -> u8;
+    fn set_priority(&mut self, priority: u8);
+    fn labels(&self) -> Option<&Vec<String>>;
+    fn set_labels(&mut self, labels: Vec<String>);
+    fn comments_mut(&mut self) -> &mut Option<Vec<Comment>>;
+    fn updated_at(&self) -> Option<&str>;
+    fn synced_at(&self) -> Option<&str>;
+    fn github_updated_at(&self) -> Option<&str>;
+    fn mark_synced(&mut self, synced_at: &str, github_updated_at: &str);
+}
+
+impl SyncTarget for Issue {
+    fn local_id(&self) -> &str {
+        &self.id
+    }
+
+    fn kind(&self) -> &'static str {
+        "issue"
+    }
+
+    fn github_issue_number(&self) -> Option<u64> {
+        self.github_issue_number
+    }
+
+    fn title(&self) -> String {
+        self.title.trim().to_string()
+    }
+
+    fn body(&self) -> String {
+        self.description.trim().to_string()
+    }
+
+    fn set_content(&mut self, title: &str, body: &str) {
+        self.title = title.to_string();
+        self.description = body.to_string();
+    }
+
+    fn is_open(&self) -> bool {
+        self.status != "completed"
+    }
+
+    fn set_open(&mut self, open: bool) {
+        if open && self.status == "completed" {
+            self.status = "pending".to_string();
+            self.completed_at = None;
+        } else if !open && self.status != "completed" {
+            self.status = "completed".to_string();
+            self.completed_at = Some(Utc::now().to_rfc3339());
+        }
+    }
+
+    fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    fn set_priority(&mut self, priority: u8) {
+        self.priority = priority;
+    }
+
+    fn labels(&self) -> Option<&Vec<String>> {
+        self.labels.as_ref()
+    }
+
+    fn set_labels(&mut self, labels: Vec<String>) {
+        self.labels = Some(labels);
+    }
+
+    fn comments_mut(&mut self) -> &mut Option<Vec<Comment>> {
+        &mut self.comments
+    }
+
+    fn updated_at(&self) -> Option<&str> {
+        self.updated_at.as_deref()
+    }
+
+    fn synced_at(&self) -> Option<&str> {
+        self.github_synced_at.as_deref()
+    }
+
+    fn github_updated_at(&self) -> Option<&str> {
+        self.github_updated_at.as_deref()
+    }
+
+    fn mark_synced(&mut self, synced_at: &str, github_updated_at: &str) {
+        self.github_synced_at = Some(synced_at.to_string());
+        self.github_updated_at = Some(github_updated_at.to_string());
+    }
+}
+
+impl SyncTarget for FeedbackItem {
+    fn local_id(&self) -> &str {
+        &self.id
+    }
+
+    fn kind(&self) -> &'static str {
+        "feedback"
+    }
+
+    fn github_issue_number(&self) -> Option<u64> {
+        self.github_issue_number
+    }
+
+    // Feedback text is stored as "title\n\nbody", matching how issues are imported
+    fn title(&self) -> String {
+        self.text.lines().next().unwrap_or("").trim().to_string()
+    }
+
+    fn body(&self) -> String {
+        match self.text.split_once('\n') {
+            Some((_, rest)) => rest.trim().to_string(),
+            None => String::new(),
+        }
+    }
+
+    fn set_content(&mut self, title: &str, body: &str) {
+        self.text = if body.is_empty() {
+            title.to_string()
+        } else {
+            format!("{}\n\n{}", title, body)
+        };
+    }
+
+    fn is_open(&self) -> bool {
+        self.status != "completed" && self.status != "refined"
+    }
+
+    fn set_open(&mut self, open: bool) {
+        if open && self.status == "completed" {
+            self.status = "pending".to_string();
+            self.completed_at = None;
+        } else if !open && self.is_open() {
+            self.status = "completed".to_string();
+            self.completed_at = Some(Utc::now().to_rfc3339());
+        }
+    }
+
+    fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    fn set_priority(&mut self, priority: u8) {
+        self.priority = priority;
+    }
+
+    fn labels(&self) -> Option<&Vec<String>> {
+        self.labels.as_ref()
+    }
+
+    fn set_labels(&mut self, labels: Vec<String>) {
+        self.labels = Some(labels);
+    }
+
+    fn comments_mut(&mut self) -> &mut Option<Vec<Comment>> {
+        &mut self.comments
+    }
+
+    fn updated_at(&self) -> Option<&str> {
+        self.updated_at.as_deref()
+    }
+
+    fn synced_at(&self) -> Option<&str> {
+        self.github_synced_at.as_deref()
+    }
+
+    fn github_updated_at(&self) -> Option<&str> {
+        self.github_updated_at.as_deref()
+    }
+
+    fn mark_synced(&mut self, synced_at: &str, github_updated_at: &str) {
+        self.github_synced_at = Some(synced_at.to_string());
+        self.github_updated_at = Some(github_updated_at.to_string());
+    }
+}
+
+/// Which side's content should be kept for one linked item
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Resolution {
+    /// Never synced before: record a baseline and only pick up remote state and labels
+    Baseline,
+    /// Neither side changed since the last sync
+    Unchanged,
+    /// Apply the remote content locally
+    Pull,
+    /// Apply the local content remotely
+    Push,
+}
+
+fn parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Decide which way an item should flow. The flag is true when both sides changed.
+fn resolve(local: &impl SyncTarget, remote: &RemoteIssue) -> (Resolution, bool) {
+    let synced_at = match parse_time(local.synced_at()) {
+        Some(t) => t,
+        None => return (Resolution::Baseline, false),
+    };
+
+    let local_updated = parse_time(local.updated_at());
+    let local_changed = local_updated.map(|t| t > synced_at).unwrap_or(false);
+    let remote_changed = match parse_time(local.github_updated_at()) {
+        Some(known) => remote.updated_at > known,
+        None => remote.updated_at > synced_at,
+    };
+
+    match (local_changed, remote_changed) {
+        (false, false) => (Resolution::Unchanged, false),
+        (true, false) => (Resolution::Push, false),
+        (false, true) => (Resolution::Pull, false),
+        (true, true) => {
+            // Last writer wins
+            let local_is_newer = local_updated.map(|t| t > remote.updated_at).unwrap_or(false);
+            let resolution = if local_is_newer { Resolution::Push } else { Resolution::Pull };
+            (resolution, true)
+        }
+    }
+}
+
+fn sorted(labels: &[String]) -> Vec<String> {
+    let mut labels = labels.to_vec();
+    labels.sort();
+    labels
+}
+
+/// Fields whose local and remote values differ, as seen through the mapping rules
+fn differing_fields(local: &impl SyncTarget, remote: &RemoteIssue, rules: &GithubMappingRules) -> Vec<String> {
+    let mut fields = Vec::new();
+    if local.title() != remote.title.trim() {
+        fields.push("title".to_string());
+    }
+    if local.body() != remote.body_text() {
+        fields.push("body".to_string());
+    }
+    if local.is_open() != remote.is_open() {
+        fields.push("state".to_string());
+    }
+    if let Some(labels) = local.labels() {
+        if sorted(labels) != sorted(&rules.local_labels(&remote.label_names())) {
+            fields.push("labels".to_string());
+        }
+    }
+    let priority_differs = match rules.priority_for(&remote.label_names(), remote.milestone_title()) {
+        Some(priority) => priority != local.priority(),
+        // Nothing on GitHub implies a priority, so it only differs if we'd add a label for it
+        None => rules.label_for_priority(local.priority()).is_some(),
+    };
+    if priority_differs {
+        fields.push("priority".to_string());
+    }
+    fields
+}
+
+fn apply_remote(local: &mut impl SyncTarget, remote: &RemoteIssue, fields: &[String], rules: &GithubMappingRules) {
+    if fields.iter().any(|f| f == "title" || f == "body") {
+        local.set_content(remote.title.trim(), &remote.body_text());
+    }
+    if fields.iter().any(|f| f == "state") {
+        local.set_open(remote.is_open());
+    }
+    if fields.iter().any(|f| f == "priority") {
+        if let Some(priority) = rules.priority_for(&remote.label_names(), remote.milestone_title()) {
+            local.set_priority(priority);
+        }
+    }
+    local.set_labels(rules.local_labels(&remote.label_names()));
+}
+
+fn build_patch(local: &impl SyncTarget, remote: &RemoteIssue, fields: &[String], rules: &GithubMappingRules) -> IssuePatch {
+    let has = |name: &str| fields.iter().any(|f| f == name);
+    IssuePatch {
+        title: if has("title") { Some(local.title()) } else { None },
+        body: if has("body") { Some(local.body()) } else { None },
+        state: if has("state") {
+            Some(if local.is_open() { "open" } else { "closed" }.to_string())
+        } else {
+            None
+        },
+        labels: if has("labels") || has("priority") {
+            let remote_labels = remote.label_names();
+            let local_labels = local.labels().cloned().unwrap_or_else(|| rules.local_labels(&remote_labels));
+            Some(rules.remote_labels(&local_labels, local.priority(), &remote_labels))
+        } else {
+            None
+        },
+    }
+}
+
+/// Thread for a remote comment listing, keeping the local ids of comments we already know about
+pub(crate) fn merge_remote_comments(local_comments: &[Comment], remote_comments: &[RemoteComment]) -> Vec<Comment> {
+    remote_comments
+        .iter()
+        .map(|rc| {
+            let id = local_comments
+                .iter()
+                .find(|c| c.github_comment_id == Some(rc.id))
+                .map(|c| c.id.clone())
+                .unwrap_or_else(|| Uuid::new_v4().to_string());
+            Comment {
+                id,
+                author: rc.user.as_ref().map(|u| u.login.clone()).unwrap_or_default(),
+                body: rc.body.clone().unwrap_or_default(),
+                created_at: rc.created_at.to_rfc3339(),
+                github_comment_id: Some(rc.id),
+            }
+        })
+        .collect()
+}
+
+/// Pull remote comments and push local ones that haven't been posted yet.
+///
+/// Comments that fail to post are queued in the outbox and left to it from then on.
+/// Returns (pulled, pushed) flags.
+async fn sync_comments(
+    tracker: &dyn IssueTracker,
+    local: &mut impl SyncTarget,
+    remote: &RemoteIssue,
+    outbox: &SyncOutbox<'_>,
+) -> Result<(bool, bool), String> {
+    let local_comments = local.comments_mut().take().unwrap_or_default();
+    let known_remote = local_comments.iter().filter(|c| c.github_comment_id.is_some()).count() as u64;
+    let unpushed: Vec<Comment> = local_comments.iter().filter(|c| c.github_comment_id.is_none()).cloned().collect();
+
+    if unpushed.is_empty() && known_remote == remote.comments {
+        *local.comments_mut() = if local_comments.is_empty() { None } else { Some(local_comments) };
+        return Ok((false, false));
+    }
+
+    let number = remote.number;
+    let remote_comments = tracker.list_comments(number).await?;
+
+    let mut merged = merge_remote_comments(&local_comments, &remote_comments);
+    let pulled = merged.len() as u64 != known_remote
+        || remote_comments.iter().any(|rc| !local_comments.iter().any(|c| c.github_comment_id == Some(rc.id)));
+
+    // Comments were taken out of `local` above, so this must not return early
+    let queued = if unpushed.is_empty() {
+        Default::default()
+    } else {
+        outbox.queued_comment_ids().await.unwrap_or_else(|e| {
+            log::warn!("Failed to read the outbound queue: {}", e);
+            Default::default()
+        })
+    };
+    let mut pushed = false;
+    let mut result = Ok(());
+    for mut comment in unpushed {
+        if queued.contains(&comment.id) {
+            merged.push(comment);
+            continue;
+        }
+        match tracker.post_comment(number, &comment.body).await {
+            Ok(posted) => {
+                comment.github_comment_id = Some(posted.id);
+                if let Some(user) = posted.user {
+                    comment.author = user.login;
+                }
+                pushed = true;
+            }
+            Err(e) => {
+                let action = OutboundAction::Comment {
+                    issue_number: number,
+                    body: comment.body.clone(),
+                    local_id: Some(comment.id.clone()),
+                };
+                if let Err(queue_error) = outbox.queue(action, e.clone()).await {
+                    log::warn!("Failed to queue comment on #{}: {}", number, queue_error);
+                }
+                result = Err(e);
+            }
+        }
+        // Unposted comments stay local until the queue posts and links them
+        merged.push(comment);
+    }
+
+    *local.comments_mut() = if merged.is_empty() { None } else { Some(merged) };
+    result.map(|_| (pulled, pushed))
+}
+
+/// Sync one linked item with its GitHub issue, recording what happened in the report
+async fn sync_item(
+    tracker: &dyn IssueTracker,
+    local: &mut impl SyncTarget,
+    remote: &RemoteIssue,
+    rules: &GithubMappingRules,
+    now: &str,
+    report: &mut SyncReport,
+    outbox: &SyncOutbox<'_>,
+) {
+    let (resolution, conflicted) = resolve(local, remote);
+    let fields = differing_fields(local, remote, rules);
+    let mut remote_updated_at = remote.updated_at;
+
+    let local_id = local.local_id().to_string();
+    let kind = local.kind();
+    let change = |fields: Vec<String>, winner: Option<&str>| SyncChange {
+        issue_number: remote.number,
+        local_id: local_id.clone(),
+        kind: kind.to_string(),
+        fields,
+        winner: winner.map(|w| w.to_string()),
+    };
+
+    match resolution {
+        Resolution::Unchanged => {}
+        Resolution::Baseline => {
+            // Only adopt remote state, labels and a mapped priority; content may have been refined locally
+            let remote_priority = rules.priority_for(&remote.label_names(), remote.milestone_title());
+            let baseline: Vec<String> = fields
+                .into_iter()
+                .filter(|f| f == "state" || f == "labels" || (f == "priority" && remote_priority.is_some()))
+                .collect();
+            if !baseline.is_empty() || local.labels().is_none() {
+                apply_remote(local, remote, &baseline, rules);
+            }
+            if !baseline.is_empty() {
+                report.pulled.push(change(baseline, None));
+            }
+        }
+        Resolution::Pull if !fields.is_empty() => {
+            apply_remote(local, remote, &fields, rules);
+            if conflicted {
+                report.conflicts.push(change(fields.clone(), Some("remote")));
+            }
+            report.pulled.push(change(fields, None));
+        }
+        Resolution::Push if !fields.is_empty() => {
+            let patch = build_patch(local, remote, &fields, rules);
+            let labels = patch.labels.clone().map(|labels| OutboundAction::Labels { issue_number: remote.number, labels });
+            match tracker.update_issue(remote.number, &patch).await {
+                Ok(updated) => {
+                    remote_updated_at = updated.updated_at;
+                    if let Some(labels) = &labels {
+                        if let Err(e) = outbox.resolve(labels).await {
+                            log::warn!("Failed to drop queued labels for #{}: {}", remote.number, e);
+                        }
+                    }
+                    if conflicted {
+                        report.conflicts.push(change(fields.clone(), Some("local")));
+                    }
+                    report.pushed.push(change(fields, None));
+                }
+                Err(e) => {
+                    // Leave the sync markers untouched so the push is retried next time; labels
+                    // are also queued so they go out with backoff even if sync doesn't run again
+                    if let Some(labels) = labels {
+                        if let Err(queue_error) = outbox.queue(labels, e.clone()).await {
+                            log::warn!("Failed to queue labels for #{}: {}", remote.number, queue_error);
+                        }
+                    }
+                    report.errors.push(e);
+                    return;
+                }
+            }
+        }
+        Resolution::Pull | Resolution::Push => {}
+    }
+
+    match sync_comments(tracker, local, remote, outbox).await {
+        Ok((pulled, pushed)) => {
+            if pulled {
+                report.pulled.push(change(vec!["comments".to_string()], None));
+            }
+            if pushed {
+                report.pushed.push(change(vec!["comments".to_string()], None));
+            }
+        }
+        Err(e) => report.errors.push(e),
+    }
+
+    local.mark_synced(now, &remote_updated_at.to_rfc3339());
+}
+
+/// Pull comment threads for pending feedback whose linked issue has comments we haven't seen
+///
+/// Covers items imported by this fetch and comments added since the last one. Local comments
+/// that haven't been posted yet are kept at the end of the thread.
+pub(crate) async fn pull_comment_threads(
+    tracker: &dyn IssueTracker,
+    project_path: &Path,
+    remote_issues: &[RemoteIssue],
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    let mut feedback_file = read_feedback_file(project_path, FEEDBACK_FILE)?;
+    let mut changed = false;
+
+    for item in feedback_file.feedback.iter_mut() {
+        let Some(remote) = item.github_issue_number.and_then(|n| remote_issues.iter().find(|r| r.number == n)) else {
+            continue;
+        };
+        let local_comments = item.comments.clone().unwrap_or_default();
+        let known_remote = local_comments.iter().filter(|c| c.github_comment_id.is_some()).count() as u64;
+        // The listing's count predates comments pushed in this sync, so only more remote comments count
+        if remote.comments <= known_remote {
+            continue;
+        }
+
+        match tracker.list_comments(remote.number).await {
+            Ok(remote_comments) => {
+                let mut thread = merge_remote_comments(&local_comments, &remote_comments);
+                thread.extend(local_comments.into_iter().filter(|c| c.github_comment_id.is_none()));
+                item.comments = if thread.is_empty() { None } else { Some(thread) };
+                changed = true;
+                report.pulled.push(SyncChange {
+                    issue_number: remote.number,
+                    local_id: item.id.clone(),
+                    kind: "feedback".to_string(),
+                    fields: vec!["comments".to_string()],
+                    winner: None,
+                });
+            }
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    if changed {
+        write_feedback_file(project_path, FEEDBACK_FILE, &feedback_file)?;
+    }
+    Ok(())
+}
+
+/// Whether an item has local edits or comments that still need to reach GitHub
+fn has_local_changes(local: &mut impl SyncTarget) -> bool {
+    let synced_at = parse_time(local.synced_at());
+    let edited = match (parse_time(local.updated_at()), synced_at) {
+        (Some(updated), Some(synced)) => updated > synced,
+        _ => false,
+    };
+    let unpushed_comments = local
+        .comments_mut()
+        .as_ref()
+        .map(|comments| comments.iter().any(|c| c.github_comment_id.is_none()))
+        .unwrap_or(false);
+    edited || unpushed_comments
+}
+
+/// Run a two-way sync between a project's local data and its issue tracker
+///
+/// Only issues updated since the project's cursor are listed. Linked items with
+/// local changes whose issue wasn't in that listing are fetched one by one. Comment and
+/// label pushes that fail are queued in `outbox` for retry.
+pub(crate) async fn sync_repository(
+    tracker: &dyn IssueTracker,
+    project_path: &Path,
+    outbox: &SyncOutbox<'_>,
+) -> Result<SyncReport, String> {
+    let mut sync_state = github::read_sync_state(project_path);
+    let rules = read_mapping_rules(project_path);
+    let listing = tracker.list_issues("all", sync_state.since.as_deref()).await?;
+    let mut remote_issues = listing.issues;
+
+    let mut issues_file = read_issues_file(project_path)?;
+    let mut issues_archive = read_issues_archive_file(project_path)?;
+    let mut pending_feedback = read_feedback_file(project_path, FEEDBACK_FILE)?;
+    let mut completed_feedback = read_feedback_file(project_path, FEEDBACK_COMPLETED_FILE)?;
+
+    let now = Utc::now().to_rfc3339();
+    let mut report = SyncReport {
+        tracker: Some(tracker.kind()),
+        since: sync_state.since.clone(),
+        fetched: remote_issues.len(),
+        pull_requests_skipped: listing.pull_requests_skipped,
+        synced_at: now.clone(),
+        ..Default::default()
+    };
+
+    let mut issues: Vec<Issue> = issues_file.issues.drain(..).chain(issues_archive.issues.drain(..)).collect();
+    let mut feedback: Vec<FeedbackItem> = pending_feedback.feedback.drain(..).chain(completed_feedback.feedback.drain(..)).collect();
+
+    // Fetch issues that weren't updated remotely but have local changes to push
+    let mut missing: Vec<u64> = Vec::new();
+    for number in issues
+        .iter_mut()
+        .filter_map(|i| if has_local_changes(i) { i.github_issue_number() } else { None })
+        .chain(feedback.iter_mut().filter_map(|f| if has_local_changes(f) { f.github_issue_number() } else { None }))
+    {
+        if !remote_issues.iter().any(|r| r.number == number) && !missing.contains(&number) {
+            missing.push(number);
+        }
+    }
+    for number in missing {
+        match tracker.get_issue(number).await {
+            Ok(issue) => remote_issues.push(issue),
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    // Issues take precedence over feedback that links to the same GitHub issue
+    let mut handled: HashSet<u64> = HashSet::new();
+    for issue in issues.iter_mut() {
+        let Some(number) = issue.github_issue_number() else { continue };
+        let Some(remote) = remote_issues.iter().find(|r| r.number == number) else { continue };
+        handled.insert(number);
+        sync_item(tracker, issue, remote, &rules, &now, &mut report, outbox).await;
+    }
+
+    for item in feedback.iter_mut() {
+        let Some(number) = item.github_issue_number() else { continue };
+        if handled.contains(&number) {
+            continue;
+        }
+        let Some(remote) = remote_issues.iter().find(|r| r.number == number) else { continue };
+        sync_item(tracker, item, remote, &rules, &now, &mut report, outbox).await;
+    }
+
+    // Completed items live in the archive files
+    let (completed, pending): (Vec<_>, Vec<_>) = issues.into_iter().partition(|i| i.status == "completed");
+    issues_file.issues = pending;
+    issues_archive.issues = completed;
+    write_issues_file(project_path, &issues_file)?;
+    write_issues_archive_file(project_path, &issues_archive)?;
+
+    let (completed, pending): (Vec<_>, Vec<_>) = feedback.into_iter().partition(|f| f.status == "completed");
+    pending_feedback.feedback = pending;
+    completed_feedback.feedback = completed;
+    write_feedback_file(project_path, FEEDBACK_FILE, &pending_feedback)?;
+    write_feedback_file(project_path, FEEDBACK_COMPLETED_FILE, &completed_feedback)?;
+
+    // Import open issues that aren't tracked locally yet, with their comment threads
+    report.imported = github::import_remote_issues(project_path, &remote_issues)?;
+    pull_comment_threads(tracker, project_path, &remote_issues, &mut report).await?;
+
+    // Advance the cursor to the newest remote update we've seen (GitHub's clock, not ours)
+    if let Some(latest) = remote_issues.iter().map(|r| r.updated_at).max() {
+        let is_newer = parse_time(sync_state.since.as_deref()).map(|since| latest > since).unwrap_or(true);
+        if is_newer {
+            sync_state.since = Some(latest.to_rfc3339_opts(SecondsFormat::Secs, true));
+            github::write_sync_state(project_path, &sync_state)?;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Two-way sync of a project's issues and feedback with its GitHub repository
+///
+/// This command:
+/// 1. Imports open GitHub issues that aren't tracked yet (same as fetch_github_issues)
+/// 2. Pulls title, body, state, labels, mapped priority and comments changed on GitHub
+/// 3. Pushes title, body, state, labels, mapped priority and comments changed locally
+/// 4. Resolves items changed on both sides in favour of the most recent edit
+/// 5. Returns a report of everything that changed
+#[tauri::command]
+pub async fn sync_github_issues(
+    app: AppHandle,
+    project_path: String,
+    github_url: String,
+) -> Result<SyncReport, String> {
+    let path = Path::new(&project_path);
+    let settings = read_settings(&app)?;
+    let tracker = tracker::tracker_from_settings(&app, Some(path), &github_url)?;
+
+    let outbox = SyncOutbox::for_app(&app, path, &github_url)?;
+    let mut report = sync_repository(tracker.as_ref(), path, &outbox).await?;
+    github_discussions::sync_discussions(&settings, path, &github_url, &mut report).await;
+    github_board::sync_configured_board(&settings, path, &github_url, &mut report).await;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::github_mapping::write_mapping_rules;
+    use crate::models::{FeedbackFile, IssueFile};
+    use crate::commands::github::GithubTracker;
+    use serde_json::json;
+    use std::path::PathBuf;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const LAST_SYNC: &str = "2024-01-01T00:00:00+00:00";
+
+    fn temp_project() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vibe-hub-sync-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(dir.join(".vibe")).unwrap();
+        dir
+    }
+
+    fn outbox(project: &Path) -> SyncOutbox<'_> {
+        SyncOutbox {
+            queue_path: project.join("outbound-queue.json"),
+            project_path: project,
+            repo_url: "https://github.com/owner/repo",
+        }
+    }
+
+    fn mock_tracker(server: &MockServer) -> GithubTracker {
+        GithubTracker::new(&server.uri(), "test-token", "owner", "repo").unwrap()
+    }
+
+    fn remote_issue(number: u64, title: &str, state: &str, updated_at: &str) -> serde_json::Value {
+        json!({
+            "number": number,
+            "title": title,
+            "body": "Body",
+            "state": state,
+            "html_url": format!("https://github.com/owner/repo/issues/{}", number),
+            "labels": [{ "name": "bug" }],
+            "comments": 0,
+            "updated_at": updated_at,
+        })
+    }
+
+    fn linked_issue(number: u64, title: &str, updated_at: Option<&str>) -> Issue {
+        Issue {
+            id: format!("issue-{}", number),
+            original_feedback_id: None,
+            title: title.to_string(),
+            description: "Body".to_string(),
+            subtasks: vec![],
+            time_estimate: None,
+            complexity: 3,
+            priority: 3,
+            status: "pending".to_string(),
+            created_at: LAST_SYNC.to_string(),
+            completed_at: None,
+            review_notes: None,
+            bug_report: None,
+            last_user_critique: None,
+            implementation_summary: None,
+            github_issue_number: Some(number),
+            github_issue_url: Some(format!("https://github.com/owner/repo/issues/{}", number)),
+            updated_at: updated_at.map(|t| t.to_string()),
+            labels: Some(vec!["bug".to_string()]),
+            comments: None,
+            github_synced_at: Some(LAST_SYNC.to_string()),
+            github_updated_at: Some(LAST_SYNC.to_string()),
+            last_run_error: None,
+        }
+    }
+
+    async fn mount_issue_list(server: &MockServer, issues: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issues))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_remote_close_is_pulled_into_archive() {
+        let server = MockServer::start().await;
+        mount_issue_list(&server, json!([remote_issue(1, "Fix login", "closed", "2024-02-01T00:00:00Z")])).await;
+
+        let project = temp_project();
+        write_issues_file(&project, &IssueFile { issues: vec![linked_issue(1, "Fix login", None)] }).unwrap();
+
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox(&project)).await.unwrap();
+
+        assert_eq!(report.pulled.len(), 1);
+        assert_eq!(report.pulled[0].fields, vec!["state".to_string()]);
+        assert!(read_issues_file(&project).unwrap().issues.is_empty());
+        let archived = read_issues_archive_file(&project).unwrap().issues;
+        assert_eq!(archived[0].status, "completed");
+    }
+
+    #[tokio::test]
+    async fn test_local_edit_is_pushed() {
+        let server = MockServer::start().await;
+        mount_issue_list(&server, json!([remote_issue(2, "Old title", "open", LAST_SYNC)])).await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/2"))
+            .and(body_partial_json(json!({ "title": "New title" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(remote_issue(2, "New title", "open", "2024-03-01T00:00:00Z")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_project();
+        let issue = linked_issue(2, "New title", Some("2024-02-01T00:00:00Z"));
+        write_issues_file(&project, &IssueFile { issues: vec![issue] }).unwrap();
+
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox(&project)).await.unwrap();
+
+        assert_eq!(report.pushed.len(), 1);
+        assert!(report.conflicts.is_empty());
+        let synced = &read_issues_file(&project).unwrap().issues[0];
+        assert_eq!(synced.github_updated_at.as_deref(), Some("2024-03-01T00:00:00+00:00"));
+    }
+
+    #[tokio::test]
+    async fn test_conflict_prefers_most_recent_edit() {
+        let server = MockServer::start().await;
+        // Remote edited after the local edit, so the remote title wins
+        mount_issue_list(&server, json!([remote_issue(3, "Remote title", "open", "2024-02-02T00:00:00Z")])).await;
+
+        let project = temp_project();
+        let issue = linked_issue(3, "Local title", Some("2024-02-01T00:00:00Z"));
+        write_issues_file(&project, &IssueFile { issues: vec![issue] }).unwrap();
+
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox(&project)).await.unwrap();
+
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].winner.as_deref(), Some("remote"));
+        assert_eq!(read_issues_file(&project).unwrap().issues[0].title, "Remote title");
+    }
+
+    #[tokio::test]
+    async fn test_priority_maps_through_labels_both_ways() {
+        let server = MockServer::start().await;
+        let mut relabelled = remote_issue(8, "Slow build", "open", "2024-02-01T00:00:00Z");
+        relabelled["labels"] = json!([{ "name": "P0" }, { "name": "ci" }]);
+        let mut untouched = remote_issue(9, "Flaky test", "open", LAST_SYNC);
+        untouched["labels"] = json!([{ "name": "P1" }, { "name": "bug" }]);
+        mount_issue_list(&server, json!([relabelled, untouched])).await;
+        // Local priority of #9 dropped to 4, which has no label, so P1 is removed
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/9"))
+            .and(body_partial_json(json!({ "labels": ["bug"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(remote_issue(9, "Flaky test", "open", "2024-03-01T00:00:00Z")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_project();
+        write_mapping_rules(&project, &GithubMappingRules {
+            priority_labels: [("P0".to_string(), 1), ("P1".to_string(), 2)].into(),
+            ..Default::default()
+        }).unwrap();
+        let mut lowered = linked_issue(9, "Flaky test", Some("2024-02-01T00:00:00Z"));
+        lowered.priority = 4;
+        write_issues_file(&project, &IssueFile { issues: vec![linked_issue(8, "Slow build", None), lowered] }).unwrap();
+
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox(&project)).await.unwrap();
+
+        assert!(report.errors.is_empty());
+        assert_eq!(report.pushed[0].fields, vec!["priority".to_string()]);
+        let issues = read_issues_file(&project).unwrap().issues;
+        let pulled = issues.iter().find(|i| i.id == "issue-8").unwrap();
+        assert_eq!(pulled.priority, 1);
+        assert_eq!(pulled.labels, Some(vec!["ci".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_cursor_limits_fetch_and_unlisted_local_edits_are_fetched() {
+        use wiremock::matchers::query_param;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param("since", "2024-01-01T00:00:00Z"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([remote_issue(7, "Updated", "open", "2024-04-01T00:00:00Z")])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // Issue 6 wasn't updated remotely, but has a local edit to push
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/6"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(remote_issue(6, "Old title", "open", LAST_SYNC)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/6"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(remote_issue(6, "Edited", "open", "2024-05-01T00:00:00Z")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_project();
+        github::write_sync_state(&project, &crate::models::GithubSyncState { since: Some("2024-01-01T00:00:00Z".to_string()), ..Default::default() }).unwrap();
+        write_issues_file(&project, &IssueFile { issues: vec![linked_issue(6, "Edited", Some("2024-02-01T00:00:00Z"))] }).unwrap();
+
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox(&project)).await.unwrap();
+
+        assert_eq!(report.since.as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert_eq!(report.fetched, 1);
+        assert_eq!(report.pushed.len(), 1);
+        assert_eq!(report.imported, 1);
+        assert_eq!(github::read_sync_state(&project).since.as_deref(), Some("2024-04-01T00:00:00Z"));
+    }
+
+    #[tokio::test]
+    async fn test_local_comment_is_posted_and_new_issue_imported() {
+        let server = MockServer::start().await;
+        let mut reopened = remote_issue(4, "Crash on start", "open", LAST_SYNC);
+        reopened["comments"] = json!(0);
+        mount_issue_list(&server, json!([reopened, remote_issue(5, "Brand new", "open", LAST_SYNC)])).await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/4/comments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues/4/comments"))
+            .and(body_partial_json(json!({ "body": "Repro steps" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": 99,
+                "body": "Repro steps",
+                "user": { "login": "octocat" },
+                "created_at": "2024-02-01T00:00:00Z",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_project();
+        let mut item = FeedbackItem {
+            id: "feedback-4".to_string(),
+            text: "Crash on start\n\nBody".to_string(),
+            priority: 3,
+            status: "pending".to_string(),
+            created_at: LAST_SYNC.to_string(),
+            completed_at: None,
+            refined_into_issue_ids: None,
+            review_notes: None,
+            related_issue_id: None,
+            github_issue_number: Some(4),
+            github_issue_url: None,
+            updated_at: None,
+            labels: Some(vec!["bug".to_string()]),
+            comments: None,
+            github_synced_at: Some(LAST_SYNC.to_string()),
+            github_updated_at: Some(LAST_SYNC.to_string()),
+            github_discussion_number: None,
+            github_discussion_url: None,
+        };
+        item.comments = Some(vec![Comment {
+            id: "c1".to_string(),
+            author: "me".to_string(),
+            body: "Repro steps".to_string(),
+            created_at: LAST_SYNC.to_string(),
+            github_comment_id: None,
+        }]);
+        write_feedback_file(&project, FEEDBACK_FILE, &FeedbackFile { feedback: vec![item] }).unwrap();
+
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox(&project)).await.unwrap();
+
+        assert_eq!(report.imported, 1);
+        assert!(report.errors.is_empty());
+        let feedback = read_feedback_file(&project, FEEDBACK_FILE).unwrap().feedback;
+        let synced = feedback.iter().find(|f| f.id == "feedback-4").unwrap();
+        let comment = &synced.comments.as_ref().unwrap()[0];
+        assert_eq!(comment.github_comment_id, Some(99));
+        assert_eq!(comment.author, "octocat");
+        assert!(feedback.iter().any(|f| f.github_issue_number == Some(5)));
+    }
+
+    #[tokio::test]
+    async fn test_failed_comment_push_is_queued_and_left_to_the_queue() {
+        let server = MockServer::start().await;
+        let mut remote = remote_issue(6, "Slow save", "open", LAST_SYNC);
+        remote["comments"] = json!(0);
+        mount_issue_list(&server, json!([remote])).await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/6/comments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues/6/comments"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_project();
+        let mut issue = linked_issue(6, "Slow save", None);
+        issue.comments = Some(vec![Comment {
+            id: "c6".to_string(),
+            author: "me".to_string(),
+            body: "Happens on large files".to_string(),
+            created_at: LAST_SYNC.to_string(),
+            github_comment_id: None,
+        }]);
+        write_issues_file(&project, &IssueFile { issues: vec![issue] }).unwrap();
+
+        let outbox = outbox(&project);
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox).await.unwrap();
+        assert_eq!(report.errors.len(), 1);
+        assert!(outbox.queued_comment_ids().await.unwrap().contains("c6"));
+
+        // The next sync doesn't post it again; the queue owns it now
+        let report = sync_repository(&mock_tracker(&server), &project, &outbox).await.unwrap();
+        assert!(report.errors.is_empty());
+        let issues = read_issues_file(&project).unwrap().issues;
+        assert_eq!(issues[0].comments.as_ref().unwrap()[0].github_comment_id, None);
+    }
+}
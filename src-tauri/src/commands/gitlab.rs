@@ -0,0 +1,324 @@
+// GitLab issue tracker backend (gitlab.com and self-hosted instances, REST API v4)
+// Converts GitLab issues and notes into the GitHub-shaped models used by the sync.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::github::{IssueListing, IssuePatch, RemoteComment, RemoteIssue, RemoteLabel, RemoteMilestone, RemoteUser};
+use crate::commands::tracker::{read_json, IssueTracker};
+use crate::models::TrackerKind;
+
+#[derive(Debug, Deserialize)]
+struct GitlabIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String, // "opened" | "closed"
+    web_url: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    milestone: Option<GitlabMilestone>,
+    #[serde(default)]
+    user_notes_count: u64,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabMilestone {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabUser {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabNote {
+    id: u64,
+    body: String,
+    author: Option<GitlabUser>,
+    created_at: DateTime<Utc>,
+    /// System notes record label/state changes rather than user comments
+    #[serde(default)]
+    system: bool,
+}
+
+/// Body of PUT /projects/:id/issues/:iid; unset fields are left untouched
+#[derive(Debug, Serialize)]
+struct GitlabIssueUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_event: Option<String>, // "close" | "reopen"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    labels: Option<String>, // Comma-separated; empty clears all labels
+}
+
+impl From<GitlabIssue> for RemoteIssue {
+    fn from(issue: GitlabIssue) -> Self {
+        RemoteIssue {
+            number: issue.iid,
+            title: issue.title,
+            body: issue.description,
+            state: if issue.state == "opened" { "open".to_string() } else { issue.state },
+            html_url: issue.web_url,
+            labels: issue.labels.into_iter().map(|name| RemoteLabel { name }).collect(),
+            milestone: issue.milestone.map(|m| RemoteMilestone { title: m.title }),
+            comments: issue.user_notes_count,
+            updated_at: issue.updated_at,
+            pull_request: None,
+        }
+    }
+}
+
+impl From<GitlabNote> for RemoteComment {
+    fn from(note: GitlabNote) -> Self {
+        RemoteComment {
+            id: note.id,
+            body: Some(note.body),
+            user: note.author.map(|a| RemoteUser { login: a.username }),
+            created_at: note.created_at,
+        }
+    }
+}
+
+/// A GitLab project accessed with a personal access token
+pub(crate) struct GitlabTracker {
+    client: Client,
+    api_url: String,
+    token: String,
+    project_id: String, // URL-encoded "group/subgroup/project" path
+}
+
+impl GitlabTracker {
+    pub fn new(api_url: &str, token: &str, owner: &str, repo: &str) -> Self {
+        Self {
+            client: Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+            project_id: format!("{}/{}", owner, repo).replace('/', "%2F"),
+        }
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{}/projects/{}{}", self.api_url, self.project_id, path))
+            .header("PRIVATE-TOKEN", &self.token)
+    }
+
+    /// GET every page of a list endpoint, following the X-Next-Page header
+    async fn get_all<T: DeserializeOwned>(&self, path: &str, params: &[(&str, &str)], action: &str) -> Result<Vec<T>, String> {
+        let mut items = Vec::new();
+        let mut page = 1u32;
+        loop {
+            let response = self
+                .request(Method::GET, path)
+                .query(params)
+                .query(&[("per_page", "100".to_string()), ("page", page.to_string())])
+                .send()
+                .await;
+            let next = response
+                .as_ref()
+                .ok()
+                .and_then(|r| r.headers().get("x-next-page"))
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u32>().ok());
+
+            let mut batch: Vec<T> = read_json(response, action).await?;
+            items.append(&mut batch);
+
+            match next {
+                Some(next) if next > page => page = next,
+                _ => break,
+            }
+        }
+        Ok(items)
+    }
+}
+
+#[async_trait]
+impl IssueTracker for GitlabTracker {
+    fn kind(&self) -> TrackerKind {
+        TrackerKind::Gitlab
+    }
+
+    async fn list_issues(&self, state: &str, since: Option<&str>) -> Result<IssueListing, String> {
+        let state = if state == "open" { "opened" } else { state };
+        let mut params = vec![("state", state)];
+        if let Some(since) = since {
+            params.push(("updated_after", since));
+        }
+
+        let issues: Vec<GitlabIssue> = self
+            .get_all("/issues", &params, "fetch GitLab issues. Please check your token and project access")
+            .await?;
+
+        // GitLab keeps merge requests separate, so there's nothing to filter out
+        Ok(IssueListing {
+            issues: issues.into_iter().map(RemoteIssue::from).collect(),
+            pull_requests_skipped: 0,
+        })
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<RemoteIssue, String> {
+        let response = self.request(Method::GET, &format!("/issues/{}", number)).send().await;
+        let issue: GitlabIssue = read_json(response, &format!("fetch GitLab issue #{}", number)).await?;
+        Ok(issue.into())
+    }
+
+    async fn create_issue(&self, title: &str, body: &str, labels: &[String]) -> Result<RemoteIssue, String> {
+        let response = self
+            .request(Method::POST, "/issues")
+            .json(&serde_json::json!({ "title": title, "description": body, "labels": labels.join(",") }))
+            .send()
+            .await;
+        let issue: GitlabIssue = read_json(response, "create GitLab issue").await?;
+        Ok(issue.into())
+    }
+
+    async fn update_issue(&self, number: u64, patch: &IssuePatch) -> Result<RemoteIssue, String> {
+        let update = GitlabIssueUpdate {
+            title: patch.title.clone(),
+            description: patch.body.clone(),
+            state_event: patch.state.as_deref().map(|state| {
+                if state == "closed" { "close" } else { "reopen" }.to_string()
+            }),
+            labels: patch.labels.as_ref().map(|labels| labels.join(",")),
+        };
+
+        let response = self
+            .request(Method::PUT, &format!("/issues/{}", number))
+            .json(&update)
+            .send()
+            .await;
+        let issue: GitlabIssue = read_json(response, &format!("update GitLab issue #{}", number)).await?;
+        Ok(issue.into())
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>, String> {
+        let notes: Vec<GitlabNote> = self
+            .get_all(
+                &format!("/issues/{}/notes", number),
+                &[("sort", "asc"), ("order_by", "created_at")],
+                &format!("fetch comments for GitLab issue #{}", number),
+            )
+            .await?;
+
+        Ok(notes.into_iter().filter(|n| !n.system).map(RemoteComment::from).collect())
+    }
+
+    async fn post_comment(&self, number: u64, body: &str) -> Result<RemoteComment, String> {
+        let response = self
+            .request(Method::POST, &format!("/issues/{}/notes", number))
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await;
+        let note: GitlabNote = read_json(response, &format!("add comment to GitLab issue #{}", number)).await?;
+        Ok(note.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const PROJECT_PATH: &str = "/api/v4/projects/team%2Fbackend%2Fapi";
+
+    fn tracker(server: &MockServer) -> GitlabTracker {
+        GitlabTracker::new(&format!("{}/api/v4", server.uri()), "glpat-test", "team/backend", "api")
+    }
+
+    fn issue_json(iid: u64, state: &str) -> serde_json::Value {
+        json!({
+            "iid": iid,
+            "title": format!("Issue {}", iid),
+            "description": "Details",
+            "state": state,
+            "web_url": format!("https://gitlab.example.com/team/backend/api/-/issues/{}", iid),
+            "labels": ["bug", "P1"],
+            "milestone": { "title": "v1.0" },
+            "user_notes_count": 1,
+            "updated_at": "2024-01-01T00:00:00Z",
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_follows_pages_and_normalises_state() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{}/issues", PROJECT_PATH)))
+            .and(header("PRIVATE-TOKEN", "glpat-test"))
+            .and(query_param("state", "opened"))
+            .and(query_param("updated_after", "2024-01-01T00:00:00Z"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).insert_header("X-Next-Page", "2").set_body_json(json!([issue_json(1, "opened")])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("{}/issues", PROJECT_PATH)))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).insert_header("X-Next-Page", "").set_body_json(json!([issue_json(2, "opened")])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let listing = tracker(&server).list_issues("open", Some("2024-01-01T00:00:00Z")).await.unwrap();
+
+        assert_eq!(listing.issues.len(), 2);
+        let first = &listing.issues[0];
+        assert!(first.is_open());
+        assert_eq!(first.label_names(), vec!["bug".to_string(), "P1".to_string()]);
+        assert_eq!(first.milestone_title(), Some("v1.0"));
+        assert_eq!(first.comments, 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_maps_state_and_labels() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path(format!("{}/issues/3", PROJECT_PATH)))
+            .and(body_json(json!({ "state_event": "close", "labels": "bug,P0" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_json(3, "closed")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let patch = IssuePatch {
+            state: Some("closed".to_string()),
+            labels: Some(vec!["bug".to_string(), "P0".to_string()]),
+            ..Default::default()
+        };
+        let updated = tracker(&server).update_issue(3, &patch).await.unwrap();
+
+        assert!(!updated.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_list_comments_skips_system_notes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{}/issues/4/notes", PROJECT_PATH)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "id": 10, "body": "added ~bug label", "author": { "username": "bot" }, "created_at": "2024-01-01T00:00:00Z", "system": true },
+                { "id": 11, "body": "Can reproduce", "author": { "username": "dev" }, "created_at": "2024-01-02T00:00:00Z", "system": false },
+            ])))
+            .mount(&server)
+            .await;
+
+        let comments = tracker(&server).list_comments(4).await.unwrap();
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].id, 11);
+        assert_eq!(comments[0].user.as_ref().unwrap().login, "dev");
+    }
+}
@@ -1,4 +1,5 @@
-use crate::models::{Issue, IssueFile, NewIssue, UpdateIssue, FeedbackFile};
+use crate::commands::worktrees;
+use crate::models::{Comment, Issue, IssueFile, NewIssue, UpdateIssue, FeedbackFile};
 use std::fs;
 use std::path::Path;
 use uuid::Uuid;
@@ -37,7 +38,7 @@ pub fn read_issues_archive_file(project_path: &Path) -> Result<IssueFile, String
         .map_err(|e| format!("Failed to parse issues archive file: {}", e))
 }
 
-fn write_issues_file(project_path: &Path, issues_file: &IssueFile) -> Result<(), String> {
+pub fn write_issues_file(project_path: &Path, issues_file: &IssueFile) -> Result<(), String> {
     let vibe_dir = project_path.join(VIBE_DIR);
     let issues_path = vibe_dir.join(ISSUES_FILE);
 
@@ -56,7 +57,7 @@ fn write_issues_file(project_path: &Path, issues_file: &IssueFile) -> Result<(),
     Ok(())
 }
 
-fn write_issues_archive_file(project_path: &Path, issues_file: &IssueFile) -> Result<(), String> {
+pub fn write_issues_archive_file(project_path: &Path, issues_file: &IssueFile) -> Result<(), String> {
     let vibe_dir = project_path.join(VIBE_DIR);
     let archive_path = vibe_dir.join(ISSUES_ARCHIVE_FILE);
 
@@ -152,6 +153,12 @@ pub async fn add_issue(
         implementation_summary: None,
         github_issue_number: issue.github_issue_number,
         github_issue_url: issue.github_issue_url,
+        updated_at: None,
+        labels: issue.labels,
+        comments: None,
+        github_synced_at: None,
+        github_updated_at: None,
+        last_run_error: None,
     };
 
     issues_file.issues.push(new_issue.clone());
@@ -238,6 +245,10 @@ pub async fn update_issue(
     if let Some(github_issue_url) = updates.github_issue_url {
         issue.github_issue_url = Some(github_issue_url);
     }
+    if let Some(labels) = updates.labels {
+        issue.labels = Some(labels);
+    }
+    issue.updated_at = Some(chrono::Utc::now().to_rfc3339());
 
     let new_status = issue.status.clone();
 
@@ -263,6 +274,13 @@ pub async fn update_issue(
     write_issues_file(path, &pending_file)?;
     write_issues_archive_file(path, &archive_file)?;
 
+    // A merged worktree is no longer needed once its issue is done
+    if status_changed && new_status == "completed" {
+        if let Err(e) = worktrees::cleanup_issue_worktree(path, &issue_id) {
+            log::error!(project = project_path.as_str(); "Failed to clean up worktree for {}: {}", issue_id, e);
+        }
+    }
+
     Ok(())
 }
 
@@ -308,10 +326,44 @@ pub async fn delete_issue(project_path: String, issue_id: String) -> Result<(),
     Ok(())
 }
 
-const FEEDBACK_FILE: &str = "feedback.json";
-const FEEDBACK_COMPLETED_FILE: &str = "feedback-completed.json";
+/// Add a local comment to an issue's thread.
+/// For GitHub-linked issues the comment is posted on the next sync.
+#[tauri::command]
+pub async fn add_issue_comment(
+    project_path: String,
+    issue_id: String,
+    body: String,
+) -> Result<Comment, String> {
+    let path = Path::new(&project_path);
+    let mut pending_file = read_issues_file(path)?;
+    let mut archive_file = read_issues_archive_file(path)?;
+
+    let issue = pending_file.issues.iter_mut()
+        .chain(archive_file.issues.iter_mut())
+        .find(|i| i.id == issue_id)
+        .ok_or("Issue not found")?;
+
+    let comment = Comment {
+        id: Uuid::new_v4().to_string(),
+        author: "me".to_string(),
+        body,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        github_comment_id: None,
+    };
+
+    issue.comments.get_or_insert_with(Vec::new).push(comment.clone());
+    issue.updated_at = Some(chrono::Utc::now().to_rfc3339());
+
+    write_issues_file(path, &pending_file)?;
+    write_issues_archive_file(path, &archive_file)?;
+
+    Ok(comment)
+}
+
+pub const FEEDBACK_FILE: &str = "feedback.json";
+pub const FEEDBACK_COMPLETED_FILE: &str = "feedback-completed.json";
 
-fn read_feedback_file(project_path: &Path, filename: &str) -> Result<FeedbackFile, String> {
+pub fn read_feedback_file(project_path: &Path, filename: &str) -> Result<FeedbackFile, String> {
     let feedback_path = project_path.join(VIBE_DIR).join(filename);
 
     if !feedback_path.exists() {
@@ -325,7 +377,7 @@ fn read_feedback_file(project_path: &Path, filename: &str) -> Result<FeedbackFil
         .map_err(|e| format!("Failed to parse feedback file: {}", e))
 }
 
-fn write_feedback_file(project_path: &Path, filename: &str, feedback_file: &FeedbackFile) -> Result<(), String> {
+pub fn write_feedback_file(project_path: &Path, filename: &str, feedback_file: &FeedbackFile) -> Result<(), String> {
     let vibe_dir = project_path.join(VIBE_DIR);
     let feedback_path = vibe_dir.join(filename);
 
@@ -385,6 +437,12 @@ pub async fn migrate_completed_feedback_to_issues(project_path: String) -> Resul
             implementation_summary: None,
             github_issue_number: feedback.github_issue_number,
             github_issue_url: feedback.github_issue_url,
+            updated_at: feedback.updated_at,
+            labels: feedback.labels,
+            comments: feedback.comments,
+            github_synced_at: feedback.github_synced_at,
+            github_updated_at: feedback.github_updated_at,
+            last_run_error: None,
         };
 
         issues_file.issues.push(issue);
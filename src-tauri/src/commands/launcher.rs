@@ -1,159 +1,110 @@
 use std::process::Command;
-use std::fs::OpenOptions;
-use std::io::Write;
-use std::collections::HashMap;
-use std::sync::Mutex;
-use serde::Serialize;
+use std::path::Path;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::{agents, logs, sessions, spawn, transcripts};
+use crate::models::{SessionContext, SpawnSpec};
+#[cfg(target_os = "linux")]
+use crate::commands::{terminal, tmux};
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
-// Global session tracking
-lazy_static::lazy_static! {
-    static ref SESSIONS: Mutex<HashMap<String, SessionInfo>> = Mutex::new(HashMap::new());
+#[tauri::command]
+pub async fn log_debug(message: String) -> Result<(), String> {
+    log::debug!(target: "frontend", "{}", message);
+    Ok(())
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct SessionInfo {
-    pub project_path: String,
-    pub status: String, // "running", "idle", "not_started"
-    pub pid: Option<u32>,
-    #[serde(skip)]
-    pub created_at: Option<std::time::Instant>,
+/// Quote a string for bash as a single-quoted word
+#[cfg(target_os = "linux")]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
 }
 
-impl SessionInfo {
-    fn new(project_path: String, pid: Option<u32>) -> Self {
-        Self {
-            project_path,
-            status: "running".to_string(),
-            pid,
-            created_at: Some(std::time::Instant::now()),
-        }
-    }
+/// Launch the project's agent in a terminal with a prompt, recording the session.
+/// `context` says what the prompt is for (template name, issues, feedback).
+#[tauri::command]
+pub async fn launch_claude_code(
+    app: AppHandle,
+    project_path: String,
+    prompt: String,
+    context: Option<SessionContext>,
+) -> Result<(), String> {
+    let work_dir = project_path.clone();
+    launch_session(app, project_path, work_dir, prompt, context.unwrap_or_default()).await
 }
 
-pub fn log_to_file(message: &str) {
-    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-    let log_message = format!("[{}] {}", timestamp, message);
-
-    // Always write to stderr for debugging
-    eprintln!("{}", log_message);
-
-    // Try multiple log locations
-    let mut log_locations = vec![
-        std::env::temp_dir().join("vibe-hub-debug.log"),
-        std::path::PathBuf::from("E:\\vibe-hub-debug.log"),
-    ];
-
-    // Add current directory if available
-    if let Ok(current_dir) = std::env::current_dir() {
-        log_locations.push(current_dir.join("vibe-hub-debug.log"));
-    }
-
-    for log_path in &log_locations {
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(log_path)
-        {
-            let _ = writeln!(file, "{}", log_message);
-            let _ = file.flush();
-            break; // Successfully wrote, stop trying other locations
-        }
+/// Launch an agent session for a project that runs in `work_dir` (the project itself or one
+/// of its worktrees). Tracking, the transcript and the agent profile belong to the project.
+pub(crate) async fn launch_session(
+    app: AppHandle,
+    project_path: String,
+    work_dir: String,
+    prompt: String,
+    context: SessionContext,
+) -> Result<(), String> {
+    log::info!(project = project_path.as_str(); "Launching agent session in {} ({} char prompt)", work_dir, prompt.len());
+
+    // The session helper reports the agent's PID and exit code here
+    let session_files = sessions::prepare_session()?;
+
+    // The agent to run, per the project's profile (the prompt file is only written if it reads one)
+    let prompt_file = session_files.dir.join("agent-prompt.txt");
+    let agent = agents::project_agent_command(&app, &project_path, &prompt, false, &prompt_file)?;
+    log::debug!(project = project_path.as_str(); "Agent command: {} with {} argument(s)", agent.program, agent.args.len());
+
+    // Profiles that read the prompt from stdin get it from a file of its own
+    let stdin_file = session_files.dir.join("agent-stdin.txt");
+    if let Some(input) = &agent.stdin {
+        std::fs::write(&stdin_file, input)
+            .map_err(|e| format!("Failed to create prompt file: {}", e))?;
     }
-}
 
-#[tauri::command]
-pub async fn log_debug(message: String) -> Result<(), String> {
-    log_to_file(&format!("[FRONTEND] {}", message));
-    Ok(())
-}
-
-#[tauri::command]
-pub async fn launch_claude_code(project_path: String, prompt: String) -> Result<(), String> {
-    log_to_file(&format!("launch_claude_code called with path: {}", project_path));
-    log_to_file(&format!("Prompt length: {} chars", prompt.len()));
+    // Extract project name from path for unique window title
+    let project_name = Path::new(&project_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Project");
+
+    let spec = SpawnSpec {
+        program: agent.program,
+        args: agent.args,
+        env: agent.env,
+        cwd: work_dir.clone(),
+        stdin_file: agent.stdin.is_some().then(|| stdin_file.to_string_lossy().to_string()),
+        title: Some(format!("Claude Code - {}", project_name)),
+        cleanup: [prompt_file, stdin_file].iter().map(|p| p.to_string_lossy().to_string()).collect(),
+        // A shell left in a tmux window would keep the session, and so the project, looking busy
+        keep_open: !(cfg!(target_os = "linux") && crate::commands::tmux::enabled(&app)),
+    };
+    spawn::write_spec(&session_files.dir, &spec)?;
+    let (helper, helper_args) = spawn::helper_command(&session_files.dir)?;
 
-    // Register session
-    {
-        let mut sessions = SESSIONS.lock().unwrap();
-        sessions.insert(
-            project_path.clone(),
-            SessionInfo::new(project_path.clone(), None),
-        );
+    // Recorded before the launch so HEAD is taken before the agent can commit
+    if let Err(e) = transcripts::begin_transcript(&session_files.id, &project_path, &work_dir, "terminal", &prompt, context) {
+        log::warn!(project = project_path.as_str(); "Failed to record session transcript: {}", e);
     }
 
     #[cfg(target_os = "windows")]
     {
-        // Extract project name from path for unique window title
-        let project_name = std::path::Path::new(&project_path)
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("Project");
-
-        let window_title = format!("Claude Code - {}", project_name);
-
-        // Create a temporary batch file to launch Claude Code
-        // This approach works reliably from both GUI and console subsystems
-        let temp_dir = std::env::temp_dir();
-        let batch_file = temp_dir.join("vibe-hub-launch-claude.bat");
-
-        // Generate batch file that launches claude with or without a prompt
-        let batch_content = if !prompt.is_empty() {
-            // Write the prompt to a temporary text file
-            let prompt_file = temp_dir.join("vibe-hub-claude-prompt.txt");
-            if let Err(e) = std::fs::write(&prompt_file, &prompt) {
-                let error_msg = format!("Failed to create prompt file: {}", e);
-                log_to_file(&error_msg);
-                return Err(error_msg);
-            }
-            log_to_file(&format!("Prompt file created at: {:?}", prompt_file));
-
-            // Read the prompt from the file and pass it as a command-line argument
-            // Using @file syntax to read from file
-            format!(
-                "@echo off\ntitle {}\ncd /d \"{}\"\nclaude \"@{}\"\npause",
-                window_title,
-                project_path,
-                prompt_file.display()
-            )
-        } else {
-            format!(
-                "@echo off\ntitle {}\ncd /d \"{}\"\nclaude\npause",
-                window_title,
-                project_path
-            )
-        };
-
-        log_to_file(&format!("Creating batch file at: {:?}", batch_file));
-
-        // Write the batch file
-        if let Err(e) = std::fs::write(&batch_file, batch_content) {
-            let error_msg = format!("Failed to create batch file: {}", e);
-            log_to_file(&error_msg);
-            return Err(error_msg);
-        }
-
-        log_to_file("Batch file created successfully");
-
-        // Launch the batch file in a new console window with unique title
-        log_to_file(&format!("Executing: cmd /c start \"{}\" cmd /c \"{}\"", window_title, batch_file.display()));
-
-        let result = Command::new("cmd")
-            .args(&["/c", "start", &window_title, "cmd", "/c", batch_file.to_str().unwrap()])
-            .creation_flags(0x08000000) // CREATE_NO_WINDOW - hide the intermediate cmd window
+        // The helper opens its own console window, titled so focus_claude_terminal can find it
+        log::debug!("Executing: {} {}", helper, helper_args.join(" "));
+        let result = Command::new(&helper)
+            .args(&helper_args)
+            .current_dir(&work_dir)
+            .creation_flags(0x00000010) // CREATE_NEW_CONSOLE
             .spawn();
 
         match result {
             Ok(_) => {
-                log_to_file("Command spawned successfully");
-                log_to_file(&format!("Batch file should be at: {}", batch_file.display()));
+                sessions::start_session(&app, &project_path, session_files, None);
                 Ok(())
             }
             Err(e) => {
                 let error_msg = format!("Failed to launch Claude Code: {}", e);
-                log_to_file(&error_msg);
+                log::error!(project = project_path.as_str(); "{}", error_msg);
+                let _ = transcripts::finish_transcript(&project_path, &session_files.id, "failed_to_start", None);
                 Err(error_msg)
             }
         }
@@ -161,21 +112,35 @@ pub async fn launch_claude_code(project_path: String, prompt: String) -> Result<
 
     #[cfg(target_os = "linux")]
     {
-        // Launch terminal in the project directory with optional prompt
-        let command = if !prompt.is_empty() {
-            // Escape single quotes in the prompt for bash
-            let escaped_prompt = prompt.replace("'", "'\\''");
-            format!("cd '{}' && claude '{}'; exec bash", project_path, escaped_prompt)
-        } else {
-            format!("cd '{}' && claude; exec bash", project_path)
+        // Terminals and tmux take a bash script; it only ever holds the helper's own path and run directory
+        let command = std::iter::once(&helper)
+            .chain(helper_args.iter())
+            .map(|word| shell_quote(word))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let command = format!("exec {}", command);
+        let session_id = session_files.id.clone();
+        let launch_failed = |e: String| {
+            let _ = transcripts::finish_transcript(&project_path, &session_id, "failed_to_start", None);
+            format!("Failed to launch Claude Code: {}", e)
         };
 
-        Command::new("gnome-terminal")
-            .args(&["--", "bash", "-c", &command])
-            .spawn()
-            .map_err(|e| format!("Failed to launch Claude Code: {}", e))?;
+        if !tmux::enabled(&app) {
+            terminal::spawn_terminal(&app, &work_dir, Some(&command)).map_err(launch_failed)?;
 
-        Ok(())
+            sessions::start_session(&app, &project_path, session_files, None);
+            return Ok(());
+        }
+
+        // Start detached in the project's tmux session, then attach a terminal to it
+        let tmux_session = tmux::session_name(&project_path);
+        tmux::run_in_session(&tmux_session, &work_dir, &command).map_err(launch_failed)?;
+        log::info!(project = project_path.as_str(); "Started Claude Code in tmux session {}", tmux_session);
+
+        sessions::start_session(&app, &project_path, session_files, Some(tmux_session.clone()));
+        tmux::attach_in_terminal(&app, &tmux_session, &work_dir).map_err(|e| {
+            format!("Claude Code is running in tmux session '{}', but opening a terminal failed: {}", tmux_session, e)
+        })
     }
 }
 
@@ -245,8 +210,9 @@ pub async fn open_in_vscode(project_path: String) -> Result<(), String> {
     Ok(())
 }
 
+#[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
 #[tauri::command]
-pub async fn open_in_terminal(project_path: String) -> Result<(), String> {
+pub async fn open_in_terminal(app: AppHandle, project_path: String) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
         // Note: We intentionally DO show the terminal window (user requested it)
@@ -270,9 +236,7 @@ pub async fn open_in_terminal(project_path: String) -> Result<(), String> {
 
     #[cfg(target_os = "linux")]
     {
-        Command::new("gnome-terminal")
-            .args(&["--working-directory", &project_path])
-            .spawn()
+        terminal::spawn_terminal(&app, &project_path, None)
             .map_err(|e| format!("Failed to open terminal: {}", e))?;
     }
 
@@ -280,9 +244,12 @@ pub async fn open_in_terminal(project_path: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub async fn get_debug_log_path() -> Result<String, String> {
-    let log_path = std::env::temp_dir().join("vibe-hub-debug.log");
-    Ok(log_path.to_string_lossy().to_string())
+pub async fn get_debug_log_path(app: AppHandle) -> Result<String, String> {
+    let dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get app log directory: {}", e))?;
+    Ok(logs::log_path(&dir).to_string_lossy().to_string())
 }
 
 #[tauri::command]
@@ -332,63 +299,9 @@ pub async fn open_in_fork(project_path: String) -> Result<(), String> {
     }
 }
 
+#[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
 #[tauri::command]
-pub async fn get_session_status(project_path: String) -> Result<SessionInfo, String> {
-    // First check if we have a session registered
-    let has_session = {
-        let sessions = SESSIONS.lock().unwrap();
-        sessions.contains_key(&project_path)
-    };
-
-    if has_session {
-        // Only verify window exists after grace period (30 seconds)
-        // This gives the terminal time to launch and set its title
-        let should_verify = {
-            let sessions = SESSIONS.lock().unwrap();
-            if let Some(session) = sessions.get(&project_path) {
-                if let Some(created_at) = session.created_at {
-                    created_at.elapsed().as_secs() > 30
-                } else {
-                    true // Verify if no timestamp (old sessions)
-                }
-            } else {
-                false
-            }
-        };
-
-        // Note: Window verification disabled because Claude changes window title dynamically
-        // Session will remain active until manually cleared or app restart
-        // TODO: Implement working directory enumeration for reliable window tracking
-        #[cfg(target_os = "windows")]
-        if should_verify {
-            log_to_file(&format!("Session verification skipped (window title unreliable) for {}", project_path));
-        }
-
-        // Window still exists or we're not on Windows, return the session
-        let sessions = SESSIONS.lock().unwrap();
-        if let Some(session) = sessions.get(&project_path) {
-            Ok(session.clone())
-        } else {
-            Ok(SessionInfo {
-                project_path: project_path.clone(),
-                status: "not_started".to_string(),
-                pid: None,
-                created_at: None,
-            })
-        }
-    } else {
-        // Return not_started status if no session exists
-        Ok(SessionInfo {
-            project_path: project_path.clone(),
-            status: "not_started".to_string(),
-            pid: None,
-            created_at: None,
-        })
-    }
-}
-
-#[tauri::command]
-pub async fn focus_claude_terminal(project_path: String) -> Result<(), String> {
+pub async fn focus_claude_terminal(app: AppHandle, project_path: String) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
         use windows::Win32::UI::WindowsAndMessaging::{FindWindowW, SetForegroundWindow};
@@ -411,20 +324,31 @@ pub async fn focus_claude_terminal(project_path: String) -> Result<(), String> {
             if let Ok(hwnd) = FindWindowW(PCWSTR::null(), PCWSTR::from_raw(title_wide.as_ptr())) {
                 if !hwnd.is_invalid() {
                     let _ = SetForegroundWindow(hwnd);
-                    log_to_file(&format!("Found and focused window with title: {}", window_title));
+                    log::debug!("Found and focused window with title: {}", window_title);
                     return Ok(());
                 }
             }
 
             // If we couldn't find the window, just log and return error
             // Don't remove the session - let get_session_status handle cleanup
-            log_to_file(&format!("Window '{}' not found for focus, but keeping session active", window_title));
+            log::warn!("Window '{}' not found for focus, but keeping session active", window_title);
             Err(format!("Claude terminal window '{}' not found. It may be minimized or the window title may have changed.", window_title))
         }
     }
 
-    #[cfg(not(target_os = "windows"))]
+    // Focusing a tmux session means attaching to it in a new terminal
+    #[cfg(target_os = "linux")]
+    {
+        let tmux_session = tmux::session_name(&project_path);
+        if !tmux::enabled(&app) || !tmux::has_session(&tmux_session) {
+            return Err("Focus terminal needs a running tmux session on Linux (turn on tmux sessions in Settings)".to_string());
+        }
+
+        tmux::attach_in_terminal(&app, &tmux_session, &project_path)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
     {
-        Err("Focus terminal is only supported on Windows".to_string())
+        Err("Focus terminal is only supported on Windows and Linux".to_string())
     }
 }
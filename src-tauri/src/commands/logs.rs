@@ -0,0 +1,299 @@
+// App logging
+// Everything logs through the `log` macros; the module path is the target and a `project`
+// key names the project an entry is about, e.g. `log::info!(project = path; "...")`.
+// Entries are written as JSON lines to vibe-hub.log in the app log directory, which is
+// rotated by size, and can be searched from the UI with query_logs.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::models::LogEntry;
+
+const LOG_FILE: &str = "vibe-hub.log";
+// Rotate once the current file reaches this size, keeping this many older files
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+const MAX_ROTATED_FILES: usize = 3;
+const DEFAULT_QUERY_LIMIT: usize = 500;
+// Our own modules log at debug level; dependencies only from info up
+const CRATE_PREFIX: &str = "vibe_hub_lib";
+
+pub(crate) fn log_path(dir: &Path) -> PathBuf {
+    dir.join(LOG_FILE)
+}
+
+fn rotated_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("{}.{}", LOG_FILE, index))
+}
+
+/// "vibe_hub_lib::commands::github_sync" -> "github_sync"
+fn module_name(target: &str) -> String {
+    target
+        .strip_prefix("vibe_hub_lib::commands::")
+        .or_else(|| target.strip_prefix("vibe_hub_lib::"))
+        .unwrap_or(target)
+        .to_string()
+}
+
+struct LogFile {
+    file: File,
+    size: u64,
+}
+
+pub(crate) struct FileLogger {
+    dir: PathBuf,
+    max_bytes: u64,
+    current: Mutex<Option<LogFile>>,
+}
+
+impl FileLogger {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self { dir, max_bytes: MAX_FILE_BYTES, current: Mutex::new(None) }
+    }
+
+    fn open(&self) -> std::io::Result<LogFile> {
+        fs::create_dir_all(&self.dir)?;
+        let file = OpenOptions::new().create(true).append(true).open(log_path(&self.dir))?;
+        let size = file.metadata()?.len();
+        Ok(LogFile { file, size })
+    }
+
+    /// vibe-hub.log becomes vibe-hub.log.1, .1 becomes .2 and so on; the oldest is dropped
+    fn rotate(&self) -> std::io::Result<()> {
+        let _ = fs::remove_file(rotated_path(&self.dir, MAX_ROTATED_FILES));
+        for index in (1..MAX_ROTATED_FILES).rev() {
+            let from = rotated_path(&self.dir, index);
+            if from.exists() {
+                fs::rename(from, rotated_path(&self.dir, index + 1))?;
+            }
+        }
+        fs::rename(log_path(&self.dir), rotated_path(&self.dir, 1))
+    }
+
+    pub(crate) fn write_entry(&self, entry: &LogEntry) -> std::io::Result<()> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        let mut current = self.current.lock().unwrap();
+        if current.as_ref().is_some_and(|log| log.size > 0 && log.size + line.len() as u64 > self.max_bytes) {
+            *current = None;
+            self.rotate()?;
+        }
+        if current.is_none() {
+            *current = Some(self.open()?);
+        }
+
+        let log = current.as_mut().unwrap();
+        log.file.write_all(line.as_bytes())?;
+        log.size += line.len() as u64;
+        Ok(())
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info || metadata.target().starts_with(CRATE_PREFIX) || metadata.target() == "frontend"
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let entry = LogEntry {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            level: record.level().to_string(),
+            module: module_name(record.target()),
+            message: record.args().to_string(),
+            project: record.key_values().get("project".into()).map(|value| value.to_string()),
+        };
+
+        // Still echoed to stderr for `tauri dev`
+        eprintln!("[{}] {} [{}] {}", entry.timestamp, entry.level, entry.module, entry.message);
+        if let Err(e) = self.write_entry(&entry) {
+            eprintln!("[logs] Failed to write log file: {}", e);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(log) = self.current.lock().unwrap().as_mut() {
+            let _ = log.file.flush();
+        }
+    }
+}
+
+/// Install the file logger; called once at startup
+pub fn init(app: &AppHandle) -> Result<(), String> {
+    let dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get app log directory: {}", e))?;
+    log::set_boxed_logger(Box::new(FileLogger::new(dir)))
+        .map_err(|e| format!("Failed to install logger: {}", e))?;
+    log::set_max_level(LevelFilter::Debug);
+    Ok(())
+}
+
+/// Filters for query_logs; every one is optional
+#[derive(Debug, Default)]
+pub(crate) struct LogFilter {
+    pub min_level: Option<Level>,
+    pub module: Option<String>,
+    pub project: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl LogFilter {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        let level_ok = match (self.min_level, Level::from_str(&entry.level)) {
+            (Some(min), Ok(level)) => level <= min,
+            _ => true,
+        };
+        let module_ok = self.module.as_deref().is_none_or(|module| {
+            entry.module == module || entry.module.starts_with(&format!("{}::", module))
+        });
+        let project_ok = self.project.as_deref().is_none_or(|project| entry.project.as_deref() == Some(project));
+
+        let time = DateTime::parse_from_rfc3339(&entry.timestamp).map(|t| t.with_timezone(&Utc));
+        let time_ok = match time {
+            Ok(time) => self.since.is_none_or(|since| time >= since) && self.until.is_none_or(|until| time <= until),
+            Err(_) => self.since.is_none() && self.until.is_none(),
+        };
+
+        level_ok && module_ok && project_ok && time_ok
+    }
+}
+
+/// Matching entries from the current and rotated log files, newest first
+pub(crate) fn read_entries(dir: &Path, filter: &LogFilter, limit: usize) -> Vec<LogEntry> {
+    let files = std::iter::once(log_path(dir)).chain((1..=MAX_ROTATED_FILES).map(|index| rotated_path(dir, index)));
+
+    let mut entries = Vec::new();
+    for path in files {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        // Lines that aren't entries (e.g. from older versions) are skipped
+        let matching = content
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str::<LogEntry>(line).ok())
+            .filter(|entry| filter.matches(entry));
+        entries.extend(matching.take(limit - entries.len()));
+        if entries.len() >= limit {
+            break;
+        }
+    }
+    entries
+}
+
+fn parse_time(value: Option<String>, name: &str) -> Result<Option<DateTime<Utc>>, String> {
+    value
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            DateTime::parse_from_rfc3339(&v)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| format!("Invalid {} time '{}': {}", name, v, e))
+        })
+        .transpose()
+}
+
+/// Search the app log. `level` is the least severe level to include
+#[tauri::command]
+pub async fn query_logs(
+    app: AppHandle,
+    level: Option<String>,
+    module: Option<String>,
+    project_path: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<LogEntry>, String> {
+    let dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get app log directory: {}", e))?;
+
+    let filter = LogFilter {
+        min_level: level
+            .filter(|l| !l.is_empty())
+            .map(|l| Level::from_str(&l).map_err(|_| format!("Unknown log level '{}'", l)))
+            .transpose()?,
+        module: module.filter(|m| !m.is_empty()),
+        project: project_path.filter(|p| !p.is_empty()),
+        since: parse_time(since, "start")?,
+        until: parse_time(until, "end")?,
+    };
+
+    Ok(read_entries(&dir, &filter, limit.unwrap_or(DEFAULT_QUERY_LIMIT).max(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: &str, module: &str, project: Option<&str>, timestamp: &str) -> LogEntry {
+        LogEntry {
+            timestamp: timestamp.to_string(),
+            level: level.to_string(),
+            module: module.to_string(),
+            message: format!("{} from {}", level, module),
+            project: project.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_log_files_rotate_by_size() {
+        let dir = std::env::temp_dir().join(format!("vibe-logs-test-{}", uuid::Uuid::new_v4()));
+        let logger = FileLogger { max_bytes: 1000, ..FileLogger::new(dir.clone()) };
+        let line = entry("INFO", "runs", None, "2024-01-01T00:00:00.000Z");
+        for _ in 0..100 {
+            logger.write_entry(&line).unwrap();
+        }
+
+        assert!(fs::metadata(log_path(&dir)).unwrap().len() <= 1000);
+        assert!(fs::metadata(rotated_path(&dir, 1)).unwrap().len() > 900);
+        assert!(rotated_path(&dir, MAX_ROTATED_FILES).exists());
+        assert!(!rotated_path(&dir, MAX_ROTATED_FILES + 1).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_query_filters_by_level_module_project_and_time() {
+        let dir = std::env::temp_dir().join(format!("vibe-logs-test-{}", uuid::Uuid::new_v4()));
+        let logger = FileLogger::new(dir.clone());
+        for e in [
+            entry("DEBUG", "runs", Some("/p/a"), "2024-01-01T10:00:00.000Z"),
+            entry("WARN", "runs", Some("/p/a"), "2024-01-01T11:00:00.000Z"),
+            entry("ERROR", "github_sync", Some("/p/b"), "2024-01-01T12:00:00.000Z"),
+            entry("ERROR", "runs", None, "2024-01-01T13:00:00.000Z"),
+        ] {
+            logger.write_entry(&e).unwrap();
+        }
+
+        let warnings = LogFilter { min_level: Some(Level::Warn), ..Default::default() };
+        let found = read_entries(&dir, &warnings, 10);
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].timestamp, "2024-01-01T13:00:00.000Z", "newest first");
+
+        let project_runs = LogFilter {
+            module: Some("runs".to_string()),
+            project: Some("/p/a".to_string()),
+            since: Some("2024-01-01T10:30:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let found = read_entries(&dir, &project_runs, 10);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].level, "WARN");
+
+        assert_eq!(read_entries(&dir, &LogFilter::default(), 2).len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
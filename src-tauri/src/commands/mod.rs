@@ -3,7 +3,33 @@ pub mod projects;
 pub mod feedback;
 pub mod issues;
 pub mod launcher;
+pub mod logs;
+pub mod sessions;
+pub mod transcripts;
+pub mod terminal;
+pub mod tmux;
+pub mod agents;
+pub mod spawn;
+pub mod pty;
+pub mod runs;
+pub mod work_queue;
+pub mod worktrees;
 pub mod prompts;
 pub mod npm;
+pub mod tasks;
+pub mod dev_servers;
+pub mod dev_output;
+pub mod ports;
 pub mod watcher;
 pub mod github;
+pub mod github_sync;
+pub mod github_mapping;
+pub mod github_discussions;
+pub mod github_board;
+pub mod github_repo;
+pub mod tracker;
+pub mod gitlab;
+pub mod gitea;
+pub mod outbound;
+pub mod repo_status;
+pub mod credentials;
@@ -1,17 +1,23 @@
 use std::fs;
 use std::path::Path;
+#[cfg(not(target_os = "linux"))]
 use std::process::Command;
 use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::commands::tasks;
+#[cfg(target_os = "linux")]
+use crate::commands::terminal;
+use crate::models::ProjectTask;
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
-/// Common dev server ports to check
-const DEV_SERVER_PORTS: &[u16] = &[3000, 5173, 8080, 4200, 5000, 8000, 1420, 5713];
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PackageJson {
     pub scripts: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "packageManager")]
+    pub package_manager: Option<String>, // e.g. "pnpm@8.15.0"
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,192 +25,98 @@ pub struct AvailableScripts {
     pub has_dev: bool,
     pub has_build: bool,
     pub dev_script_name: Option<String>,
-    pub dev_script_type: Option<String>, // "npm", "bat", or "sh"
+    pub dev_script_type: Option<String>, // "npm", "bat", "sh" or "command" (a task's command line)
     pub dev_command: Option<String>,
     pub build_command: Option<String>,
+    pub package_manager: Option<String>, // npm, pnpm, yarn or bun when there's a package.json
+    pub tasks: Vec<ProjectTask>,         // Everything runnable, from all build files
 }
 
-/// Detect available npm scripts in package.json and script files
+// Tasks that start a server, in order of preference, for projects without a package.json one
+const DEV_TASK_NAMES: &[&str] = &["dev", "start", "serve", "tauri dev", "run"];
+
+/// Detect the project's dev and build scripts, and every task it has
 #[tauri::command]
 pub async fn detect_npm_scripts(project_path: String) -> Result<AvailableScripts, String> {
     let path = Path::new(&project_path);
-
-    // First, check for .bat or .sh script files (prioritize these for complex setups)
-    let dev_bat_files = ["dev.bat", "start.bat", "dev-server.bat", "start-dev.bat"];
-    let dev_sh_files = ["dev.sh", "start.sh", "dev-server.sh", "start-dev.sh"];
-
-    // Check for Windows batch files
-    for bat_file in &dev_bat_files {
-        let bat_path = path.join(bat_file);
-        if bat_path.exists() {
-            return Ok(AvailableScripts {
-                has_dev: true,
-                has_build: false,
-                dev_script_name: Some(bat_file.to_string()),
-                dev_script_type: Some("bat".to_string()),
-                dev_command: None,
-                build_command: None,
-            });
-        }
-    }
-
-    // Check for shell scripts
-    for sh_file in &dev_sh_files {
-        let sh_path = path.join(sh_file);
-        if sh_path.exists() {
-            return Ok(AvailableScripts {
-                has_dev: true,
-                has_build: false,
-                dev_script_name: Some(sh_file.to_string()),
-                dev_script_type: Some("sh".to_string()),
-                dev_command: None,
-                build_command: None,
-            });
-        }
-    }
-
-    // If no script files found, check package.json
     let package_json_path = path.join("package.json");
 
-    if !package_json_path.exists() {
-        return Ok(AvailableScripts {
-            has_dev: false,
-            has_build: false,
-            dev_script_name: None,
-            dev_script_type: None,
-            dev_command: None,
-            build_command: None,
-        });
-    }
-
-    let contents = fs::read_to_string(&package_json_path)
-        .map_err(|e| format!("Failed to read package.json: {}", e))?;
-
-    let package: PackageJson = serde_json::from_str(&contents)
-        .map_err(|e| format!("Failed to parse package.json: {}", e))?;
-
-    if let Some(scripts) = package.scripts {
-        // Check for dev server scripts - prioritize "dev" over "start"
-        let (has_dev, dev_script_name, dev_command) = if scripts.contains_key("dev") {
-            (true, Some("dev".to_string()), Some(scripts.get("dev").unwrap().clone()))
-        } else if scripts.contains_key("start") {
-            (true, Some("start".to_string()), Some(scripts.get("start").unwrap().clone()))
-        } else {
-            (false, None, None)
-        };
-
-        let has_build = scripts.contains_key("build");
+    let mut available = AvailableScripts {
+        has_dev: false,
+        has_build: false,
+        dev_script_name: None,
+        dev_script_type: None,
+        dev_command: None,
+        build_command: None,
+        package_manager: package_json_path.exists().then(|| tasks::package_manager(path).to_string()),
+        tasks: tasks::detect_tasks(path),
+    };
 
-        Ok(AvailableScripts {
-            has_dev,
-            has_build,
-            dev_script_name,
-            dev_script_type: if has_dev { Some("npm".to_string()) } else { None },
-            dev_command,
-            build_command: if has_build {
-                Some(scripts.get("build").unwrap().clone())
-            } else {
-                None
-            },
-        })
-    } else {
-        Ok(AvailableScripts {
-            has_dev: false,
-            has_build: false,
-            dev_script_name: None,
-            dev_script_type: None,
-            dev_command: None,
-            build_command: None,
-        })
+    // First, check for .bat or .sh script files (prioritize these for complex setups)
+    if let Some(script) = available.tasks.iter().find(|task| task.kind == "bat" || task.kind == "sh") {
+        available.has_dev = true;
+        available.dev_script_name = Some(script.source.clone());
+        available.dev_script_type = Some(script.kind.clone());
+        return Ok(available);
     }
-}
 
-/// Kill processes running on dev server ports
-fn terminate_dev_servers() -> Result<(), String> {
-    #[cfg(target_os = "windows")]
-    {
-        for port in DEV_SERVER_PORTS {
-            // Use netstat to find PIDs listening on this port
-            let output = Command::new("cmd")
-                .args(&["/c", &format!("netstat -ano | findstr :{}.*LISTENING", port)])
-                .creation_flags(0x08000000) // CREATE_NO_WINDOW
-                .output()
-                .map_err(|e| format!("Failed to run netstat: {}", e))?;
+    // Then package.json, prioritizing "dev" over "start"
+    if package_json_path.exists() {
+        let contents = fs::read_to_string(&package_json_path)
+            .map_err(|e| format!("Failed to read package.json: {}", e))?;
 
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
+        let package: PackageJson = serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse package.json: {}", e))?;
 
-                // Parse PIDs from netstat output
-                for line in output_str.lines() {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if let Some(pid_str) = parts.last() {
-                        // Kill the process
-                        let _ = Command::new("taskkill")
-                            .args(&["/F", "/PID", pid_str])
-                            .creation_flags(0x08000000) // CREATE_NO_WINDOW
-                            .output();
-                    }
-                }
+        if let Some(scripts) = package.scripts {
+            if let Some(name) = ["dev", "start"].into_iter().find(|name| scripts.contains_key(*name)) {
+                available.has_dev = true;
+                available.dev_script_name = Some(name.to_string());
+                available.dev_script_type = Some("npm".to_string());
+                available.dev_command = scripts.get(name).cloned();
             }
+            available.has_build = scripts.contains_key("build");
+            available.build_command = scripts.get("build").cloned();
         }
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        for port in DEV_SERVER_PORTS {
-            // Use lsof to find PIDs listening on this port
-            let output = Command::new("lsof")
-                .args(&["-ti", &format!("tcp:{}", port)])
-                .output()
-                .map_err(|e| format!("Failed to run lsof: {}", e))?;
-
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                for pid_str in output_str.lines() {
-                    if !pid_str.trim().is_empty() {
-                        let _ = Command::new("kill")
-                            .args(&["-9", pid_str.trim()])
-                            .output();
-                    }
-                }
-            }
+    // Otherwise a task from another ecosystem, started by its command line
+    if !available.has_dev {
+        if let Some(task) = DEV_TASK_NAMES
+            .iter()
+            .find_map(|name| available.tasks.iter().find(|task| task.name == *name))
+        {
+            available.has_dev = true;
+            available.dev_script_name = Some(task.command.clone());
+            available.dev_script_type = Some("command".to_string());
+            available.dev_command = Some(task.command.clone());
         }
     }
-
-    #[cfg(target_os = "linux")]
-    {
-        for port in DEV_SERVER_PORTS {
-            // Use lsof to find PIDs listening on this port
-            let output = Command::new("lsof")
-                .args(&["-ti", &format!("tcp:{}", port)])
-                .output()
-                .map_err(|e| format!("Failed to run lsof: {}", e))?;
-
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                for pid_str in output_str.lines() {
-                    if !pid_str.trim().is_empty() {
-                        let _ = Command::new("kill")
-                            .args(&["-9", pid_str.trim()])
-                            .output();
-                    }
-                }
-            }
+    if !available.has_build {
+        if let Some(task) = available.tasks.iter().find(|task| task.name == "build") {
+            available.has_build = true;
+            available.build_command = Some(task.command.clone());
         }
     }
 
-    Ok(())
+    Ok(available)
 }
 
-/// Run an npm script or script file in a new terminal window
+/// Run a package.json script, script file or task command ("command") in a new terminal window (dev servers are started
+/// through the supervisor in dev_servers.rs instead)
+#[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
 #[tauri::command]
-pub async fn run_npm_script(project_path: String, script_name: String, script_type: Option<String>) -> Result<(), String> {
-    // Terminate any existing dev servers before starting a new one
-    terminate_dev_servers()?;
+pub async fn run_npm_script(app: AppHandle, project_path: String, script_name: String, script_type: Option<String>) -> Result<(), String> {
+    let script_type = script_type.unwrap_or_else(|| "npm".to_string());
+    // Tasks run as they are, package.json scripts with the project's package manager
+    let run_command = if script_type == "command" {
+        script_name.clone()
+    } else {
+        format!("{} run {}", tasks::package_manager(Path::new(&project_path)), script_name)
+    };
+
     #[cfg(target_os = "windows")]
     {
-        let script_type = script_type.unwrap_or_else(|| "npm".to_string());
-
         if script_type == "bat" {
             // For .bat files, just run them directly with cmd
             let bat_path = Path::new(&project_path).join(&script_name);
@@ -224,10 +136,9 @@ pub async fn run_npm_script(project_path: String, script_name: String, script_ty
                 .spawn()
                 .map_err(|e| format!("Failed to launch sh file: {}", e))?;
         } else {
-            // For npm scripts, use the npm run command
-            let command = format!("npm run {}", script_name);
+            // For package.json scripts and tasks, run the command line
             Command::new("cmd")
-                .args(&["/c", "start", "cmd", "/k", &command])
+                .args(&["/c", "start", "cmd", "/k", &run_command])
                 .current_dir(&project_path)
                 .creation_flags(0x08000000) // CREATE_NO_WINDOW - hide the intermediate cmd window
                 .spawn()
@@ -237,12 +148,10 @@ pub async fn run_npm_script(project_path: String, script_name: String, script_ty
 
     #[cfg(target_os = "macos")]
     {
-        let script_type = script_type.unwrap_or_else(|| "npm".to_string());
-
         let command = if script_type == "bat" || script_type == "sh" {
             format!("cd '{}' && ./{}", project_path, script_name)
         } else {
-            format!("cd '{}' && npm run {}", project_path, script_name)
+            format!("cd '{}' && {}", project_path, run_command)
         };
 
         let script = format!(
@@ -257,18 +166,13 @@ pub async fn run_npm_script(project_path: String, script_name: String, script_ty
 
     #[cfg(target_os = "linux")]
     {
-        let script_type = script_type.unwrap_or_else(|| "npm".to_string());
-
         let command = if script_type == "bat" || script_type == "sh" {
             format!("cd '{}' && ./{}; exec bash", project_path, script_name)
         } else {
-            format!("cd '{}' && npm run {}; exec bash", project_path, script_name)
+            format!("cd '{}' && {}; exec bash", project_path, run_command)
         };
 
-        Command::new("gnome-terminal")
-            .args(&["--", "bash", "-c", &command])
-            .spawn()
-            .map_err(|e| format!("Failed to launch terminal: {}", e))?;
+        terminal::spawn_terminal(&app, &project_path, Some(&command))?;
     }
 
     Ok(())
@@ -0,0 +1,694 @@
+// Persistent queue for outbound issue tracker operations (close, comment, labels, create)
+// Operations that fail are kept in outbound-queue.json in the app data directory and retried
+// with exponential backoff by a background loop, or on request from the UI. Only the newest
+// operation per target (issue, local comment or local item) is kept.
+
+use chrono::{DateTime, Duration, Utc};
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::commands::github::{IssuePatch, RemoteComment, RemoteIssue};
+use crate::commands::issues::{
+    read_feedback_file, read_issues_archive_file, read_issues_file, write_feedback_file,
+    write_issues_archive_file, write_issues_file, FEEDBACK_COMPLETED_FILE, FEEDBACK_FILE,
+};
+use crate::commands::tracker::{self, IssueTracker};
+use crate::models::{Comment, OutboundAction, OutboundOperation, OutboundQueue};
+
+const QUEUE_FILE: &str = "outbound-queue.json";
+
+/// After this many attempts an operation is marked failed and only retried on request
+const MAX_ATTEMPTS: u32 = 8;
+
+/// How often the background loop looks for operations that are due
+const RETRY_INTERVAL_SECS: u64 = 60;
+
+lazy_static! {
+    // Serialises queue file updates between commands and the background loop
+    static ref QUEUE_LOCK: Mutex<()> = Mutex::new(());
+    // Keeps two retry passes from running the same operation twice; held across network calls
+    static ref PROCESS_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// Builds the tracker an operation should run against
+type TrackerFactory<'a> = dyn Fn(&OutboundOperation) -> Result<Box<dyn IssueTracker>, String> + Send + Sync + 'a;
+
+fn queue_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get data directory: {}", e))
+        .map(|path| path.join(QUEUE_FILE))
+}
+
+fn read_queue(path: &Path) -> Result<OutboundQueue, String> {
+    if !path.exists() {
+        return Ok(OutboundQueue::default());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read outbound queue: {}", e))?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse outbound queue: {}", e))
+}
+
+fn write_queue(path: &Path, queue: &OutboundQueue) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string_pretty(queue)
+        .map_err(|e| format!("Failed to serialize outbound queue: {}", e))?;
+
+    fs::write(path, content)
+        .map_err(|e| format!("Failed to write outbound queue: {}", e))
+}
+
+fn new_operation(project_path: Option<&Path>, repo_url: &str, action: OutboundAction) -> OutboundOperation {
+    let now = Utc::now().to_rfc3339();
+    OutboundOperation {
+        id: Uuid::new_v4().to_string(),
+        project_path: project_path.map(|p| p.to_string_lossy().to_string()),
+        repo_url: repo_url.to_string(),
+        action,
+        status: "pending".to_string(),
+        attempts: 0,
+        created_at: now.clone(),
+        last_attempt_at: None,
+        next_attempt_at: now,
+        last_error: None,
+    }
+}
+
+/// Delay before the next attempt: 30s, doubling per attempt, capped at an hour
+fn retry_delay(attempts: u32) -> Duration {
+    let seconds = 30i64.saturating_mul(1i64 << attempts.saturating_sub(1).min(10));
+    Duration::seconds(seconds.min(3600))
+}
+
+fn record_failure(operation: &mut OutboundOperation, error: String, now: DateTime<Utc>) {
+    operation.attempts += 1;
+    operation.last_attempt_at = Some(now.to_rfc3339());
+    operation.next_attempt_at = (now + retry_delay(operation.attempts)).to_rfc3339();
+    operation.last_error = Some(error);
+    if operation.attempts >= MAX_ATTEMPTS {
+        operation.status = "failed".to_string();
+    }
+}
+
+fn is_due(operation: &OutboundOperation, now: DateTime<Utc>) -> bool {
+    operation.status == "pending"
+        && DateTime::parse_from_rfc3339(&operation.next_attempt_at)
+            .map(|t| t.with_timezone(&Utc) <= now)
+            .unwrap_or(true)
+}
+
+/// Link a newly created remote issue to the local feedback item or issue it came from
+fn link_created_issue(project_path: &Path, local_id: &str, remote: &RemoteIssue) -> Result<(), String> {
+    let now = Utc::now().to_rfc3339();
+    let remote_updated_at = remote.updated_at.to_rfc3339();
+
+    let mut feedback_file = read_feedback_file(project_path, FEEDBACK_FILE)?;
+    if let Some(item) = feedback_file.feedback.iter_mut().find(|f| f.id == local_id) {
+        item.github_issue_number = Some(remote.number);
+        item.github_issue_url = Some(remote.html_url.clone());
+        item.github_synced_at = Some(now);
+        item.github_updated_at = Some(remote_updated_at);
+        return write_feedback_file(project_path, FEEDBACK_FILE, &feedback_file);
+    }
+
+    let mut issues_file = read_issues_file(project_path)?;
+    if let Some(issue) = issues_file.issues.iter_mut().find(|i| i.id == local_id) {
+        issue.github_issue_number = Some(remote.number);
+        issue.github_issue_url = Some(remote.html_url.clone());
+        issue.github_synced_at = Some(now);
+        issue.github_updated_at = Some(remote_updated_at);
+        return write_issues_file(project_path, &issues_file);
+    }
+
+    Err(format!("Created issue #{} but local item {} no longer exists", remote.number, local_id))
+}
+
+/// Record the remote id of a posted comment on the local comment it came from
+fn link_posted_comment(project_path: &Path, local_id: &str, posted: &RemoteComment) -> Result<(), String> {
+    let link = |comments: &mut Option<Vec<Comment>>| {
+        let comment = comments.as_mut()?.iter_mut().find(|c| c.id == local_id)?;
+        comment.github_comment_id = Some(posted.id);
+        if let Some(user) = &posted.user {
+            comment.author = user.login.clone();
+        }
+        Some(())
+    };
+
+    for file in [FEEDBACK_FILE, FEEDBACK_COMPLETED_FILE] {
+        let mut feedback_file = read_feedback_file(project_path, file)?;
+        if feedback_file.feedback.iter_mut().any(|f| link(&mut f.comments).is_some()) {
+            return write_feedback_file(project_path, file, &feedback_file);
+        }
+    }
+
+    let mut issues_file = read_issues_file(project_path)?;
+    if issues_file.issues.iter_mut().any(|i| link(&mut i.comments).is_some()) {
+        return write_issues_file(project_path, &issues_file);
+    }
+    let mut archive = read_issues_archive_file(project_path)?;
+    if archive.issues.iter_mut().any(|i| link(&mut i.comments).is_some()) {
+        return write_issues_archive_file(project_path, &archive);
+    }
+
+    Err(format!("Posted comment {} but local comment {} no longer exists", posted.id, local_id))
+}
+
+/// Run one operation against its tracker
+async fn execute(tracker: &dyn IssueTracker, operation: &OutboundOperation) -> Result<(), String> {
+    match &operation.action {
+        OutboundAction::Close { issue_number, comment } => {
+            let patch = IssuePatch {
+                state: Some("closed".to_string()),
+                ..Default::default()
+            };
+            tracker.update_issue(*issue_number, &patch).await?;
+            if let Some(comment) = comment {
+                tracker.post_comment(*issue_number, comment).await?;
+            }
+        }
+        OutboundAction::Comment { issue_number, body, local_id } => {
+            let posted = tracker.post_comment(*issue_number, body).await?;
+            // Like a create, a posted comment must not be posted again because linking failed
+            if let (Some(local_id), Some(project_path)) = (local_id, &operation.project_path) {
+                if let Err(e) = link_posted_comment(Path::new(project_path), local_id, &posted) {
+                    log::warn!(project = project_path.as_str(); "Failed to link posted comment {}: {}", posted.id, e);
+                }
+            }
+        }
+        OutboundAction::Labels { issue_number, labels } => {
+            let patch = IssuePatch {
+                labels: Some(labels.clone()),
+                ..Default::default()
+            };
+            tracker.update_issue(*issue_number, &patch).await?;
+        }
+        OutboundAction::Create { title, body, labels, local_id } => {
+            let created = tracker.create_issue(title, body, labels).await?;
+            // The issue exists now, so a failed link must not fail the operation: a retry
+            // would create it again
+            if let (Some(local_id), Some(project_path)) = (local_id, &operation.project_path) {
+                if let Err(e) = link_created_issue(Path::new(project_path), local_id, &created) {
+                    log::warn!(project = project_path.as_str(); "Failed to link created issue #{}: {}", created.number, e);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn run_operation(make_tracker: &TrackerFactory<'_>, operation: &OutboundOperation) -> Result<(), String> {
+    let tracker = make_tracker(operation)?;
+    execute(tracker.as_ref(), operation).await
+}
+
+/// Retry queued operations that are due (or all of them, including failed ones, with `force`)
+///
+/// The queue lock is only held to read and merge the queue, not across tracker calls, so the
+/// UI can still inspect or clear it while retries wait on the network. Successful operations
+/// are removed; the rest are rescheduled. Returns the remaining queue.
+async fn process_queue(path: &Path, make_tracker: &TrackerFactory<'_>, force: bool) -> Result<OutboundQueue, String> {
+    let _processing = PROCESS_LOCK.lock().await;
+
+    let due: Vec<OutboundOperation> = {
+        let _guard = QUEUE_LOCK.lock().await;
+        let now = Utc::now();
+        read_queue(path)?
+            .operations
+            .into_iter()
+            .filter(|operation| force || is_due(operation, now))
+            .collect()
+    };
+
+    let mut results = HashMap::new();
+    for mut operation in due {
+        let result = match run_operation(make_tracker, &operation).await {
+            Ok(()) => None,
+            Err(e) => {
+                record_failure(&mut operation, e, Utc::now());
+                Some(operation.clone())
+            }
+        };
+        results.insert(operation.id.clone(), result);
+    }
+
+    // Merge by id: operations added or cleared meanwhile are left as they are now
+    let _guard = QUEUE_LOCK.lock().await;
+    let mut queue = read_queue(path)?;
+    queue.operations = queue
+        .operations
+        .into_iter()
+        .filter_map(|operation| match results.remove(&operation.id) {
+            Some(result) => result,
+            None => Some(operation),
+        })
+        .collect();
+    write_queue(path, &queue)?;
+    Ok(queue)
+}
+
+/// Add an operation that just failed to the queue, replacing a queued one with the same target
+async fn enqueue_failed(path: &Path, mut operation: OutboundOperation, error: String) -> Result<(), String> {
+    let _guard = QUEUE_LOCK.lock().await;
+    let mut queue = read_queue(path)?;
+    let existing = queue
+        .operations
+        .iter_mut()
+        .find(|op| op.repo_url == operation.repo_url && op.action.same_target(&operation.action));
+
+    match existing {
+        Some(existing) => {
+            existing.action = operation.action;
+            record_failure(existing, error, Utc::now());
+        }
+        None => {
+            record_failure(&mut operation, error, Utc::now());
+            queue.operations.push(operation);
+        }
+    }
+    write_queue(path, &queue)
+}
+
+/// Whether an operation with the same target as `action` is already queued
+async fn is_queued(path: &Path, repo_url: &str, action: &OutboundAction) -> Result<bool, String> {
+    let _guard = QUEUE_LOCK.lock().await;
+    Ok(read_queue(path)?
+        .operations
+        .iter()
+        .any(|op| op.repo_url == repo_url && op.action.same_target(action)))
+}
+
+/// Drop queued operations with the same target as `action`, once it went through another way
+async fn remove_queued(path: &Path, repo_url: &str, action: &OutboundAction) -> Result<(), String> {
+    let _guard = QUEUE_LOCK.lock().await;
+    let mut queue = read_queue(path)?;
+    let before = queue.operations.len();
+    queue.operations.retain(|op| !(op.repo_url == repo_url && op.action.same_target(action)));
+    if queue.operations.len() == before {
+        return Ok(());
+    }
+    write_queue(path, &queue)
+}
+
+/// The queue a project's sync hands the pushes it couldn't make to
+pub(crate) struct SyncOutbox<'a> {
+    pub queue_path: PathBuf,
+    pub project_path: &'a Path,
+    pub repo_url: &'a str,
+}
+
+impl<'a> SyncOutbox<'a> {
+    pub(crate) fn for_app(app: &AppHandle, project_path: &'a Path, repo_url: &'a str) -> Result<Self, String> {
+        Ok(SyncOutbox { queue_path: queue_path(app)?, project_path, repo_url })
+    }
+
+    /// Queue a push that failed during sync
+    pub(crate) async fn queue(&self, action: OutboundAction, error: String) -> Result<(), String> {
+        let operation = new_operation(Some(self.project_path), self.repo_url, action);
+        enqueue_failed(&self.queue_path, operation, error).await
+    }
+
+    /// Drop a queued push that sync has since made itself
+    pub(crate) async fn resolve(&self, action: &OutboundAction) -> Result<(), String> {
+        remove_queued(&self.queue_path, self.repo_url, action).await
+    }
+
+    /// Local comments whose posting is queued, which sync leaves to the queue
+    pub(crate) async fn queued_comment_ids(&self) -> Result<HashSet<String>, String> {
+        let _guard = QUEUE_LOCK.lock().await;
+        Ok(read_queue(&self.queue_path)?
+            .operations
+            .into_iter()
+            .filter(|op| op.repo_url == self.repo_url)
+            .filter_map(|op| match op.action {
+                OutboundAction::Comment { local_id, .. } => local_id,
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+fn app_tracker_factory(app: &AppHandle) -> impl Fn(&OutboundOperation) -> Result<Box<dyn IssueTracker>, String> + Send + Sync + '_ {
+    move |operation: &OutboundOperation| {
+        tracker::tracker_from_settings(app, operation.project_path.as_deref().map(Path::new), &operation.repo_url)
+    }
+}
+
+/// Try an operation now, queueing it for retry if it fails
+///
+/// An operation whose target is already queued is left to that entry instead, so a
+/// create can't reach the tracker twice. Returns true if it went through immediately,
+/// false if it was (or already is) queued.
+pub(crate) async fn submit(
+    app: &AppHandle,
+    project_path: Option<&Path>,
+    repo_url: &str,
+    action: OutboundAction,
+) -> Result<bool, String> {
+    if is_queued(&queue_path(app)?, repo_url, &action).await? {
+        return Ok(false);
+    }
+    let operation = new_operation(project_path, repo_url, action);
+
+    match run_operation(&app_tracker_factory(app), &operation).await {
+        Ok(()) => Ok(true),
+        Err(e) => {
+            log::warn!("Queued {:?} for {} after error: {}", operation.action, repo_url, e);
+            enqueue_failed(&queue_path(app)?, operation, e).await?;
+            Ok(false)
+        }
+    }
+}
+
+/// Start the background loop that retries due operations
+pub fn start_retry_loop(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(RETRY_INTERVAL_SECS)).await;
+
+            let path = match queue_path(&app) {
+                Ok(path) => path,
+                Err(_) => continue,
+            };
+            if !path.exists() {
+                continue;
+            }
+            if let Err(e) = process_queue(&path, &app_tracker_factory(&app), false).await {
+                log::error!("Failed to process queue: {}", e);
+            }
+        }
+    });
+}
+
+/// List queued outbound operations, including ones that have given up
+#[tauri::command]
+pub async fn get_outbound_queue(app: AppHandle) -> Result<Vec<OutboundOperation>, String> {
+    let path = queue_path(&app)?;
+    let _guard = QUEUE_LOCK.lock().await;
+    Ok(read_queue(&path)?.operations)
+}
+
+/// Retry every queued operation now, including failed ones. Returns what's left.
+#[tauri::command]
+pub async fn retry_outbound_queue(app: AppHandle) -> Result<Vec<OutboundOperation>, String> {
+    let path = queue_path(&app)?;
+    Ok(process_queue(&path, &app_tracker_factory(&app), true).await?.operations)
+}
+
+/// Remove operations from the queue (all of them if no ids are given). Returns what's left.
+#[tauri::command]
+pub async fn clear_outbound_queue(
+    app: AppHandle,
+    ids: Option<Vec<String>>,
+) -> Result<Vec<OutboundOperation>, String> {
+    let path = queue_path(&app)?;
+    let _guard = QUEUE_LOCK.lock().await;
+    let mut queue = read_queue(&path)?;
+
+    match ids {
+        Some(ids) => queue.operations.retain(|op| !ids.contains(&op.id)),
+        None => queue.operations.clear(),
+    }
+
+    write_queue(&path, &queue)?;
+    Ok(queue.operations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::github::GithubTracker;
+    use crate::models::{FeedbackFile, FeedbackItem};
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vibe-hub-outbound-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(dir.join(".vibe")).unwrap();
+        dir
+    }
+
+    fn issue_json(number: u64) -> serde_json::Value {
+        json!({
+            "number": number,
+            "title": "Title",
+            "body": null,
+            "state": "closed",
+            "html_url": format!("https://github.com/owner/repo/issues/{}", number),
+            "labels": [],
+            "comments": 0,
+            "updated_at": "2024-01-01T00:00:00Z",
+        })
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_and_caps() {
+        assert_eq!(retry_delay(1), Duration::seconds(30));
+        assert_eq!(retry_delay(3), Duration::seconds(120));
+        assert_eq!(retry_delay(20), Duration::seconds(3600));
+    }
+
+    #[tokio::test]
+    async fn test_failed_close_is_retried_after_backoff() {
+        let server = MockServer::start().await;
+        let queue_file = temp_dir().join(QUEUE_FILE);
+        let uri = server.uri();
+        let factory = move |_: &OutboundOperation| -> Result<Box<dyn IssueTracker>, String> {
+            Ok(Box::new(GithubTracker::new(&uri, "token", "owner", "repo")?))
+        };
+
+        // GitHub is down for the first attempt
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/3"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        let operation = new_operation(None, "https://github.com/owner/repo", OutboundAction::Close { issue_number: 3, comment: None });
+        let error = run_operation(&factory, &operation).await.unwrap_err();
+        enqueue_failed(&queue_file, operation, error).await.unwrap();
+
+        // Not due yet, so nothing is sent
+        let queue = process_queue(&queue_file, &factory, false).await.unwrap();
+        assert_eq!(queue.operations.len(), 1);
+        assert_eq!(queue.operations[0].attempts, 1);
+        assert!(queue.operations[0].last_error.is_some());
+
+        // Connectivity is back and the retry is forced
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/3"))
+            .and(body_partial_json(json!({ "state": "closed" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_json(3)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let queue = process_queue(&queue_file, &factory, true).await.unwrap();
+        assert!(queue.operations.is_empty());
+        assert!(read_queue(&queue_file).unwrap().operations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_queue_stays_usable_while_a_retry_waits_on_the_network() {
+        let server = MockServer::start().await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/4"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_json(4)).set_delay(std::time::Duration::from_secs(1)))
+            .mount(&server)
+            .await;
+
+        let queue_file = temp_dir().join(QUEUE_FILE);
+        let slow = new_operation(None, "https://github.com/owner/repo", OutboundAction::Close { issue_number: 4, comment: None });
+        write_queue(&queue_file, &OutboundQueue { operations: vec![slow] }).unwrap();
+
+        let uri = server.uri();
+        let retry_file = queue_file.clone();
+        let retry = tokio::spawn(async move {
+            let factory = move |_: &OutboundOperation| -> Result<Box<dyn IssueTracker>, String> {
+                Ok(Box::new(GithubTracker::new(&uri, "token", "owner", "repo")?))
+            };
+            process_queue(&retry_file, &factory, true).await
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+        // A new failure can be queued while the close is still in flight
+        let queued = new_operation(None, "https://github.com/owner/repo", OutboundAction::Close { issue_number: 5, comment: None });
+        let queued_id = queued.id.clone();
+        tokio::time::timeout(std::time::Duration::from_millis(500), enqueue_failed(&queue_file, queued, "offline".to_string()))
+            .await
+            .expect("queue was locked during the retry")
+            .unwrap();
+
+        let queue = retry.await.unwrap().unwrap();
+        assert_eq!(queue.operations.len(), 1);
+        assert_eq!(queue.operations[0].id, queued_id);
+    }
+
+    #[tokio::test]
+    async fn test_create_links_local_feedback() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(body_partial_json(json!({ "title": "Dark mode", "labels": ["ui"] })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(issue_json(12)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_dir();
+        let item = FeedbackItem {
+            id: "feedback-1".to_string(),
+            text: "Dark mode".to_string(),
+            priority: 3,
+            status: "pending".to_string(),
+            created_at: Utc::now().to_rfc3339(),
+            completed_at: None,
+            refined_into_issue_ids: None,
+            review_notes: None,
+            related_issue_id: None,
+            github_issue_number: None,
+            github_issue_url: None,
+            updated_at: None,
+            labels: Some(vec!["ui".to_string()]),
+            comments: None,
+            github_synced_at: None,
+            github_updated_at: None,
+            github_discussion_number: None,
+            github_discussion_url: None,
+        };
+        write_feedback_file(&project, FEEDBACK_FILE, &FeedbackFile { feedback: vec![item] }).unwrap();
+
+        let action = OutboundAction::Create {
+            title: "Dark mode".to_string(),
+            body: String::new(),
+            labels: vec!["ui".to_string()],
+            local_id: Some("feedback-1".to_string()),
+        };
+        let operation = new_operation(Some(&project), "https://github.com/owner/repo", action);
+        let tracker = GithubTracker::new(&server.uri(), "token", "owner", "repo").unwrap();
+        execute(&tracker, &operation).await.unwrap();
+
+        let linked = &read_feedback_file(&project, FEEDBACK_FILE).unwrap().feedback[0];
+        assert_eq!(linked.github_issue_number, Some(12));
+        assert!(linked.github_synced_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_create_is_not_repeated_when_local_item_is_gone() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(issue_json(13)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The feedback item was deleted while the create was queued
+        let project = temp_dir();
+        let queue_file = temp_dir().join(QUEUE_FILE);
+        let action = OutboundAction::Create {
+            title: "Deleted".to_string(),
+            body: String::new(),
+            labels: vec![],
+            local_id: Some("feedback-gone".to_string()),
+        };
+        let operation = new_operation(Some(&project), "https://github.com/owner/repo", action);
+        write_queue(&queue_file, &OutboundQueue { operations: vec![operation] }).unwrap();
+
+        let uri = server.uri();
+        let factory = move |_: &OutboundOperation| -> Result<Box<dyn IssueTracker>, String> {
+            Ok(Box::new(GithubTracker::new(&uri, "token", "owner", "repo")?))
+        };
+        assert!(process_queue(&queue_file, &factory, true).await.unwrap().operations.is_empty());
+        assert!(process_queue(&queue_file, &factory, true).await.unwrap().operations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_queued_operations_are_kept_once_per_target() {
+        let queue_file = temp_dir().join(QUEUE_FILE);
+        let repo = "https://github.com/owner/repo";
+        let create = |title: &str| OutboundAction::Create {
+            title: title.to_string(),
+            body: String::new(),
+            labels: vec![],
+            local_id: Some("feedback-1".to_string()),
+        };
+        let labels = |labels: &[&str]| OutboundAction::Labels {
+            issue_number: 2,
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+        };
+
+        enqueue_failed(&queue_file, new_operation(None, repo, create("First")), "offline".to_string()).await.unwrap();
+        enqueue_failed(&queue_file, new_operation(None, repo, create("Second")), "offline".to_string()).await.unwrap();
+        enqueue_failed(&queue_file, new_operation(None, repo, labels(&["bug"])), "offline".to_string()).await.unwrap();
+        enqueue_failed(&queue_file, new_operation(None, repo, labels(&["bug", "ui"])), "offline".to_string()).await.unwrap();
+
+        // A second create for the same local item can't go out while the first is queued
+        assert!(is_queued(&queue_file, repo, &create("Third")).await.unwrap());
+        let operations = read_queue(&queue_file).unwrap().operations;
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[0].action, create("Second"));
+        assert_eq!(operations[0].attempts, 2);
+        assert_eq!(operations[1].action, labels(&["bug", "ui"]));
+
+        // Sync pushed the labels itself
+        remove_queued(&queue_file, repo, &labels(&[])).await.unwrap();
+        assert_eq!(read_queue(&queue_file).unwrap().operations.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_queued_comment_is_linked_once_posted() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues/8/comments"))
+            .and(body_partial_json(json!({ "body": "Still broken" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": 800,
+                "body": "Still broken",
+                "user": { "login": "octocat" },
+                "created_at": "2024-01-01T00:00:00Z",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let project = temp_dir();
+        let mut feedback_file: FeedbackFile = serde_json::from_value(json!({ "feedback": [{
+            "id": "feedback-8",
+            "text": "Broken",
+            "priority": 3,
+            "status": "pending",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "githubIssueNumber": 8,
+        }] }))
+        .unwrap();
+        feedback_file.feedback[0].comments = Some(vec![Comment {
+            id: "c8".to_string(),
+            author: "me".to_string(),
+            body: "Still broken".to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            github_comment_id: None,
+        }]);
+        write_feedback_file(&project, FEEDBACK_FILE, &feedback_file).unwrap();
+
+        let action = OutboundAction::Comment { issue_number: 8, body: "Still broken".to_string(), local_id: Some("c8".to_string()) };
+        let operation = new_operation(Some(&project), "https://github.com/owner/repo", action);
+        let tracker = GithubTracker::new(&server.uri(), "token", "owner", "repo").unwrap();
+        execute(&tracker, &operation).await.unwrap();
+
+        let comment = &read_feedback_file(&project, FEEDBACK_FILE).unwrap().feedback[0].comments.clone().unwrap()[0];
+        assert_eq!(comment.github_comment_id, Some(800));
+        assert_eq!(comment.author, "octocat");
+    }
+}
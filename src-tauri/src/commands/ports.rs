@@ -0,0 +1,295 @@
+// Per-project dev server ports
+// Each project gets a port of its own from PORT_RANGE, picked from a hash of its path so it
+// stays the same across runs, and remembered in ports.json in the app data directory.
+// Dev servers are told to use it (see dev_servers.rs) and are only started once it's free;
+// when it isn't, the error names the process holding it.
+
+use lazy_static::lazy_static;
+use std::fs;
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpListener};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use crate::commands::dev_servers;
+use crate::models::{PortOwner, PortRegistry, PortStatus};
+use crate::utils::path_hash;
+
+const REGISTRY_FILE: &str = "ports.json";
+// Dev tools that take `--port <n>`; others are left to read PORT from the environment
+const PORT_FLAG_TOOLS: &[&str] = &["vite", "next", "astro", "nuxt", "nuxi", "webpack-dev-server"];
+// Where dev servers listen: IPv4 and IPv6 loopback (Vite on newer Node binds only ::1) and all
+// interfaces
+const LISTEN_ADDRESSES: &[IpAddr] = &[
+    IpAddr::V4(Ipv4Addr::LOCALHOST),
+    IpAddr::V6(Ipv6Addr::LOCALHOST),
+    IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+];
+// Clear of the usual defaults (3000, 5173, 8080, ...) so assigned ports don't meet them
+const PORT_RANGE: RangeInclusive<u16> = 5200..=5999;
+
+lazy_static! {
+    // Serialises registry updates between concurrent dev server starts
+    static ref REGISTRY_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn registry_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get data directory: {}", e))
+        .map(|path| path.join(REGISTRY_FILE))
+}
+
+fn read_registry(path: &Path) -> Result<PortRegistry, String> {
+    if !path.exists() {
+        return Ok(PortRegistry::default());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read port registry: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse port registry: {}", e))
+}
+
+fn write_registry(path: &Path, registry: &PortRegistry) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string_pretty(registry)
+        .map_err(|e| format!("Failed to serialize port registry: {}", e))?;
+    fs::write(path, content)
+        .map_err(|e| format!("Failed to write port registry: {}", e))
+}
+
+/// Where in PORT_RANGE a project's search for a port starts (a hash of its path)
+fn preferred_port(project_path: &str) -> u16 {
+    let hash = path_hash(project_path);
+    let span = (PORT_RANGE.end() - PORT_RANGE.start()) as u64 + 1;
+    PORT_RANGE.start() + (hash % span) as u16
+}
+
+/// The project's port, assigning the first one from its preferred port on that no other
+/// project has and `in_use` doesn't rule out
+fn assign_port(registry: &mut PortRegistry, project_path: &str, in_use: impl Fn(u16) -> bool) -> Result<u16, String> {
+    if let Some(port) = registry.ports.get(project_path) {
+        return Ok(*port);
+    }
+
+    let start = preferred_port(project_path);
+    let port = (start..=*PORT_RANGE.end())
+        .chain(*PORT_RANGE.start()..start)
+        .find(|port| !registry.ports.values().any(|taken| taken == port) && !in_use(*port))
+        .ok_or_else(|| "No free port left for dev servers".to_string())?;
+    registry.ports.insert(project_path.to_string(), port);
+    Ok(port)
+}
+
+/// The port the project's dev server should use
+pub(crate) fn project_port(app: &AppHandle, project_path: &str) -> Result<u16, String> {
+    let _guard = REGISTRY_LOCK.lock().unwrap();
+    let path = registry_path(app)?;
+    let mut registry = read_registry(&path)?;
+    let known = registry.ports.contains_key(project_path);
+    let port = assign_port(&mut registry, project_path, port_in_use)?;
+    if !known {
+        write_registry(&path, &registry)?;
+        log::info!(project = project_path; "Assigned dev server port {}", port);
+    }
+    Ok(port)
+}
+
+/// Whether something is listening on the port locally; addresses this machine doesn't have
+/// (no IPv6) don't count
+pub(crate) fn port_in_use(port: u16) -> bool {
+    LISTEN_ADDRESSES.iter().any(|address| {
+        matches!(TcpListener::bind((*address, port)), Err(e) if e.kind() == ErrorKind::AddrInUse)
+    })
+}
+
+/// The process listening on a port, as far as lsof or netstat can tell
+fn find_listener(port: u16) -> Option<(u32, String)> {
+    #[cfg(not(target_os = "windows"))]
+    {
+        // -F output: "p<pid>" then "c<command>" lines
+        let output = Command::new("lsof")
+            .args(["-nP", &format!("-iTCP:{}", port), "-sTCP:LISTEN", "-Fpc"])
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        let pid = text.lines().find_map(|line| line.strip_prefix('p')?.parse().ok())?;
+        let name = text.lines().find_map(|line| line.strip_prefix('c')).unwrap_or("unknown");
+        Some((pid, name.to_string()))
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let output = Command::new("netstat")
+            .args(["-ano", "-p", "TCP"])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output()
+            .ok()?;
+        let suffix = format!(":{}", port);
+        let pid: u32 = String::from_utf8_lossy(&output.stdout).lines().find_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            (parts.len() == 5 && parts[1].ends_with(&suffix) && parts[3] == "LISTENING")
+                .then(|| parts[4].parse().ok())
+                .flatten()
+        })?;
+
+        // "name.exe","1234",...
+        let output = Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        let name = text.split(',').next().map(|name| name.trim_matches('"').to_string());
+        Some((pid, name.filter(|n| !n.is_empty()).unwrap_or_else(|| "unknown".to_string())))
+    }
+}
+
+pub(crate) fn port_status(port: u16) -> PortStatus {
+    if !port_in_use(port) {
+        return PortStatus { port, available: true, owner: None };
+    }
+
+    let owner = find_listener(port).map(|(pid, name)| PortOwner {
+        pid,
+        name,
+        project_path: dev_servers::project_on_port(port),
+    });
+    PortStatus { port, available: false, owner }
+}
+
+/// Fail with who holds the port if it isn't free
+pub(crate) fn ensure_port_free(port: u16) -> Result<(), String> {
+    let status = port_status(port);
+    if status.available {
+        return Ok(());
+    }
+
+    let holder = match status.owner {
+        Some(PortOwner { pid, name, project_path: Some(project) }) => {
+            format!("{} (PID {}), the dev server of {}", name, pid, project)
+        }
+        Some(PortOwner { pid, name, project_path: None }) => format!("{} (PID {})", name, pid),
+        None => "another process".to_string(),
+    };
+    Err(format!("Port {} is already in use by {}", port, holder))
+}
+
+/// Whether a dev command runs a tool that takes `--port`
+pub(crate) fn accepts_port_flag(command: &str) -> bool {
+    let words: Vec<&str> = command
+        .split(|c: char| c.is_whitespace() || matches!(c, '&' | ';' | '|'))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.rsplit(['/', '\\']).next().unwrap_or(word))
+        .collect();
+    words.iter().any(|word| PORT_FLAG_TOOLS.contains(word))
+        || words.windows(2).any(|pair| pair == ["ng", "serve"] || pair == ["webpack", "serve"])
+}
+
+/// A port the command already sets itself with `--port <n>`, `--port=<n>` or `-p <n>`
+pub(crate) fn explicit_port(command: &str) -> Option<u16> {
+    let words: Vec<&str> = command.split_whitespace().collect();
+    words.iter().enumerate().find_map(|(i, word)| match *word {
+        "--port" | "-p" => words.get(i + 1)?.parse().ok(),
+        _ => word.strip_prefix("--port=")?.parse().ok(),
+    })
+}
+
+#[tauri::command]
+pub async fn get_project_port(app: AppHandle, project_path: String) -> Result<u16, String> {
+    project_port(&app, &project_path)
+}
+
+/// Give the project a port of its own choosing, or a freshly assigned one with None
+#[tauri::command]
+pub async fn set_project_port(app: AppHandle, project_path: String, port: Option<u16>) -> Result<u16, String> {
+    let _guard = REGISTRY_LOCK.lock().unwrap();
+    let path = registry_path(&app)?;
+    let mut registry = read_registry(&path)?;
+    registry.ports.remove(&project_path);
+
+    let port = match port {
+        Some(port) if port < 1024 => return Err(format!("Port {} is reserved; choose one from 1024 up", port)),
+        Some(port) => {
+            if let Some((other, _)) = registry.ports.iter().find(|(_, taken)| **taken == port) {
+                return Err(format!("Port {} is already assigned to {}", port, other));
+            }
+            registry.ports.insert(project_path.clone(), port);
+            port
+        }
+        None => assign_port(&mut registry, &project_path, port_in_use)?,
+    };
+    write_registry(&path, &registry)?;
+    Ok(port)
+}
+
+/// Whether a port is free, and who holds it if not
+#[tauri::command]
+pub async fn get_port_status(port: u16) -> Result<PortStatus, String> {
+    Ok(port_status(port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ports_are_stable_and_distinct_per_project() {
+        let mut registry = PortRegistry::default();
+        let a = assign_port(&mut registry, "/projects/a", |_| false).unwrap();
+        assert_eq!(a, preferred_port("/projects/a"));
+        assert!(PORT_RANGE.contains(&a));
+
+        // Taken by another project or in use: the next free one
+        let mut other = PortRegistry::default();
+        other.ports.insert("/projects/b".to_string(), a);
+        let moved = assign_port(&mut other, "/projects/a", |port| port == a + 1).unwrap();
+        assert_eq!(moved, if a + 2 > *PORT_RANGE.end() { *PORT_RANGE.start() } else { a + 2 });
+
+        // Once assigned it no longer moves
+        assert_eq!(assign_port(&mut registry, "/projects/a", |_| true).unwrap(), a);
+    }
+
+    #[test]
+    fn test_port_flags_in_dev_commands() {
+        assert!(accepts_port_flag("vite"));
+        assert!(accepts_port_flag("cd web && ./node_modules/.bin/vite --host"));
+        assert!(accepts_port_flag("ng serve"));
+        assert!(!accepts_port_flag("node server.js"));
+        assert!(!accepts_port_flag("tauri dev"));
+
+        assert_eq!(explicit_port("vite --port 3001"), Some(3001));
+        assert_eq!(explicit_port("next dev -p 4000"), Some(4000));
+        assert_eq!(explicit_port("astro dev --port=4321 --host"), Some(4321));
+        assert_eq!(explicit_port("vite --host"), None);
+    }
+
+    #[test]
+    fn test_busy_port_is_reported() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(ensure_port_free(port).unwrap_err().starts_with(&format!("Port {} is already in use by", port)));
+
+        drop(listener);
+        assert!(ensure_port_free(port).is_ok());
+
+        // A server on IPv6 loopback only, where the machine has one
+        if let Ok(listener) = TcpListener::bind(("::1", 0)) {
+            let port = listener.local_addr().unwrap().port();
+            assert!(port_in_use(port));
+            drop(listener);
+            assert!(!port_in_use(port));
+        }
+    }
+}
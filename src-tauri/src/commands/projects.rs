@@ -1,4 +1,8 @@
-use crate::models::{Project, FeedbackFile, IssueFile};
+use crate::commands::github_repo::{self, GithubRepoOptions};
+use crate::commands::repo_status::read_repo_status;
+use crate::commands::settings::read_settings_file;
+use crate::commands::tracker;
+use crate::models::{Project, FeedbackFile, IssueFile, Settings};
 use std::fs;
 use std::path::Path;
 use uuid::Uuid;
@@ -7,7 +11,7 @@ use uuid::Uuid;
 use std::os::windows::process::CommandExt;
 
 // Helper to create git command with no window flash on Windows
-fn git_command() -> std::process::Command {
+pub(crate) fn git_command() -> std::process::Command {
     let mut cmd = std::process::Command::new("git");
     #[cfg(target_os = "windows")]
     cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
@@ -492,18 +496,15 @@ fn get_git_remote_url(project_path: &Path) -> Option<String> {
     }
 
     // Convert SSH URLs to HTTPS for consistency
-    let https_url = if url.starts_with("git@github.com:") {
-        url.replace("git@github.com:", "https://github.com/")
-            .trim_end_matches(".git")
-            .to_string()
-    } else {
-        url.trim_end_matches(".git").to_string()
-    };
+    Some(tracker::normalize_remote_url(&url))
+}
 
-    Some(https_url)
+/// Whether a remote is on a tracker we can sync issues with, resolved the way sync does
+fn has_known_tracker(settings: &Settings, project_path: &Path, url: &str) -> bool {
+    tracker::resolve_tracker_kind(settings, Some(project_path), url).is_ok()
 }
 
-fn ensure_metadata_file(project_path: &Path) -> Result<(), String> {
+fn ensure_metadata_file(project_path: &Path, settings: &Settings) -> Result<(), String> {
     let vibe_dir = project_path.join(VIBE_DIR);
     let metadata_path = vibe_dir.join(METADATA_FILE);
 
@@ -520,10 +521,10 @@ fn ensure_metadata_file(project_path: &Path) -> Result<(), String> {
 
         let has_sync_field = content.lines().any(|line| line.trim().starts_with("GitHubSync:"));
 
-        // If GitHubSync field is missing and there's a GitHub remote, add it
+        // If GitHubSync field is missing and there's a known tracker remote, add it
         if !has_sync_field {
             if let Some(github_url) = get_git_remote_url(project_path) {
-                if github_url.contains("github.com") {
+                if has_known_tracker(settings, project_path, &github_url) {
                     let mut updated_content = String::new();
                     let mut added_field = false;
 
@@ -551,9 +552,9 @@ fn ensure_metadata_file(project_path: &Path) -> Result<(), String> {
     let color = assign_project_color(&folder_name);
     let text_color = calculate_text_color(&color);
 
-    // Add GitHubSync field if there's a GitHub remote
+    // Add GitHubSync field if there's a known tracker remote
     let github_sync_field = if let Some(github_url) = get_git_remote_url(project_path) {
-        if github_url.contains("github.com") {
+        if has_known_tracker(settings, project_path, &github_url) {
             "GitHubSync: false\n"
         } else {
             ""
@@ -627,7 +628,7 @@ fn get_last_modified(project_path: &Path) -> Option<String> {
 }
 
 #[tauri::command]
-pub async fn scan_projects(projects_dir: String) -> Result<Vec<Project>, String> {
+pub async fn scan_projects(app: tauri::AppHandle, projects_dir: String) -> Result<Vec<Project>, String> {
     let projects_path = Path::new(&projects_dir);
 
     if !projects_path.exists() {
@@ -637,6 +638,7 @@ pub async fn scan_projects(projects_dir: String) -> Result<Vec<Project>, String>
     let entries = fs::read_dir(projects_path)
         .map_err(|e| format!("Failed to read projects directory: {}", e))?;
 
+    let settings = read_settings_file(&app).unwrap_or_default();
     let mut projects = Vec::new();
 
     for entry in entries {
@@ -649,7 +651,7 @@ pub async fn scan_projects(projects_dir: String) -> Result<Vec<Project>, String>
             let _ = migrate_to_vibe_folder(&path);
 
             // Auto-create metadata file if it doesn't exist
-            let _ = ensure_metadata_file(&path);
+            let _ = ensure_metadata_file(&path, &settings);
 
             // Auto-migrate completed issues to archive for better performance
             let _ = migrate_completed_issues_internal(&path);
@@ -685,6 +687,8 @@ pub async fn scan_projects(projects_dir: String) -> Result<Vec<Project>, String>
                 .chain(pending_issues.iter().map(|i| i.priority))
                 .min(); // Lower number = higher priority (1 is highest)
 
+            let repo_status = read_repo_status(&path);
+
             let project = Project {
                 id: Uuid::new_v4().to_string(),
                 name: folder_name,
@@ -709,6 +713,9 @@ pub async fn scan_projects(projects_dir: String) -> Result<Vec<Project>, String>
                 has_git_repo: has_git,
                 dev_command,
                 build_command,
+                open_pull_requests: repo_status.as_ref().map(|s| s.pull_requests.len()),
+                ci_status: repo_status.as_ref().and_then(|s| s.ci.as_ref()).map(|ci| ci.summary()),
+                repo_status_fetched_at: repo_status.map(|s| s.fetched_at),
             };
 
             projects.push(project);
@@ -770,6 +777,8 @@ pub async fn get_project_detail(project_path: String) -> Result<Project, String>
         .chain(pending_issues.iter().map(|i| i.priority))
         .min(); // Lower number = higher priority (1 is highest)
 
+    let repo_status = read_repo_status(path);
+
     Ok(Project {
         id: Uuid::new_v4().to_string(),
         name: folder_name,
@@ -794,6 +803,9 @@ pub async fn get_project_detail(project_path: String) -> Result<Project, String>
         has_git_repo: has_git,
         dev_command,
         build_command,
+        open_pull_requests: repo_status.as_ref().map(|s| s.pull_requests.len()),
+        ci_status: repo_status.as_ref().and_then(|s| s.ci.as_ref()).map(|ci| ci.summary()),
+        repo_status_fetched_at: repo_status.map(|s| s.fetched_at),
     })
 }
 
@@ -877,6 +889,7 @@ pub async fn update_all_metadata(
     let mut existing_text_color: Option<String> = None;
     let mut existing_icon_path: Option<String> = None;
     let mut existing_github_sync: Option<String> = None;
+    let mut existing_tracker: Option<String> = None;
 
     if let Some(contents) = &existing_contents {
         for line in contents.lines() {
@@ -889,6 +902,8 @@ pub async fn update_all_metadata(
                 existing_icon_path = Some(trimmed.trim_start_matches("IconPath:").trim().to_string());
             } else if trimmed.starts_with("GitHubSync:") {
                 existing_github_sync = Some(trimmed.to_string());
+            } else if trimmed.starts_with("Tracker:") {
+                existing_tracker = Some(trimmed.to_string());
             }
         }
     }
@@ -925,6 +940,9 @@ pub async fn update_all_metadata(
     if let Some(github_sync_line) = existing_github_sync {
         content.push_str(&format!("{}\n", github_sync_line));
     }
+    if let Some(tracker_line) = existing_tracker {
+        content.push_str(&format!("{}\n", tracker_line));
+    }
 
     content.push('\n');
 
@@ -1025,7 +1043,13 @@ pub async fn check_metadata_exists(project_path: String) -> Result<bool, String>
 }
 
 #[tauri::command]
-pub async fn create_new_project(projects_dir: String, project_name: String, summary: Option<String>) -> Result<String, String> {
+pub async fn create_new_project(
+    app: tauri::AppHandle,
+    projects_dir: String,
+    project_name: String,
+    summary: Option<String>,
+    github_repo: Option<GithubRepoOptions>,
+) -> Result<String, String> {
 
 
     // Convert project name to folder name (lowercase with dashes)
@@ -1196,6 +1220,13 @@ TextColor: {}
     // Add .vibe to .gitignore
     let _ = ensure_vibe_in_gitignore(&project_path);
 
+    // Optionally create the GitHub repository and push the initial commit
+    if let Some(options) = github_repo {
+        github_repo::publish_project_from_settings(&app, &project_path, summary.as_deref(), &options)
+            .await
+            .map_err(|e| format!("Project '{}' was created, but publishing it to GitHub failed: {}", folder_name, e))?;
+    }
+
     Ok(project_path.to_string_lossy().to_string())
 }
 
@@ -1642,17 +1673,7 @@ pub async fn get_github_url(project_path: String) -> Result<Option<String>, Stri
     }
 
     // Convert SSH URLs to HTTPS for browser
-    let https_url = if url.starts_with("git@github.com:") {
-        url.replace("git@github.com:", "https://github.com/")
-            .trim_end_matches(".git")
-            .to_string()
-    } else if url.starts_with("https://github.com/") {
-        url.trim_end_matches(".git").to_string()
-    } else {
-        url
-    };
-
-    Ok(Some(https_url))
+    Ok(Some(tracker::normalize_remote_url(&url)))
 }
 
 #[tauri::command]
@@ -1704,7 +1725,8 @@ pub async fn toggle_github_sync(
     // If enabled, trigger immediate sync for this project
     if enabled {
         if let Some(github_url) = get_git_remote_url(path) {
-            if github_url.contains("github.com") {
+            let settings = crate::commands::github::read_settings(&app);
+            if settings.is_ok_and(|settings| tracker::resolve_tracker(&settings, Some(path), &github_url).is_ok()) {
                 use crate::commands::github::fetch_github_issues;
                 let _ = fetch_github_issues(app, project_path, github_url).await;
             }
@@ -9,13 +9,18 @@ pub async fn get_prompt(
     prompt_name: String,
     replacements: HashMap<String, String>
 ) -> Result<String, String> {
+    render_prompt(&prompt_name, replacements)
+}
+
+/// Look up a prompt in prompts.json and fill in its {PLACEHOLDERS}
+pub(crate) fn render_prompt(prompt_name: &str, replacements: HashMap<String, String>) -> Result<String, String> {
     // Parse the embedded JSON
     let prompts: HashMap<String, Value> = serde_json::from_str(PROMPTS_JSON)
         .map_err(|e| format!("Failed to parse embedded prompts JSON: {}", e))?;
 
     // Get the prompt by key
     let mut prompt = prompts
-        .get(&prompt_name)
+        .get(prompt_name)
         .and_then(|v| v.as_str())
         .ok_or_else(|| format!("Prompt '{}' not found in prompts.json", prompt_name))?
         .to_string();
@@ -0,0 +1,328 @@
+// Embedded terminals for agent sessions
+// Commands run in a pseudo-terminal owned by Vibe Hub instead of an external window.
+// A reader thread feeds the output through a vt100 parser and emits the rendered screen as
+// "pty-output" events; "pty-exit" follows when the command exits. Sessions are keyed by id,
+// so a project can have several running at once.
+
+use chrono::Utc;
+use portable_pty::{native_pty_system, Child, ChildKiller, CommandBuilder, MasterPty, PtySize};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+use crate::commands::agents;
+use crate::commands::{sessions, transcripts};
+use crate::models::{PtyExit, PtyScreen, PtySessionInfo, SessionContext, SessionRecord};
+
+/// Lines kept above the visible screen by the parser
+const SCROLLBACK_LINES: usize = 1000;
+
+struct PtySession {
+    info: PtySessionInfo,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    parser: Arc<Mutex<vt100::Parser>>,
+}
+
+// PTY sessions by id, kept after exit until the frontend closes them
+lazy_static::lazy_static! {
+    static ref PTY_SESSIONS: Mutex<HashMap<String, PtySession>> = Mutex::new(HashMap::new());
+}
+
+struct OpenedPty {
+    master: Box<dyn MasterPty + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    reader: Box<dyn Read + Send>,
+    writer: Box<dyn Write + Send>,
+}
+
+fn open_pty(program: &str, args: &[String], env: &[(String, String)], cwd: &str, cols: u16, rows: u16) -> Result<OpenedPty, String> {
+    let pair = native_pty_system()
+        .openpty(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 })
+        .map_err(|e| format!("Failed to open a pseudo-terminal: {}", e))?;
+
+    let mut command = CommandBuilder::new(program);
+    command.args(args);
+    command.cwd(cwd);
+    command.env("TERM", "xterm-256color");
+    for (key, value) in env {
+        command.env(key, value);
+    }
+
+    let child = pair
+        .slave
+        .spawn_command(command)
+        .map_err(|e| format!("Failed to start {}: {}", program, e))?;
+    // Our copy of the slave end has to go, otherwise the reader never sees EOF
+    drop(pair.slave);
+
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to read from the pseudo-terminal: {}", e))?;
+    let writer = pair
+        .master
+        .take_writer()
+        .map_err(|e| format!("Failed to write to the pseudo-terminal: {}", e))?;
+
+    Ok(OpenedPty { master: pair.master, child, reader, writer })
+}
+
+/// Feed output into the parser until the command closes the terminal
+fn pump_output(mut reader: impl Read, parser: &Mutex<vt100::Parser>, mut on_update: impl FnMut(&vt100::Parser)) {
+    let mut buf = [0u8; 8192];
+    loop {
+        match reader.read(&mut buf) {
+            // Linux reports EIO rather than EOF once the child side is closed
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                let mut parser = parser.lock().unwrap();
+                parser.process(&buf[..n]);
+                on_update(&parser);
+            }
+        }
+    }
+}
+
+fn screen_snapshot(id: &str, parser: &vt100::Parser) -> PtyScreen {
+    let screen = parser.screen();
+    let (_, cols) = screen.size();
+    let (cursor_row, cursor_col) = screen.cursor_position();
+
+    PtyScreen {
+        id: id.to_string(),
+        lines: screen.rows(0, cols).collect(),
+        cursor_row,
+        cursor_col,
+        hide_cursor: screen.hide_cursor(),
+    }
+}
+
+/// Wait for the command to exit, then record it and tell the frontend.
+/// Agent sessions also have a transcript to complete.
+fn finish_session(app: &AppHandle, mut child: Box<dyn Child + Send + Sync>, info: PtySessionInfo, started: Instant, agent: bool) {
+    let exit_code = child.wait().ok().map(|status| status.exit_code());
+
+    if let Some(session) = PTY_SESSIONS.lock().unwrap().get_mut(&info.id) {
+        session.info.status = "exited".to_string();
+        session.info.exit_code = exit_code;
+    }
+
+    let record = SessionRecord {
+        id: info.id.clone(),
+        project_path: info.project_path.clone(),
+        pid: info.pid,
+        started_at: info.started_at.clone(),
+        ended_at: Utc::now().to_rfc3339(),
+        duration_secs: started.elapsed().as_secs(),
+        exit_code: exit_code.map(|code| code as i32),
+        outcome: "exited".to_string(),
+    };
+    log::info!(project = info.project_path.as_str(); "PTY session {} exited with code {:?}", info.id, exit_code);
+    if let Err(e) = sessions::append_history(Path::new(&info.project_path), record) {
+        log::error!(project = info.project_path.as_str(); "Failed to record session history: {}", e);
+    }
+    if agent {
+        if let Err(e) = transcripts::finish_transcript(&info.project_path, &info.id, "exited", exit_code.map(|code| code as i32)) {
+            log::warn!(project = info.project_path.as_str(); "Failed to complete session transcript: {}", e);
+        }
+    }
+
+    let _ = app.emit("pty-exit", &PtyExit {
+        id: info.id,
+        project_path: info.project_path,
+        exit_code,
+    });
+}
+
+fn with_session<T>(id: &str, f: impl FnOnce(&mut PtySession) -> Result<T, String>) -> Result<T, String> {
+    let mut sessions = PTY_SESSIONS.lock().unwrap();
+    let session = sessions
+        .get_mut(id)
+        .ok_or_else(|| format!("Terminal session {} not found", id))?;
+    f(session)
+}
+
+/// Start a command (the project's agent by default) in an embedded terminal
+#[tauri::command]
+pub async fn spawn_pty(
+    app: AppHandle,
+    project_path: String,
+    command: Option<String>,
+    args: Option<Vec<String>>,
+    cols: u16,
+    rows: u16,
+) -> Result<PtySessionInfo, String> {
+    if !Path::new(&project_path).is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+
+    let command = command.filter(|c| !c.trim().is_empty());
+    let agent = command.is_none();
+    let (command, args, env) = match command {
+        Some(command) => (command, args.unwrap_or_default(), Vec::new()),
+        None => {
+            // The project's agent without a prompt, so no prompt file is written
+            let agent = agents::project_agent_command(&app, &project_path, "", false, Path::new(""))?.spawnable();
+            (agent.program, agent.args, agent.env)
+        }
+    };
+    let (cols, rows) = (cols.max(1), rows.max(1));
+
+    // Recorded before the agent starts so HEAD is taken before it can commit
+    let id = Uuid::new_v4().to_string();
+    if agent {
+        if let Err(e) = transcripts::begin_transcript(&id, &project_path, &project_path, "embedded", "", SessionContext::default()) {
+            log::warn!(project = project_path.as_str(); "Failed to record session transcript: {}", e);
+        }
+    }
+
+    let opened = open_pty(&command, &args, &env, &project_path, cols, rows).inspect_err(|_| {
+        if agent {
+            let _ = transcripts::finish_transcript(&project_path, &id, "failed_to_start", None);
+        }
+    })?;
+    let info = PtySessionInfo {
+        id,
+        project_path,
+        command,
+        args,
+        pid: opened.child.process_id(),
+        cols,
+        rows,
+        status: "running".to_string(),
+        exit_code: None,
+        started_at: Utc::now().to_rfc3339(),
+    };
+    log::info!(
+        project = info.project_path.as_str();
+        "Started PTY session {}: {} {}", info.id, info.command, info.args.join(" ")
+    );
+
+    let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, SCROLLBACK_LINES)));
+    PTY_SESSIONS.lock().unwrap().insert(info.id.clone(), PtySession {
+        info: info.clone(),
+        master: opened.master,
+        writer: opened.writer,
+        killer: opened.child.clone_killer(),
+        parser: parser.clone(),
+    });
+
+    let thread_app = app.clone();
+    let thread_info = info.clone();
+    let (reader, child) = (opened.reader, opened.child);
+    std::thread::spawn(move || {
+        let started = Instant::now();
+        pump_output(reader, &parser, |parser| {
+            let _ = thread_app.emit("pty-output", &screen_snapshot(&thread_info.id, parser));
+        });
+        finish_session(&thread_app, child, thread_info, started, agent);
+    });
+
+    Ok(info)
+}
+
+/// Send keyboard input to an embedded terminal
+#[tauri::command]
+pub async fn write_pty(id: String, data: String) -> Result<(), String> {
+    with_session(&id, |session| {
+        session
+            .writer
+            .write_all(data.as_bytes())
+            .and_then(|_| session.writer.flush())
+            .map_err(|e| format!("Failed to write to terminal: {}", e))
+    })
+}
+
+#[tauri::command]
+pub async fn resize_pty(id: String, cols: u16, rows: u16) -> Result<(), String> {
+    let (cols, rows) = (cols.max(1), rows.max(1));
+    with_session(&id, |session| {
+        session
+            .master
+            .resize(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 })
+            .map_err(|e| format!("Failed to resize terminal: {}", e))?;
+        session.parser.lock().unwrap().screen_mut().set_size(rows, cols);
+        session.info.cols = cols;
+        session.info.rows = rows;
+        Ok(())
+    })
+}
+
+/// Kill the command running in an embedded terminal; "pty-exit" follows
+#[tauri::command]
+pub async fn kill_pty(id: String) -> Result<(), String> {
+    with_session(&id, |session| {
+        if session.info.status != "running" {
+            return Ok(());
+        }
+        session
+            .killer
+            .kill()
+            .map_err(|e| format!("Failed to stop terminal session: {}", e))
+    })
+}
+
+/// Forget a terminal session, killing its command if it is still running
+#[tauri::command]
+pub async fn close_pty(id: String) -> Result<(), String> {
+    let session = PTY_SESSIONS.lock().unwrap().remove(&id);
+    if let Some(mut session) = session {
+        if session.info.status == "running" {
+            let _ = session.killer.kill();
+        }
+    }
+    Ok(())
+}
+
+/// Embedded terminal sessions for a project, oldest first
+#[tauri::command]
+pub async fn list_pty_sessions(project_path: String) -> Result<Vec<PtySessionInfo>, String> {
+    let mut sessions: Vec<PtySessionInfo> = PTY_SESSIONS
+        .lock()
+        .unwrap()
+        .values()
+        .filter(|s| s.info.project_path == project_path)
+        .map(|s| s.info.clone())
+        .collect();
+    sessions.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    Ok(sessions)
+}
+
+/// Current screen of a terminal session, for rendering it before the next output arrives
+#[tauri::command]
+pub async fn get_pty_screen(id: String) -> Result<PtyScreen, String> {
+    with_session(&id, |session| {
+        Ok(screen_snapshot(&session.info.id, &session.parser.lock().unwrap()))
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pty_output_is_rendered_and_exit_code_reported() {
+        let cwd = std::env::temp_dir().to_string_lossy().to_string();
+        let args = vec!["-c".to_string(), "printf 'hello\\r\\nfrom pty'; exit 3".to_string()];
+        let opened = open_pty("sh", &args, &[], &cwd, 40, 5).unwrap();
+        let OpenedPty { master: _master, mut child, reader, .. } = opened;
+
+        let parser = Mutex::new(vt100::Parser::new(5, 40, 0));
+        let mut updates = 0;
+        pump_output(reader, &parser, |_| updates += 1);
+        assert!(updates > 0);
+
+        let screen = screen_snapshot("test", &parser.lock().unwrap());
+        assert_eq!(screen.lines.len(), 5);
+        assert_eq!(screen.lines[0], "hello");
+        assert_eq!(screen.lines[1], "from pty");
+        assert_eq!((screen.cursor_row, screen.cursor_col), (1, 8));
+        assert_eq!(child.wait().unwrap().exit_code(), 3);
+    }
+}
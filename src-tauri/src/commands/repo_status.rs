@@ -0,0 +1,331 @@
+// Pull request and CI overview for a project's GitHub repository
+// Results are cached in .vibe/github-status.json so project lists can show them without network calls.
+
+use chrono::Utc;
+use octocrab::{Octocrab, Page};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::commands::github;
+use crate::commands::tracker::{self, TrackerConfig};
+use crate::models::{CiStatus, PullRequestSummary, RepoStatus, TrackerKind};
+
+const STATUS_FILE: &str = "github-status.json";
+
+#[derive(Debug, Deserialize)]
+struct RemoteRepository {
+    default_branch: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemotePullRequest {
+    number: u64,
+    title: String,
+    html_url: String,
+    user: Option<github::RemoteUser>,
+    #[serde(default)]
+    draft: bool,
+    mergeable: Option<bool>,
+    updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteReview {
+    user: Option<github::RemoteUser>,
+    state: String, // "APPROVED" | "CHANGES_REQUESTED" | "COMMENTED" | "DISMISSED" | "PENDING"
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRuns {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRun {
+    name: Option<String>,
+    status: Option<String>,
+    conclusion: Option<String>,
+    html_url: String,
+    updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckSuites {
+    check_suites: Vec<CheckSuite>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckSuite {
+    status: Option<String>,
+    conclusion: Option<String>,
+    updated_at: Option<String>,
+}
+
+/// Read the cached status for a project, if it has been fetched before
+pub(crate) fn read_repo_status(project_path: &Path) -> Option<RepoStatus> {
+    let content = fs::read_to_string(project_path.join(".vibe").join(STATUS_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_repo_status(project_path: &Path, status: &RepoStatus) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(status)
+        .map_err(|e| format!("Failed to serialize GitHub status: {}", e))?;
+
+    fs::write(project_path.join(".vibe").join(STATUS_FILE), content)
+        .map_err(|e| format!("Failed to write GitHub status: {}", e))
+}
+
+/// Overall review state from a pull request's reviews
+///
+/// Only each reviewer's latest approving or blocking review counts; comments alone
+/// never outrank them.
+fn review_state(reviews: &[RemoteReview]) -> String {
+    let mut latest: HashMap<&str, &str> = HashMap::new();
+    let mut commented = false;
+
+    for review in reviews {
+        let reviewer = review.user.as_ref().map(|u| u.login.as_str()).unwrap_or("");
+        match review.state.as_str() {
+            "APPROVED" | "CHANGES_REQUESTED" | "DISMISSED" => {
+                latest.insert(reviewer, review.state.as_str());
+            }
+            "COMMENTED" => commented = true,
+            _ => {}
+        }
+    }
+
+    if latest.values().any(|s| *s == "CHANGES_REQUESTED") {
+        "changes_requested"
+    } else if latest.values().any(|s| *s == "APPROVED") {
+        "approved"
+    } else if commented {
+        "commented"
+    } else {
+        "pending"
+    }
+    .to_string()
+}
+
+/// Combine the check suites on a commit into one status
+fn combine_check_suites(branch: &str, suites: &[CheckSuite]) -> Option<CiStatus> {
+    if suites.is_empty() {
+        return None;
+    }
+
+    let pending = suites.iter().any(|s| s.status.as_deref() != Some("completed"));
+    let conclusion = if pending {
+        None
+    } else {
+        let failed = suites.iter().find(|s| {
+            !matches!(s.conclusion.as_deref(), Some("success") | Some("neutral") | Some("skipped"))
+        });
+        Some(failed.and_then(|s| s.conclusion.clone()).unwrap_or_else(|| "success".to_string()))
+    };
+
+    Some(CiStatus {
+        branch: branch.to_string(),
+        status: if pending { "in_progress" } else { "completed" }.to_string(),
+        conclusion,
+        name: None,
+        url: None,
+        updated_at: suites.iter().filter_map(|s| s.updated_at.clone()).max(),
+    })
+}
+
+async fn list_pull_requests(client: &Octocrab, owner: &str, repo: &str) -> Result<Vec<PullRequestSummary>, String> {
+    let map_err = |e: octocrab::Error| format!("Failed to fetch pull requests: {}", e);
+
+    let mut page: Page<RemotePullRequest> = client
+        .get(format!("/repos/{}/{}/pulls", owner, repo), Some(&[("state", "open"), ("per_page", "100")]))
+        .await
+        .map_err(map_err)?;
+
+    let mut pulls = Vec::new();
+    loop {
+        pulls.extend(page.take_items());
+        match client.get_page::<RemotePullRequest>(&page.next).await.map_err(map_err)? {
+            Some(next) => page = next,
+            None => break,
+        }
+    }
+
+    let mut summaries = Vec::new();
+    for pull in pulls {
+        // The list endpoint never reports mergeability, so each pull request is fetched on its own
+        let detail: RemotePullRequest = client
+            .get(format!("/repos/{}/{}/pulls/{}", owner, repo, pull.number), None::<&()>)
+            .await
+            .map_err(|e| format!("Failed to fetch pull request #{}: {}", pull.number, e))?;
+        let reviews: Vec<RemoteReview> = client
+            .get(format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pull.number), Some(&[("per_page", "100")]))
+            .await
+            .map_err(|e| format!("Failed to fetch reviews for pull request #{}: {}", pull.number, e))?;
+
+        summaries.push(PullRequestSummary {
+            number: detail.number,
+            title: detail.title,
+            author: detail.user.map(|u| u.login),
+            url: detail.html_url,
+            draft: detail.draft,
+            review_state: review_state(&reviews),
+            mergeable: detail.mergeable,
+            updated_at: detail.updated_at,
+        });
+    }
+
+    Ok(summaries)
+}
+
+/// Latest workflow run on a branch, falling back to the check suites on its head commit
+async fn branch_ci_status(client: &Octocrab, owner: &str, repo: &str, branch: &str) -> Result<Option<CiStatus>, String> {
+    let runs: WorkflowRuns = client
+        .get(
+            format!("/repos/{}/{}/actions/runs", owner, repo),
+            Some(&[("branch", branch), ("per_page", "1")]),
+        )
+        .await
+        .map_err(|e| format!("Failed to fetch workflow runs: {}", e))?;
+
+    if let Some(run) = runs.workflow_runs.into_iter().next() {
+        return Ok(Some(CiStatus {
+            branch: branch.to_string(),
+            status: run.status.unwrap_or_else(|| "queued".to_string()),
+            conclusion: run.conclusion,
+            name: run.name,
+            url: Some(run.html_url),
+            updated_at: Some(run.updated_at),
+        }));
+    }
+
+    let suites: CheckSuites = client
+        .get(format!("/repos/{}/{}/commits/{}/check-suites", owner, repo, branch), None::<&()>)
+        .await
+        .map_err(|e| format!("Failed to fetch check suites: {}", e))?;
+
+    Ok(combine_check_suites(branch, &suites.check_suites))
+}
+
+/// Fetch open pull requests and default-branch CI status for a repository
+pub(crate) async fn fetch_status(client: &Octocrab, owner: &str, repo: &str) -> Result<RepoStatus, String> {
+    let repository: RemoteRepository = client
+        .get(format!("/repos/{}/{}", owner, repo), None::<&()>)
+        .await
+        .map_err(|e| format!("Failed to fetch repository {}/{}: {}", owner, repo, e))?;
+
+    Ok(RepoStatus {
+        pull_requests: list_pull_requests(client, owner, repo).await?,
+        ci: branch_ci_status(client, owner, repo, &repository.default_branch).await?,
+        default_branch: repository.default_branch,
+        fetched_at: Utc::now().to_rfc3339(),
+    })
+}
+
+/// Fetch and cache open pull requests and CI status for a project's GitHub repository
+#[tauri::command]
+pub async fn fetch_repo_status(
+    app: AppHandle,
+    project_path: String,
+    github_url: String,
+) -> Result<RepoStatus, String> {
+    let path = Path::new(&project_path);
+    let settings = github::read_settings(&app)?;
+    if !settings.github_integration_enabled {
+        return Err("GitHub integration is disabled in settings. Please enable it in Settings.".to_string());
+    }
+
+    let TrackerConfig { kind, api_url, token, location } = tracker::resolve_tracker(&settings, Some(path), &github_url)?;
+    if kind != TrackerKind::Github {
+        return Err("Pull request and CI status are only available for GitHub repositories".to_string());
+    }
+
+    let client = github::build_client(&api_url, &token)?;
+    let status = fetch_status(&client, &location.owner, &location.repo).await?;
+    write_repo_status(path, &status)?;
+
+    Ok(status)
+}
+
+/// Get the cached pull request and CI status for a project
+#[tauri::command]
+pub async fn get_repo_status(project_path: String) -> Result<Option<RepoStatus>, String> {
+    Ok(read_repo_status(Path::new(&project_path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn review(user: &str, state: &str) -> RemoteReview {
+        RemoteReview {
+            user: Some(github::RemoteUser { login: user.to_string() }),
+            state: state.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_review_state_uses_latest_review_per_reviewer() {
+        assert_eq!(review_state(&[]), "pending");
+        assert_eq!(review_state(&[review("a", "COMMENTED")]), "commented");
+        assert_eq!(review_state(&[review("a", "CHANGES_REQUESTED"), review("a", "APPROVED")]), "approved");
+        assert_eq!(review_state(&[review("a", "APPROVED"), review("b", "CHANGES_REQUESTED")]), "changes_requested");
+        assert_eq!(review_state(&[review("a", "APPROVED"), review("a", "DISMISSED")]), "pending");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_status_reads_pulls_and_falls_back_to_check_suites() {
+        let server = MockServer::start().await;
+        let pull = json!({
+            "number": 4,
+            "title": "Add dark mode",
+            "html_url": "https://github.com/owner/repo/pull/4",
+            "user": { "login": "octo" },
+            "draft": false,
+            "mergeable": null,
+            "updated_at": "2024-01-02T00:00:00Z",
+        });
+        let mut detail = pull.clone();
+        detail["mergeable"] = json!(true);
+
+        Mock::given(method("GET")).and(path("/repos/owner/repo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "default_branch": "main" })))
+            .mount(&server).await;
+        Mock::given(method("GET")).and(path("/repos/owner/repo/pulls")).and(query_param("state", "open"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([pull])))
+            .mount(&server).await;
+        Mock::given(method("GET")).and(path("/repos/owner/repo/pulls/4"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(detail))
+            .mount(&server).await;
+        Mock::given(method("GET")).and(path("/repos/owner/repo/pulls/4/reviews"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "user": { "login": "rev" }, "state": "APPROVED" }])))
+            .mount(&server).await;
+        Mock::given(method("GET")).and(path("/repos/owner/repo/actions/runs")).and(query_param("branch", "main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "total_count": 0, "workflow_runs": [] })))
+            .mount(&server).await;
+        Mock::given(method("GET")).and(path("/repos/owner/repo/commits/main/check-suites"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "check_suites": [
+                { "status": "completed", "conclusion": "success", "updated_at": "2024-01-01T00:00:00Z" },
+                { "status": "completed", "conclusion": "failure", "updated_at": "2024-01-03T00:00:00Z" },
+            ] })))
+            .mount(&server).await;
+
+        let client = github::build_client(&server.uri(), "test-token").unwrap();
+        let status = fetch_status(&client, "owner", "repo").await.unwrap();
+
+        assert_eq!(status.default_branch, "main");
+        assert_eq!(status.pull_requests.len(), 1);
+        let pr = &status.pull_requests[0];
+        assert_eq!(pr.author.as_deref(), Some("octo"));
+        assert_eq!(pr.review_state, "approved");
+        assert_eq!(pr.mergeable, Some(true));
+
+        let ci = status.ci.unwrap();
+        assert_eq!(ci.summary(), "failure");
+        assert_eq!(ci.updated_at.as_deref(), Some("2024-01-03T00:00:00Z"));
+    }
+}
@@ -0,0 +1,545 @@
+// Headless agent runs
+// Runs the project's agent profile non-interactively on a single issue with a prompt from
+// prompts.json. Each run is recorded in .vibe/runs/<id>.json with its transcript in <id>.log,
+// which runs that time out or are cancelled get too. When the agent finishes, the issue moves to
+// for-review with the agent's final output as its implementation summary, or keeps its old
+// status with the error recorded.
+
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, Command};
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use crate::commands::agents::{self, AgentCommand};
+use crate::commands::dev_servers;
+use crate::commands::issues::{read_issues_file, write_issues_file};
+use crate::commands::prompts::render_prompt;
+use crate::models::{AgentRun, Issue, WorktreeRecord};
+
+const RUNS_DIR: &str = "runs";
+const DEFAULT_PROMPT: &str = "issueFix";
+// Appended to the chosen prompt to narrow it down to one issue
+const HEADLESS_PROMPT: &str = "headlessIssueRun";
+// Appended when the agent works in an issue's worktree
+const WORKTREE_PROMPT: &str = "issueWorktree";
+// Longest implementation summary taken from the agent's output, keeping the end
+const MAX_SUMMARY_CHARS: usize = 4000;
+// How long an agent that hit its run limit gets to exit before it is killed
+const STOP_GRACE: Duration = Duration::from_secs(5);
+
+// Issues with a run in progress, as "<project path>\n<issue id>"
+lazy_static::lazy_static! {
+    static ref ACTIVE_RUNS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+fn runs_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".vibe").join(RUNS_DIR)
+}
+
+fn transcript_path(project_path: &Path, run_id: &str) -> PathBuf {
+    runs_dir(project_path).join(format!("{}.log", run_id))
+}
+
+fn write_run(run: &AgentRun) -> Result<(), String> {
+    let dir = runs_dir(Path::new(&run.project_path));
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create runs directory: {}", e))?;
+    let content = serde_json::to_string_pretty(run)
+        .map_err(|e| format!("Failed to serialize run: {}", e))?;
+    fs::write(dir.join(format!("{}.json", run.id)), content)
+        .map_err(|e| format!("Failed to write run: {}", e))
+}
+
+/// Outcome of running the agent, to completion or until a run limit stopped it
+#[derive(Default)]
+struct AgentOutput {
+    exit_code: Option<i32>,
+    stdout: String,
+    stderr: String,
+    stopped: Option<String>, // Why it was stopped: timeout or cancellation
+}
+
+/// Collect a pipe until it closes, which for a stopped agent is once its group is gone
+fn read_pipe(pipe: Option<impl AsyncRead + Unpin + Send + 'static>) -> tokio::task::JoinHandle<String> {
+    tokio::spawn(async move {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes).await;
+        }
+        String::from_utf8_lossy(&bytes).to_string()
+    })
+}
+
+/// Stop an agent and everything it started: TERM to its process group, then KILL to whatever
+/// is left once it has exited or had STOP_GRACE to do so
+async fn stop_agent(child: &mut Child) {
+    #[cfg(unix)]
+    if let Some(pgid) = child.id() {
+        dev_servers::signal_group(pgid, "-TERM");
+        let _ = tokio::time::timeout(STOP_GRACE, child.wait()).await;
+        dev_servers::signal_group(pgid, "-KILL");
+    }
+
+    #[cfg(target_os = "windows")]
+    if let Some(pid) = child.id() {
+        dev_servers::stop_process_group(pid, STOP_GRACE);
+    }
+
+    let _ = child.kill().await;
+}
+
+async fn execute_agent(agent: &AgentCommand, cwd: &Path, limits: &RunLimits) -> Result<AgentOutput, String> {
+    let agent = agent.clone().spawnable();
+    let program = &agent.program;
+    let mut command = Command::new(program);
+    command
+        .args(&agent.args)
+        .envs(agent.env.iter().map(|(k, v)| (k, v)))
+        .current_dir(cwd)
+        .stdin(if agent.stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // Should the run itself be dropped, the agent goes with it
+        .kill_on_drop(true);
+
+    // In a group of its own, so stopping it also stops what it started
+    #[cfg(unix)]
+    command.process_group(0);
+
+    #[cfg(target_os = "windows")]
+    command.creation_flags(0x00000200 | 0x08000000); // CREATE_NEW_PROCESS_GROUP | CREATE_NO_WINDOW
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", program, e))?;
+
+    // Write the prompt from a separate task so a chatty agent can't fill the pipes first
+    let writer = match (child.stdin.take(), agent.stdin.clone()) {
+        (Some(mut stdin), Some(prompt)) => Some(tokio::spawn(async move {
+            let _ = stdin.write_all(prompt.as_bytes()).await;
+        })),
+        _ => None,
+    };
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let (exit_code, stopped) = tokio::select! {
+        status = child.wait() => {
+            let status = status.map_err(|e| format!("Failed to wait for {}: {}", program, e))?;
+            (status.code(), None)
+        }
+        reason = wait_for_limit(limits) => {
+            stop_agent(&mut child).await;
+            (None, Some(reason))
+        }
+    };
+    if let Some(writer) = writer {
+        writer.abort();
+    }
+
+    Ok(AgentOutput {
+        exit_code,
+        stdout: stdout.await.unwrap_or_default(),
+        stderr: stderr.await.unwrap_or_default(),
+        stopped,
+    })
+}
+
+fn write_transcript(path: &Path, prompt: &str, output: &AgentOutput, result: &Result<String, String>) -> Result<(), String> {
+    let content = format!(
+        "## Prompt\n\n{}\n\n## Output\n\n{}\n\n## Errors\n\n{}\n\n## Exit code\n\n{}\n\n## Result\n\n{}\n",
+        prompt.trim_end(),
+        output.stdout.trim_end(),
+        output.stderr.trim_end(),
+        output.exit_code.map(|c| c.to_string()).unwrap_or_else(|| "none".to_string()),
+        match result {
+            Ok(_) => "Succeeded",
+            Err(error) => error,
+        }
+    );
+    fs::write(path, content).map_err(|e| format!("Failed to write run transcript: {}", e))
+}
+
+/// Succeeded with the summary to store, or failed with the error to record
+fn run_result(output: &AgentOutput) -> Result<String, String> {
+    if let Some(reason) = &output.stopped {
+        return Err(reason.clone());
+    }
+    if output.exit_code != Some(0) {
+        let detail = output.stderr.trim();
+        let detail = if detail.is_empty() { output.stdout.trim() } else { detail };
+        return Err(match output.exit_code {
+            Some(code) => format!("Agent exited with code {}: {}", code, last_chars(detail, 500)),
+            None => format!("Agent was killed: {}", last_chars(detail, 500)),
+        });
+    }
+
+    let summary = output.stdout.trim();
+    if summary.is_empty() {
+        return Err("Agent finished without any output".to_string());
+    }
+    Ok(last_chars(summary, MAX_SUMMARY_CHARS))
+}
+
+fn last_chars(text: &str, max: usize) -> String {
+    let count = text.chars().count();
+    if count <= max {
+        return text.to_string();
+    }
+    format!("…{}", text.chars().skip(count - max).collect::<String>())
+}
+
+fn update_pending_issue<T>(project_path: &Path, issue_id: &str, f: impl FnOnce(&mut Issue) -> T) -> Result<T, String> {
+    let mut issues_file = read_issues_file(project_path)?;
+    let issue = issues_file
+        .issues
+        .iter_mut()
+        .find(|i| i.id == issue_id)
+        .ok_or("Issue not found")?;
+
+    let result = f(issue);
+    issue.updated_at = Some(Utc::now().to_rfc3339());
+    write_issues_file(project_path, &issues_file)?;
+    Ok(result)
+}
+
+/// Write a finished run back to its issue
+fn apply_run_result(project_path: &Path, issue_id: &str, previous_status: &str, result: &Result<String, String>) -> Result<(), String> {
+    update_pending_issue(project_path, issue_id, |issue| match result {
+        Ok(summary) => {
+            issue.implementation_summary = Some(summary.clone());
+            issue.status = "for-review".to_string();
+            issue.last_run_error = None;
+        }
+        Err(error) => {
+            issue.status = previous_status.to_string();
+            issue.last_run_error = Some(error.clone());
+        }
+    })
+}
+
+/// A run that has been recorded and marked in progress on its issue, ready to execute
+pub(crate) struct PreparedRun {
+    pub run: AgentRun,
+    prompt: String,
+    previous_status: String,
+}
+
+/// Limits for a run started by the work queue
+#[derive(Default)]
+pub(crate) struct RunLimits {
+    pub timeout: Option<Duration>,
+    pub cancel: Option<Arc<Notify>>,
+}
+
+/// A timeout as minutes and seconds, e.g. "30 seconds", "10 minutes" or "1 minute 30 seconds"
+fn describe_timeout(timeout: Duration) -> String {
+    let plural = |n: u64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+    let (minutes, seconds) = (timeout.as_secs() / 60, timeout.as_secs() % 60);
+    match (minutes, seconds) {
+        (0, seconds) => plural(seconds, "second"),
+        (minutes, 0) => plural(minutes, "minute"),
+        (minutes, seconds) => format!("{} {}", plural(minutes, "minute"), plural(seconds, "second")),
+    }
+}
+
+async fn wait_for_limit(limits: &RunLimits) -> String {
+    let timeout = async {
+        match limits.timeout {
+            Some(timeout) => tokio::time::sleep(timeout).await,
+            None => std::future::pending().await,
+        }
+    };
+    let cancel = async {
+        match &limits.cancel {
+            Some(cancel) => cancel.notified().await,
+            None => std::future::pending().await,
+        }
+    };
+
+    tokio::select! {
+        _ = timeout => format!("Agent timed out after {}", describe_timeout(limits.timeout.unwrap_or_default())),
+        _ = cancel => "Cancelled".to_string(),
+    }
+}
+
+/// Run the agent to completion and write the outcome back to the run and the issue
+pub(crate) async fn complete_run(app: &AppHandle, prepared: PreparedRun, limits: RunLimits) -> AgentRun {
+    let PreparedRun { mut run, prompt, previous_status } = prepared;
+    let project_path = PathBuf::from(&run.project_path);
+    let work_dir = run.work_dir.as_ref().map(PathBuf::from).unwrap_or_else(|| project_path.clone());
+    let prompt_file = runs_dir(&project_path).join(format!("{}.prompt.md", run.id));
+
+    let execution = match agents::project_agent_command(app, &run.project_path, &prompt, true, &prompt_file) {
+        Ok(agent) => execute_agent(&agent, &work_dir, &limits).await,
+        Err(e) => Err(e),
+    };
+    // The prompt is kept in the transcript; the file was only for handing it over
+    let _ = fs::remove_file(&prompt_file);
+
+    // Runs that never started get a transcript too, with just the prompt and the error
+    let (output, result) = match execution {
+        Ok(output) => {
+            let result = run_result(&output);
+            (output, result)
+        }
+        Err(e) => (AgentOutput::default(), Err(e)),
+    };
+    run.exit_code = output.exit_code;
+    if let Err(e) = write_transcript(&transcript_path(&project_path, &run.id), &prompt, &output, &result) {
+        log::error!(project = run.project_path.as_str(); "{}", e);
+    }
+
+    if let Err(e) = apply_run_result(&project_path, &run.issue_id, &previous_status, &result) {
+        log::error!(project = run.project_path.as_str(); "Failed to update issue {} after run {}: {}", run.issue_id, run.id, e);
+    }
+
+    run.finished_at = Some(Utc::now().to_rfc3339());
+    match result {
+        Ok(summary) => {
+            run.status = "succeeded".to_string();
+            run.summary = Some(summary);
+        }
+        Err(error) => {
+            run.status = "failed".to_string();
+            run.error = Some(error);
+        }
+    }
+    log::info!(project = run.project_path.as_str(); "Headless run {} for issue {} {}", run.id, run.issue_id, run.status);
+    if let Err(e) = write_run(&run) {
+        log::error!(project = run.project_path.as_str(); "{}", e);
+    }
+
+    ACTIVE_RUNS.lock().unwrap().remove(&format!("{}\n{}", run.project_path, run.issue_id));
+    let _ = app.emit("agent-run-finished", &run);
+    run
+}
+
+/// Prompt for working on one issue: the named prompt followed by the extra ones,
+/// plus worktree instructions when the agent works in the issue's worktree
+pub(crate) fn issue_prompt(
+    project_path: &str,
+    issue: &Issue,
+    prompt_name: &str,
+    extra_prompts: &[&str],
+    worktree: Option<&WorktreeRecord>,
+) -> Result<String, String> {
+    let project_name = Path::new(project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Project".to_string());
+    let mut replacements = HashMap::from([
+        ("PROJECT_NAME".to_string(), project_name),
+        ("PROJECT_PATH".to_string(), project_path.to_string()),
+        ("ISSUE_ID".to_string(), issue.id.clone()),
+        ("ISSUE_TITLE".to_string(), issue.title.clone()),
+        ("ISSUE_DESCRIPTION".to_string(), issue.description.clone()),
+    ]);
+    let mut names = vec![prompt_name];
+    names.extend_from_slice(extra_prompts);
+    if let Some(worktree) = worktree {
+        replacements.insert("WORKTREE_PATH".to_string(), worktree.path.clone());
+        replacements.insert("BRANCH".to_string(), worktree.branch.clone());
+        names.push(WORKTREE_PROMPT);
+    }
+
+    let parts = names
+        .into_iter()
+        .map(|name| render_prompt(name, replacements.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(parts.join("\n\n---\n\n"))
+}
+
+/// Build the prompt for a run, record it and mark its issue in progress
+pub(crate) fn prepare_run(
+    project_path: &str,
+    issue_id: &str,
+    prompt_name: Option<String>,
+    worktree: Option<&WorktreeRecord>,
+) -> Result<PreparedRun, String> {
+    let path = Path::new(project_path);
+    let issue = read_issues_file(path)?
+        .issues
+        .into_iter()
+        .find(|i| i.id == issue_id)
+        .ok_or("Issue not found")?;
+
+    let prompt_name = prompt_name.unwrap_or_else(|| DEFAULT_PROMPT.to_string());
+    let prompt = issue_prompt(project_path, &issue, &prompt_name, &[HEADLESS_PROMPT], worktree)?;
+
+    let key = format!("{}\n{}", project_path, issue_id);
+    if !ACTIVE_RUNS.lock().unwrap().insert(key.clone()) {
+        return Err("An agent is already working on this issue".to_string());
+    }
+
+    let run = AgentRun {
+        id: Uuid::new_v4().to_string(),
+        project_path: project_path.to_string(),
+        issue_id: issue_id.to_string(),
+        prompt_name,
+        work_dir: worktree.map(|w| w.path.clone()),
+        status: "running".to_string(),
+        started_at: Utc::now().to_rfc3339(),
+        finished_at: None,
+        exit_code: None,
+        summary: None,
+        error: None,
+    };
+
+    let started = write_run(&run).and_then(|_| {
+        update_pending_issue(path, issue_id, |issue| {
+            issue.status = "in-progress".to_string();
+            issue.last_run_error = None;
+        })
+    });
+    if let Err(e) = started {
+        ACTIVE_RUNS.lock().unwrap().remove(&key);
+        return Err(e);
+    }
+    log::info!(project = run.project_path.as_str(); "Started headless run {} for issue {}", run.id, issue_id);
+
+    Ok(PreparedRun { run, prompt, previous_status: issue.status })
+}
+
+/// Start a headless agent run on an issue; "agent-run-finished" is emitted when it ends
+#[tauri::command]
+pub async fn start_headless_run(
+    app: AppHandle,
+    project_path: String,
+    issue_id: String,
+    prompt_name: Option<String>,
+) -> Result<AgentRun, String> {
+    let prepared = prepare_run(&project_path, &issue_id, prompt_name, None)?;
+    let run = prepared.run.clone();
+    tauri::async_runtime::spawn(async move {
+        complete_run(&app, prepared, RunLimits::default()).await;
+    });
+    Ok(run)
+}
+
+/// Headless runs for a project, most recent first
+#[tauri::command]
+pub async fn get_agent_runs(project_path: String) -> Result<Vec<AgentRun>, String> {
+    let dir = runs_dir(Path::new(&project_path));
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+
+    let mut runs: Vec<AgentRun> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|p| fs::read_to_string(p).ok())
+        .filter_map(|content| serde_json::from_str(&content).ok())
+        .collect();
+    runs.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(runs)
+}
+
+#[tauri::command]
+pub async fn get_run_transcript(project_path: String, run_id: String) -> Result<String, String> {
+    if run_id.contains(['/', '\\']) || run_id.contains("..") {
+        return Err("Invalid run id".to_string());
+    }
+
+    fs::read_to_string(transcript_path(Path::new(&project_path), &run_id))
+        .map_err(|e| format!("Failed to read run transcript: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::IssueFile;
+
+    fn project_with_issue(status: &str) -> PathBuf {
+        let project = std::env::temp_dir().join(format!("vibe-hub-runs-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&project).unwrap();
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "id": "issue-1",
+            "title": "Fix login",
+            "description": "",
+            "subtasks": [],
+            "priority": 2,
+            "status": status,
+            "createdAt": "2024-01-01T00:00:00Z",
+        }))
+        .unwrap();
+        write_issues_file(&project, &IssueFile { issues: vec![issue] }).unwrap();
+        project
+    }
+
+    fn sh(script: &str, stdin: Option<&str>) -> AgentCommand {
+        AgentCommand {
+            program: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            env: Vec::new(),
+            stdin: stdin.map(String::from),
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_agent_output_becomes_the_implementation_summary() {
+        let project = project_with_issue("in-progress");
+        let output = execute_agent(&sh("cat > prompt.txt; echo 'Fixed the login form.'", Some("Fix issue-1")), &project, &RunLimits::default())
+            .await
+            .unwrap();
+        assert_eq!(fs::read_to_string(project.join("prompt.txt")).unwrap(), "Fix issue-1");
+
+        apply_run_result(&project, "issue-1", "pending", &run_result(&output)).unwrap();
+        let issue = &read_issues_file(&project).unwrap().issues[0];
+        assert_eq!(issue.status, "for-review");
+        assert_eq!(issue.implementation_summary.as_deref(), Some("Fixed the login form."));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_failed_run_records_error_and_restores_status() {
+        let project = project_with_issue("in-progress");
+        let output = execute_agent(&sh("echo 'not logged in' >&2; exit 2", None), &project, &RunLimits::default())
+            .await
+            .unwrap();
+
+        apply_run_result(&project, "issue-1", "needs-rework", &run_result(&output)).unwrap();
+        let issue = &read_issues_file(&project).unwrap().issues[0];
+        assert_eq!(issue.status, "needs-rework");
+        assert_eq!(issue.last_run_error.as_deref(), Some("Agent exited with code 2: not logged in"));
+        assert!(issue.implementation_summary.is_none());
+    }
+
+    #[test]
+    fn test_describe_timeout_uses_minutes_and_seconds() {
+        assert_eq!(describe_timeout(Duration::from_secs(45)), "45 seconds");
+        assert_eq!(describe_timeout(Duration::from_secs(600)), "10 minutes");
+        assert_eq!(describe_timeout(Duration::from_secs(90)), "1 minute 30 seconds");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_timed_out_run_keeps_partial_output_and_stops_its_group() {
+        let project = project_with_issue("in-progress");
+        let limits = RunLimits { timeout: Some(Duration::from_secs(1)), cancel: None };
+        // The background sleep would keep the pipes open if only the shell were killed
+        let started = std::time::Instant::now();
+        let output = execute_agent(&sh("echo 'halfway there'; sleep 30 & wait", None), &project, &limits)
+            .await
+            .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert_eq!(output.stdout.trim(), "halfway there");
+
+        let result = run_result(&output);
+        assert_eq!(result.as_deref().unwrap_err(), "Agent timed out after 1 second");
+        let transcript = project.join("run.log");
+        write_transcript(&transcript, "Fix issue-1", &output, &result).unwrap();
+        let transcript = fs::read_to_string(transcript).unwrap();
+        assert!(transcript.contains("halfway there") && transcript.contains("## Result\n\nAgent timed out"));
+    }
+}
@@ -0,0 +1,321 @@
+// Lifecycle tracking for Claude Code sessions launched in a terminal
+// The session helper (see spawn.rs) writes the agent's PID and exit code into a per-session
+// run directory. A monitor task watches those files and the PID, then records the finished
+// session in .vibe/session-history.json, completes its transcript (see transcripts.rs) and emits
+// "session-started" / "session-stopped" events.
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use crate::commands::{tmux, transcripts};
+use crate::models::{SessionHistory, SessionInfo, SessionRecord};
+
+const HISTORY_FILE: &str = "session-history.json";
+const MAX_HISTORY: usize = 100;
+// How long the terminal gets to start the agent before the launch counts as failed
+const START_TIMEOUT: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// Running sessions by project path
+lazy_static::lazy_static! {
+    static ref SESSIONS: Mutex<HashMap<String, SessionInfo>> = Mutex::new(HashMap::new());
+}
+
+/// Run directory the session helper reports a session's PID and exit code into
+pub(crate) struct SessionFiles {
+    pub id: String,
+    pub dir: PathBuf,
+    pub pid_file: PathBuf,
+    pub exit_file: PathBuf,
+}
+
+impl SessionFiles {
+    pub(crate) fn new(id: String, dir: PathBuf) -> Self {
+        Self {
+            id,
+            pid_file: dir.join("pid"),
+            exit_file: dir.join("exit"),
+            dir,
+        }
+    }
+}
+
+/// What a session's run directory and PID say about it
+#[derive(Debug, PartialEq)]
+enum SessionState {
+    Starting,
+    Running(u32),
+    /// The PID is gone but no exit code was written (yet)
+    Gone(u32),
+    Exited { pid: Option<u32>, exit_code: i32 },
+    TimedOut,
+}
+
+/// Parse a number written by a shell, ignoring whitespace and any encoding noise around it
+fn read_number<T: FromStr>(path: &Path) -> Option<T> {
+    let content = fs::read_to_string(path).ok()?;
+    content
+        .trim_matches(|c: char| !c.is_ascii_digit() && c != '-')
+        .parse()
+        .ok()
+}
+
+/// Whether a process with this PID is still running
+pub(crate) fn process_alive(pid: u32) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW - hide the cmd window
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains(&format!("\"{}\"", pid)))
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+}
+
+fn check_session(files: &SessionFiles, known_pid: Option<u32>, elapsed: Duration) -> SessionState {
+    let pid = known_pid.or_else(|| read_number(&files.pid_file));
+
+    if let Some(exit_code) = read_number(&files.exit_file) {
+        return SessionState::Exited { pid, exit_code };
+    }
+
+    match pid {
+        Some(pid) if process_alive(pid) => SessionState::Running(pid),
+        Some(pid) => SessionState::Gone(pid),
+        None if elapsed > START_TIMEOUT => SessionState::TimedOut,
+        None => SessionState::Starting,
+    }
+}
+
+/// Create the run directory for a new session
+pub(crate) fn prepare_session() -> Result<SessionFiles, String> {
+    let id = Uuid::new_v4().to_string();
+    let dir = std::env::temp_dir().join("vibe-hub-sessions").join(&id);
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create session directory: {}", e))?;
+
+    Ok(SessionFiles::new(id, dir))
+}
+
+/// Register a launched session and watch it until it exits
+pub(crate) fn start_session(app: &AppHandle, project_path: &str, files: SessionFiles, tmux_session: Option<String>) {
+    let session = SessionInfo {
+        id: Some(files.id.clone()),
+        project_path: project_path.to_string(),
+        status: "running".to_string(),
+        pid: None,
+        started_at: Some(Utc::now().to_rfc3339()),
+        tmux_session,
+        created_at: Some(Instant::now()),
+    };
+
+    SESSIONS.lock().unwrap().insert(project_path.to_string(), session.clone());
+    let _ = app.emit("session-started", &session);
+
+    tauri::async_runtime::spawn(monitor_session(app.clone(), session, files));
+}
+
+async fn monitor_session(app: AppHandle, session: SessionInfo, files: SessionFiles) {
+    let started = session.created_at.unwrap_or_else(Instant::now);
+    let mut pid: Option<u32> = None;
+    let mut seen_gone = false;
+
+    let (pid, exit_code, outcome) = loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        match check_session(&files, pid, started.elapsed()) {
+            SessionState::Starting => {}
+            SessionState::Running(running) => {
+                if pid.is_none() {
+                    pid = Some(running);
+                    set_session_pid(&session.project_path, &files.id, running);
+                }
+                seen_gone = false;
+            }
+            // Give the helper one more poll to write the exit code after the agent exits
+            SessionState::Gone(gone) if !seen_gone => {
+                pid = Some(gone);
+                seen_gone = true;
+            }
+            SessionState::Gone(gone) => break (Some(gone), None, "closed"),
+            SessionState::Exited { pid, exit_code } => break (pid, Some(exit_code), "exited"),
+            SessionState::TimedOut => break (None, None, "failed_to_start"),
+        }
+    };
+
+    let record = SessionRecord {
+        id: files.id.clone(),
+        project_path: session.project_path.clone(),
+        pid,
+        started_at: session.started_at.clone().unwrap_or_default(),
+        ended_at: Utc::now().to_rfc3339(),
+        duration_secs: started.elapsed().as_secs(),
+        exit_code,
+        outcome: outcome.to_string(),
+    };
+    log::info!(
+        project = record.project_path.as_str();
+        "Session {} ended: {} (exit code {:?}, {}s)", record.id, record.outcome, record.exit_code, record.duration_secs
+    );
+
+    {
+        // A newer launch for the same project replaces this entry; leave that one alone
+        let mut sessions = SESSIONS.lock().unwrap();
+        if sessions.get(&session.project_path).and_then(|s| s.id.as_deref()) == Some(files.id.as_str()) {
+            sessions.remove(&session.project_path);
+        }
+    }
+
+    if let Err(e) = append_history(Path::new(&session.project_path), record.clone()) {
+        log::error!(project = session.project_path.as_str(); "Failed to record session history: {}", e);
+    }
+    if let Err(e) = transcripts::finish_transcript(&session.project_path, &files.id, outcome, exit_code) {
+        log::warn!(project = session.project_path.as_str(); "Failed to complete session transcript: {}", e);
+    }
+    let _ = app.emit("session-stopped", &record);
+    let _ = fs::remove_dir_all(&files.dir);
+}
+
+fn set_session_pid(project_path: &str, id: &str, pid: u32) {
+    let mut sessions = SESSIONS.lock().unwrap();
+    if let Some(session) = sessions.get_mut(project_path) {
+        if session.id.as_deref() == Some(id) {
+            session.pid = Some(pid);
+        }
+    }
+}
+
+fn read_history(project_path: &Path) -> Result<SessionHistory, String> {
+    let history_path = project_path.join(".vibe").join(HISTORY_FILE);
+    if !history_path.exists() {
+        return Ok(SessionHistory::default());
+    }
+
+    let content = fs::read_to_string(&history_path)
+        .map_err(|e| format!("Failed to read session history: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse session history: {}", e))
+}
+
+/// Add a finished session to the project's history, dropping the oldest beyond MAX_HISTORY
+pub(crate) fn append_history(project_path: &Path, record: SessionRecord) -> Result<(), String> {
+    let mut history = read_history(project_path)?;
+    history.sessions.push(record);
+    let excess = history.sessions.len().saturating_sub(MAX_HISTORY);
+    history.sessions.drain(..excess);
+
+    let vibe_dir = project_path.join(".vibe");
+    fs::create_dir_all(&vibe_dir)
+        .map_err(|e| format!("Failed to create .vibe directory: {}", e))?;
+    let content = serde_json::to_string_pretty(&history)
+        .map_err(|e| format!("Failed to serialize session history: {}", e))?;
+    fs::write(vibe_dir.join(HISTORY_FILE), content)
+        .map_err(|e| format!("Failed to write session history: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_session_status(app: AppHandle, project_path: String) -> Result<SessionInfo, String> {
+    if let Some(session) = SESSIONS.lock().unwrap().get(&project_path) {
+        return Ok(session.clone());
+    }
+
+    // A tmux session outlives the app, so one started before a restart still counts
+    if tmux::enabled(&app) {
+        let name = tmux::session_name(&project_path);
+        if tmux::has_session(&name) {
+            return Ok(SessionInfo {
+                status: "running".to_string(),
+                tmux_session: Some(name),
+                ..SessionInfo::not_started(project_path)
+            });
+        }
+    }
+
+    Ok(SessionInfo::not_started(project_path))
+}
+
+/// Finished sessions for a project, most recent first
+#[tauri::command]
+pub async fn get_session_history(project_path: String) -> Result<Vec<SessionRecord>, String> {
+    let mut sessions = read_history(Path::new(&project_path))?.sessions;
+    sessions.reverse();
+    Ok(sessions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_files() -> SessionFiles {
+        let dir = std::env::temp_dir().join(format!("vibe-hub-session-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        SessionFiles::new("test".to_string(), dir)
+    }
+
+    #[test]
+    fn test_check_session_follows_pid_and_exit_code() {
+        let files = temp_files();
+        assert_eq!(check_session(&files, None, Duration::ZERO), SessionState::Starting);
+        assert_eq!(check_session(&files, None, START_TIMEOUT * 2), SessionState::TimedOut);
+
+        let pid = std::process::id();
+        fs::write(&files.pid_file, format!("{}\n", pid)).unwrap();
+        assert_eq!(check_session(&files, None, Duration::ZERO), SessionState::Running(pid));
+
+        fs::write(&files.exit_file, "3 \r\n").unwrap();
+        assert_eq!(
+            check_session(&files, Some(pid), Duration::ZERO),
+            SessionState::Exited { pid: Some(pid), exit_code: 3 }
+        );
+
+        let mut child = Command::new(env!("CARGO")).arg("--version").spawn().unwrap();
+        let finished = child.id();
+        child.wait().unwrap();
+        fs::remove_file(&files.exit_file).unwrap();
+        assert_eq!(check_session(&files, Some(finished), Duration::ZERO), SessionState::Gone(finished));
+    }
+
+    #[test]
+    fn test_history_keeps_the_most_recent_sessions() {
+        let project = std::env::temp_dir().join(format!("vibe-hub-history-test-{}", Uuid::new_v4()));
+        for i in 0..MAX_HISTORY + 2 {
+            let record = SessionRecord {
+                id: i.to_string(),
+                project_path: project.to_string_lossy().to_string(),
+                pid: None,
+                started_at: Utc::now().to_rfc3339(),
+                ended_at: Utc::now().to_rfc3339(),
+                duration_secs: 1,
+                exit_code: Some(0),
+                outcome: "exited".to_string(),
+            };
+            append_history(&project, record).unwrap();
+        }
+
+        let sessions = read_history(&project).unwrap().sessions;
+        assert_eq!(sessions.len(), MAX_HISTORY);
+        assert_eq!(sessions[0].id, "2");
+        assert_eq!(sessions.last().unwrap().id, (MAX_HISTORY + 1).to_string());
+    }
+}
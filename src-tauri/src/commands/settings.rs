@@ -1,3 +1,4 @@
+use crate::commands::credentials;
 use crate::models::Settings;
 use std::fs;
 use std::path::PathBuf;
@@ -10,9 +11,9 @@ fn get_settings_path(app: &AppHandle) -> Result<PathBuf, String> {
         .map(|path| path.join("settings.json"))
 }
 
-#[tauri::command]
-pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
-    let settings_path = get_settings_path(&app)?;
+/// Read settings.json as stored, without tokens from the credential store
+pub(crate) fn read_settings_file(app: &AppHandle) -> Result<Settings, String> {
+    let settings_path = get_settings_path(app)?;
 
     if !settings_path.exists() {
         return Ok(Settings::default());
@@ -26,22 +27,14 @@ pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
 }
 
 #[tauri::command]
-pub async fn update_settings(app: AppHandle, settings: Settings) -> Result<(), String> {
-    let settings_path = get_settings_path(&app)?;
-
-    // Create parent directory if it doesn't exist
-    if let Some(parent) = settings_path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create config directory: {}", e))?;
-    }
-
-    let json = serde_json::to_string_pretty(&settings)
-        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
-
-    fs::write(&settings_path, json)
-        .map_err(|e| format!("Failed to write settings: {}", e))?;
+pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
+    read_settings_file(&app)
+}
 
-    Ok(())
+#[tauri::command]
+pub async fn update_settings(app: AppHandle, settings: Settings) -> Result<(), String> {
+    // Tokens go to the credential store; settings.json is written without them
+    credentials::save_settings(&app, &settings)
 }
 
 #[tauri::command]
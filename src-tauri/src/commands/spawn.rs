@@ -0,0 +1,196 @@
+// Spawn specs for agent sessions launched in a terminal
+// Rather than building a shell command line, the launcher writes what to run to spec.json in
+// the session's run directory and has the terminal start Vibe Hub itself with
+// `--run-agent <dir>`. That process starts the agent directly, reports its PID and exit code
+// for session tracking, deletes the prompt files and then keeps the terminal open, except in
+// tmux, where the window closing is what ends the session.
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::commands::sessions::SessionFiles;
+use crate::models::SpawnSpec;
+
+pub(crate) const HELPER_FLAG: &str = "--run-agent";
+const SPEC_FILE: &str = "spec.json";
+
+pub(crate) fn write_spec(dir: &Path, spec: &SpawnSpec) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(spec)
+        .map_err(|e| format!("Failed to serialize spawn spec: {}", e))?;
+    fs::write(dir.join(SPEC_FILE), content)
+        .map_err(|e| format!("Failed to write spawn spec: {}", e))
+}
+
+fn read_spec(dir: &Path) -> Result<SpawnSpec, String> {
+    let content = fs::read_to_string(dir.join(SPEC_FILE))
+        .map_err(|e| format!("Failed to read spawn spec: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse spawn spec: {}", e))
+}
+
+/// This executable in helper mode, running the spec in `dir`
+pub(crate) fn helper_command(dir: &Path) -> Result<(String, Vec<String>), String> {
+    // An AppImage's own path is gone once the app exits, which a tmux session can outlive
+    let exe = match std::env::var_os("APPIMAGE") {
+        Some(appimage) => PathBuf::from(appimage),
+        None => std::env::current_exe().map_err(|e| format!("Failed to locate Vibe Hub: {}", e))?,
+    };
+
+    Ok((
+        exe.to_string_lossy().to_string(),
+        vec![HELPER_FLAG.to_string(), dir.to_string_lossy().to_string()],
+    ))
+}
+
+/// Full path of a bare program name on Windows, so .cmd shims run with std's argument
+/// escaping for batch files instead of being handed to cmd /c as a string
+pub(crate) fn resolve_program(program: &str) -> String {
+    if !cfg!(target_os = "windows") || Path::new(program).extension().is_some() || Path::new(program).components().count() > 1 {
+        return program.to_string();
+    }
+
+    std::env::var_os("PATH")
+        .and_then(|path| {
+            std::env::split_paths(&path).find_map(|dir| {
+                ["exe", "cmd", "bat"]
+                    .iter()
+                    .map(|ext| dir.join(format!("{}.{}", program, ext)))
+                    .find(|candidate| candidate.is_file())
+            })
+        })
+        .map(|found| found.to_string_lossy().to_string())
+        .unwrap_or_else(|| program.to_string())
+}
+
+/// Run a spec to completion in this terminal, writing the PID and exit code for the session monitor
+fn run_spec(spec: &SpawnSpec, files: &SessionFiles) -> Result<i32, String> {
+    let mut command = Command::new(resolve_program(&spec.program));
+    command
+        .args(&spec.args)
+        .envs(spec.env.iter().map(|(k, v)| (k, v)))
+        .current_dir(&spec.cwd);
+
+    if let Some(stdin_file) = &spec.stdin_file {
+        let input = File::open(stdin_file)
+            .map_err(|e| format!("Failed to open prompt file: {}", e))?;
+        command.stdin(Stdio::from(input));
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", spec.program, e))?;
+    let _ = fs::write(&files.pid_file, child.id().to_string());
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for {}: {}", spec.program, e))?;
+    Ok(status.code().unwrap_or(-1))
+}
+
+fn run_session(dir: &Path) -> i32 {
+    let files = SessionFiles::new(String::new(), dir.to_path_buf());
+    let spec = match read_spec(dir) {
+        Ok(spec) => spec,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    #[cfg(target_os = "windows")]
+    open_console(spec.title.as_deref());
+
+    let exit_code = run_spec(&spec, &files).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        127
+    });
+    let _ = fs::write(&files.exit_file, exit_code.to_string());
+    for path in &spec.cleanup {
+        let _ = fs::remove_file(path);
+    }
+
+    println!("\n[Agent exited with code {}]", exit_code);
+    if spec.keep_open {
+        keep_terminal_open(&spec.cwd);
+    }
+    exit_code
+}
+
+/// A release build has no console of its own on Windows
+#[cfg(target_os = "windows")]
+fn open_console(title: Option<&str>) {
+    use windows::core::HSTRING;
+    use windows::Win32::System::Console::{AllocConsole, SetConsoleTitleW};
+
+    unsafe {
+        // Fails harmlessly when there already is one (debug builds)
+        let _ = AllocConsole();
+        if let Some(title) = title {
+            let _ = SetConsoleTitleW(&HSTRING::from(title));
+        }
+    }
+}
+
+/// Leave the user in a shell in the project, like the terminal would after the agent
+#[cfg(unix)]
+fn keep_terminal_open(cwd: &str) {
+    use std::os::unix::process::CommandExt;
+    let error = Command::new("bash").current_dir(cwd).exec();
+    eprintln!("Failed to start bash: {}", error);
+}
+
+#[cfg(not(unix))]
+fn keep_terminal_open(_cwd: &str) {
+    println!("Press Enter to close this window");
+    let _ = std::io::stdin().read_line(&mut String::new());
+}
+
+/// Run a launched session if this process was started as a helper, returning its exit code
+pub fn run_helper_from_args() -> Option<i32> {
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() != Some(HELPER_FLAG) {
+        return None;
+    }
+
+    match args.next() {
+        Some(dir) => Some(run_session(Path::new(&dir))),
+        None => {
+            eprintln!("{} needs a session directory", HELPER_FLAG);
+            Some(2)
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_runs_without_a_shell_and_reports_its_exit_code() {
+        let dir = std::env::temp_dir().join(format!("vibe-spawn-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let prompt_file = dir.join("agent-stdin.txt");
+        fs::write(&prompt_file, "Fix 'it' && $(rm -rf /)").unwrap();
+
+        // The prompt and argument reach the program verbatim
+        let spec = SpawnSpec {
+            program: "sh".to_string(),
+            args: vec!["-c".to_string(), "cat > out.txt; echo \"$1 $MODE\" >> out.txt; exit 3".to_string(), "sh".to_string(), "a 'b'; c".to_string()],
+            env: vec![("MODE".to_string(), "test".to_string())],
+            cwd: dir.to_string_lossy().to_string(),
+            stdin_file: Some(prompt_file.to_string_lossy().to_string()),
+            title: None,
+            cleanup: vec![prompt_file.to_string_lossy().to_string()],
+            keep_open: false,
+        };
+        write_spec(&dir, &spec).unwrap();
+
+        let files = SessionFiles::new(String::new(), dir.clone());
+        assert_eq!(run_spec(&read_spec(&dir).unwrap(), &files), Ok(3));
+        assert_eq!(fs::read_to_string(dir.join("out.txt")).unwrap(), "Fix 'it' && $(rm -rf /)a 'b'; c test\n");
+        assert!(fs::read_to_string(&files.pid_file).unwrap().parse::<u32>().is_ok());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
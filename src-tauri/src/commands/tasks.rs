@@ -0,0 +1,373 @@
+// Runnable project tasks
+// Collected from every build tool a project has set up: dev/start .bat and .sh scripts,
+// package.json scripts (run with the package manager its lockfile belongs to), deno.json,
+// Cargo and Tauri, pyproject.toml (plain, Poetry or uv), Go modules, Makefiles and justfiles.
+// Build files are read line by line where that's enough, so none of them need to be valid.
+
+use std::fs;
+use std::path::Path;
+
+use crate::commands::npm::PackageJson;
+use crate::models::ProjectTask;
+
+// Checked in this order, so a project with a .bat and a .sh script gets the .bat first
+const DEV_BAT_FILES: &[&str] = &["dev.bat", "start.bat", "dev-server.bat", "start-dev.bat"];
+const DEV_SH_FILES: &[&str] = &["dev.sh", "start.sh", "dev-server.sh", "start-dev.sh"];
+
+pub(crate) const PACKAGE_MANAGERS: &[&str] = &["npm", "pnpm", "yarn", "bun"];
+
+// Lockfiles by package manager; a project with none uses packageManager from package.json
+const LOCKFILES: &[(&str, &str)] = &[
+    ("bun.lockb", "bun"),
+    ("bun.lock", "bun"),
+    ("pnpm-lock.yaml", "pnpm"),
+    ("yarn.lock", "yarn"),
+    ("package-lock.json", "npm"),
+];
+
+const MAKEFILES: &[&str] = &["GNUmakefile", "makefile", "Makefile"];
+const JUSTFILES: &[&str] = &["justfile", "Justfile", ".justfile"];
+// justfile lines that start with one of these are settings, not recipes
+const JUST_KEYWORDS: &[&str] = &["set", "alias", "export", "import", "mod"];
+
+fn task(name: &str, command: String, kind: &str, source: &str) -> ProjectTask {
+    ProjectTask {
+        name: name.to_string(),
+        command,
+        kind: kind.to_string(),
+        source: source.to_string(),
+    }
+}
+
+fn read_package_json(root: &Path) -> Option<PackageJson> {
+    let content = fs::read_to_string(root.join("package.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// The package manager that runs a project's package.json scripts
+pub(crate) fn package_manager(root: &Path) -> &'static str {
+    if let Some((_, manager)) = LOCKFILES.iter().find(|(file, _)| root.join(file).exists()) {
+        return manager;
+    }
+
+    // "pnpm@8.15.0"
+    read_package_json(root)
+        .and_then(|package| package.package_manager)
+        .and_then(|field| {
+            let name = field.split('@').next().unwrap_or_default().to_string();
+            PACKAGE_MANAGERS.iter().find(|manager| **manager == name).copied()
+        })
+        .unwrap_or("npm")
+}
+
+/// Whether a TOML file has a `[section]` table or one nested under it
+fn toml_has_section(content: &str, section: &str) -> bool {
+    let header = format!("[{}]", section);
+    let nested = format!("[{}.", section);
+    content.lines().map(str::trim).any(|line| line == header || line.starts_with(&nested))
+}
+
+/// The keys of a `[section]` table in a TOML file
+fn toml_keys(content: &str, section: &str) -> Vec<String> {
+    let header = format!("[{}]", section);
+    let mut inside = false;
+    let mut keys = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            inside = line == header;
+        } else if inside && !line.starts_with('#') {
+            if let Some((key, _)) = line.split_once('=') {
+                let key = key.trim().trim_matches('"');
+                if !key.is_empty() {
+                    keys.push(key.to_string());
+                }
+            }
+        }
+    }
+    keys
+}
+
+/// Rule targets in a Makefile, leaving out special (.PHONY) and pattern (%.o) targets
+fn make_targets(content: &str) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    for line in content.lines() {
+        if line.starts_with([' ', '\t', '#']) {
+            continue;
+        }
+        let Some((lhs, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // Variable assignments: FOO := x, FOO ::= x, FOO = a:b
+        if lhs.contains('=') || rest.trim_start_matches(':').starts_with('=') {
+            continue;
+        }
+
+        for target in lhs.split_whitespace() {
+            if !target.starts_with('.') && !target.contains(['%', '$']) && !targets.iter().any(|t| t == target) {
+                targets.push(target.to_string());
+            }
+        }
+    }
+    targets
+}
+
+/// Public recipes in a justfile: not marked `[private]`, and not named with a leading `_`
+fn just_recipes(content: &str) -> Vec<String> {
+    let mut recipes = Vec::new();
+    // Set by a `[private]` attribute line until the recipe it belongs to
+    let mut private = false;
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with([' ', '\t', '#']) {
+            continue;
+        }
+        if let Some(attributes) = line.trim_end().strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            private |= attributes.split(',').any(|attribute| attribute.trim() == "private");
+            continue;
+        }
+
+        let is_private = std::mem::take(&mut private);
+        let Some((head, rest)) = line.split_once(':') else {
+            continue;
+        };
+        if rest.starts_with('=') {
+            continue;
+        }
+        let Some(name) = head.split_whitespace().next().map(|name| name.trim_start_matches('@')) else {
+            continue;
+        };
+        let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if valid && !is_private && !JUST_KEYWORDS.contains(&name) {
+            recipes.push(name.to_string());
+        }
+    }
+    recipes
+}
+
+fn script_file_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    for file in DEV_BAT_FILES.iter().filter(|file| root.join(file).exists()) {
+        tasks.push(task(file.trim_end_matches(".bat"), format!(".\\{}", file), "bat", file));
+    }
+    for file in DEV_SH_FILES.iter().filter(|file| root.join(file).exists()) {
+        tasks.push(task(file.trim_end_matches(".sh"), format!("./{}", file), "sh", file));
+    }
+}
+
+fn package_json_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    let Some(scripts) = read_package_json(root).and_then(|package| package.scripts) else {
+        return;
+    };
+
+    let manager = package_manager(root);
+    let mut names: Vec<&String> = scripts.keys().collect();
+    names.sort();
+    for name in names {
+        tasks.push(task(name, format!("{} run {}", manager, name), manager, "package.json"));
+    }
+}
+
+fn deno_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    for source in ["deno.json", "deno.jsonc"] {
+        let Ok(content) = fs::read_to_string(root.join(source)) else {
+            continue;
+        };
+        // Whole-line comments are all deno.jsonc files usually have
+        let json: String = content
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let Ok(config) = serde_json::from_str::<serde_json::Value>(&json) else {
+            continue;
+        };
+
+        if let Some(deno_tasks) = config.get("tasks").and_then(|tasks| tasks.as_object()) {
+            for name in deno_tasks.keys() {
+                tasks.push(task(name, format!("deno task {}", name), "deno", source));
+            }
+        }
+        return;
+    }
+}
+
+fn cargo_tasks(root: &Path, manifest: &str, tasks: &mut Vec<ProjectTask>) {
+    let Ok(content) = fs::read_to_string(root.join(manifest)) else {
+        return;
+    };
+
+    let flag = if manifest == "Cargo.toml" { String::new() } else { format!(" --manifest-path {}", manifest) };
+    // A Tauri app's crate is run through `tauri dev` rather than on its own
+    let binary = manifest == "Cargo.toml"
+        && toml_has_section(&content, "package")
+        && (root.join("src/main.rs").exists() || content.lines().any(|line| line.trim() == "[[bin]]"));
+    if binary {
+        tasks.push(task("run", "cargo run".to_string(), "cargo", manifest));
+    }
+    tasks.push(task("build", format!("cargo build{}", flag), "cargo", manifest));
+    tasks.push(task("test", format!("cargo test{}", flag), "cargo", manifest));
+}
+
+fn tauri_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    let Some(source) = ["src-tauri/tauri.conf.json", "src-tauri/tauri.conf.json5", "src-tauri/Tauri.toml"]
+        .into_iter()
+        .find(|source| root.join(source).exists())
+    else {
+        return;
+    };
+
+    // The CLI from package.json when the project has it, otherwise the cargo subcommand
+    let has_script = read_package_json(root)
+        .and_then(|package| package.scripts)
+        .is_some_and(|scripts| scripts.contains_key("tauri"));
+    let cli = if has_script { format!("{} run tauri", package_manager(root)) } else { "cargo tauri".to_string() };
+    tasks.push(task("tauri dev", format!("{} dev", cli), "tauri", source));
+    tasks.push(task("tauri build", format!("{} build", cli), "tauri", source));
+}
+
+fn python_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    let Ok(content) = fs::read_to_string(root.join("pyproject.toml")) else {
+        return;
+    };
+
+    let (kind, runner) = if root.join("uv.lock").exists() {
+        ("uv", "uv run ")
+    } else if root.join("poetry.lock").exists() || toml_has_section(&content, "tool.poetry") {
+        ("poetry", "poetry run ")
+    } else {
+        ("python", "")
+    };
+
+    for section in ["project.scripts", "tool.poetry.scripts"] {
+        for name in toml_keys(&content, section) {
+            if !tasks.iter().any(|task| task.source == "pyproject.toml" && task.name == name) {
+                tasks.push(task(&name, format!("{}{}", runner, name), kind, "pyproject.toml"));
+            }
+        }
+    }
+
+    if toml_has_section(&content, "tool.pytest") || root.join("tests").is_dir() {
+        let pytest = if runner.is_empty() { "python -m pytest".to_string() } else { format!("{}pytest", runner) };
+        tasks.push(task("test", pytest, kind, "pyproject.toml"));
+    }
+}
+
+fn go_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    if !root.join("go.mod").exists() {
+        return;
+    }
+
+    if root.join("main.go").exists() {
+        tasks.push(task("run", "go run .".to_string(), "go", "go.mod"));
+    }
+    // Commands laid out as cmd/<name>/main.go
+    if let Ok(entries) = fs::read_dir(root.join("cmd")) {
+        let mut commands: Vec<String> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        commands.sort();
+        for name in commands {
+            tasks.push(task(&format!("run {}", name), format!("go run ./cmd/{}", name), "go", "go.mod"));
+        }
+    }
+    tasks.push(task("build", "go build ./...".to_string(), "go", "go.mod"));
+    tasks.push(task("test", "go test ./...".to_string(), "go", "go.mod"));
+}
+
+fn make_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    // make itself only reads the first of these it finds
+    if let Some((source, content)) = MAKEFILES
+        .iter()
+        .find_map(|file| fs::read_to_string(root.join(file)).ok().map(|content| (file, content)))
+    {
+        for target in make_targets(&content) {
+            tasks.push(task(&target, format!("make {}", target), "make", source));
+        }
+    }
+}
+
+fn just_tasks(root: &Path, tasks: &mut Vec<ProjectTask>) {
+    if let Some((source, content)) = JUSTFILES
+        .iter()
+        .find_map(|file| fs::read_to_string(root.join(file)).ok().map(|content| (file, content)))
+    {
+        for recipe in just_recipes(&content) {
+            tasks.push(task(&recipe, format!("just {}", recipe), "just", source));
+        }
+    }
+}
+
+/// Every task the project's build files define
+pub(crate) fn detect_tasks(root: &Path) -> Vec<ProjectTask> {
+    let mut tasks = Vec::new();
+    script_file_tasks(root, &mut tasks);
+    package_json_tasks(root, &mut tasks);
+    deno_tasks(root, &mut tasks);
+    tauri_tasks(root, &mut tasks);
+    cargo_tasks(root, "Cargo.toml", &mut tasks);
+    cargo_tasks(root, "src-tauri/Cargo.toml", &mut tasks);
+    python_tasks(root, &mut tasks);
+    go_tasks(root, &mut tasks);
+    make_tasks(root, &mut tasks);
+    just_tasks(root, &mut tasks);
+    tasks
+}
+
+#[tauri::command]
+pub async fn detect_project_tasks(project_path: String) -> Result<Vec<ProjectTask>, String> {
+    let root = Path::new(&project_path);
+    if !root.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+    Ok(detect_tasks(root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(tasks: &[ProjectTask], source: &str) -> Vec<String> {
+        tasks.iter().filter(|task| task.source == source).map(|task| task.command.clone()).collect()
+    }
+
+    #[test]
+    fn test_tasks_from_each_ecosystem() {
+        let dir = std::env::temp_dir().join(format!("vibe-hub-tasks-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("src-tauri")).unwrap();
+        fs::create_dir_all(dir.join("cmd/api")).unwrap();
+        fs::write(dir.join("package.json"), r#"{"scripts": {"dev": "vite", "tauri": "tauri"}}"#).unwrap();
+        fs::write(dir.join("pnpm-lock.yaml"), "").unwrap();
+        fs::write(dir.join("src-tauri/tauri.conf.json"), "{}").unwrap();
+        fs::write(dir.join("src-tauri/Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+        fs::write(dir.join("pyproject.toml"), "[project]\nname = \"x\"\n\n[project.scripts]\nserve = \"x.app:main\"\n\n[tool.pytest.ini_options]\n").unwrap();
+        fs::write(dir.join("uv.lock"), "").unwrap();
+        fs::write(dir.join("go.mod"), "module example.com/x\n").unwrap();
+        fs::write(dir.join("deno.jsonc"), "{\n  // Tasks\n  \"tasks\": {\"start\": \"deno run main.ts\"}\n}").unwrap();
+        fs::write(dir.join("dev.sh"), "").unwrap();
+
+        let tasks = detect_tasks(&dir);
+        assert_eq!(package_manager(&dir), "pnpm");
+        assert_eq!(commands(&tasks, "dev.sh"), ["./dev.sh"]);
+        assert_eq!(commands(&tasks, "package.json"), ["pnpm run dev", "pnpm run tauri"]);
+        assert_eq!(commands(&tasks, "src-tauri/tauri.conf.json"), ["pnpm run tauri dev", "pnpm run tauri build"]);
+        assert_eq!(
+            commands(&tasks, "src-tauri/Cargo.toml"),
+            ["cargo build --manifest-path src-tauri/Cargo.toml", "cargo test --manifest-path src-tauri/Cargo.toml"]
+        );
+        assert_eq!(commands(&tasks, "pyproject.toml"), ["uv run serve", "uv run pytest"]);
+        assert_eq!(commands(&tasks, "go.mod"), ["go run ./cmd/api", "go build ./...", "go test ./..."]);
+        assert_eq!(commands(&tasks, "deno.jsonc"), ["deno task start"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_make_targets_and_just_recipes() {
+        let makefile = "CC := gcc\nFLAGS = -a:b\n.PHONY: build test\n\nbuild: deps\n\tgo build\n\ntest lint:\n\t./check\n%.o: %.c\n\ttouch $@\n";
+        assert_eq!(make_targets(makefile), ["build", "test", "lint"]);
+
+        let justfile = "set shell := [\"bash\", \"-c\"]\nalias b := build\n\n# Build it\nbuild:\n    cargo build\n\n[private]\n@serve port=\"8000\": build\n    ./serve\n\n_helper:\n    true\n\n[no-cd]\n@lint *args:\n    cargo clippy {{args}}\n";
+        assert_eq!(just_recipes(justfile), ["build", "lint"]);
+    }
+}
@@ -0,0 +1,144 @@
+// Terminal emulator profiles for Linux
+// Launching Claude Code, opening a terminal and running scripts all go through the profile
+// chosen in settings, or the first installed built-in profile when none is chosen.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::AppHandle;
+
+use crate::commands::settings::read_settings_file;
+use crate::models::{Settings, TerminalProfile};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn profile(name: &str, command_args: &[&str], open_args: &[&str]) -> TerminalProfile {
+    TerminalProfile {
+        name: name.to_string(),
+        program: name.to_string(),
+        command_args: args(command_args),
+        open_args: args(open_args),
+    }
+}
+
+/// Profiles for common emulators, in the order auto-detection tries them
+pub(crate) fn builtin_profiles() -> Vec<TerminalProfile> {
+    vec![
+        profile("gnome-terminal", &["--working-directory={cwd}", "--", "bash", "-c", "{command}"], &["--working-directory={cwd}"]),
+        profile("konsole", &["--workdir", "{cwd}", "-e", "bash", "-c", "{command}"], &["--workdir", "{cwd}"]),
+        profile("xfce4-terminal", &["--working-directory={cwd}", "-x", "bash", "-c", "{command}"], &["--working-directory={cwd}"]),
+        profile("alacritty", &["--working-directory", "{cwd}", "-e", "bash", "-c", "{command}"], &["--working-directory", "{cwd}"]),
+        profile("kitty", &["--directory", "{cwd}", "bash", "-c", "{command}"], &["--directory", "{cwd}"]),
+        profile("wezterm", &["start", "--cwd", "{cwd}", "--", "bash", "-c", "{command}"], &["start", "--cwd", "{cwd}"]),
+        profile("foot", &["--working-directory={cwd}", "bash", "-c", "{command}"], &["--working-directory={cwd}"]),
+        // xterm has no working directory flag; it starts in the spawn's current directory
+        profile("xterm", &["-e", "bash", "-c", "{command}"], &[]),
+    ]
+}
+
+/// Find an executable by name on PATH (or check it directly if it's a path)
+fn find_executable(program: &str) -> Option<PathBuf> {
+    let candidate = Path::new(program);
+    if candidate.components().count() > 1 {
+        return candidate.is_file().then(|| candidate.to_path_buf());
+    }
+
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(program))
+        .find(|p| p.is_file())
+}
+
+/// Built-in profiles whose emulator is installed
+pub(crate) fn detect_profiles() -> Vec<TerminalProfile> {
+    builtin_profiles()
+        .into_iter()
+        .filter(|p| find_executable(&p.program).is_some())
+        .collect()
+}
+
+/// The profile to launch with: the one named in settings (custom profiles take precedence
+/// over built-in ones with the same name), otherwise the first installed built-in
+pub(crate) fn resolve_profile(settings: &Settings, installed: &[TerminalProfile]) -> Result<TerminalProfile, String> {
+    match settings.terminal_profile.as_deref().filter(|name| !name.is_empty()) {
+        Some(name) => settings
+            .terminal_profiles
+            .iter()
+            .chain(builtin_profiles().iter())
+            .find(|p| p.name == name)
+            .cloned()
+            .ok_or_else(|| format!("Terminal profile '{}' not found", name)),
+        None => installed.first().cloned().ok_or_else(|| {
+            "No supported terminal emulator found. Install one or add a terminal profile in Settings.".to_string()
+        }),
+    }
+}
+
+/// Fill in a profile's argument template
+fn expand_args(template: &[String], cwd: &str, command: &str) -> Vec<String> {
+    template
+        .iter()
+        .map(|arg| arg.replace("{cwd}", cwd).replace("{command}", command))
+        .collect()
+}
+
+/// Open the configured terminal in `cwd`, running a bash script if one is given
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn spawn_terminal(app: &AppHandle, cwd: &str, command: Option<&str>) -> Result<(), String> {
+    let settings = read_settings_file(app)?;
+    let profile = resolve_profile(&settings, &detect_profiles())?;
+
+    let template = match command {
+        Some(_) => &profile.command_args,
+        None => &profile.open_args,
+    };
+
+    Command::new(&profile.program)
+        .args(expand_args(template, cwd, command.unwrap_or_default()))
+        .current_dir(cwd)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to launch {}: {}", profile.name, e))
+}
+
+/// Terminal profiles that can be chosen in settings: installed built-ins, then custom ones
+#[tauri::command]
+pub async fn get_terminal_profiles(app: AppHandle) -> Result<Vec<TerminalProfile>, String> {
+    let settings = read_settings_file(&app)?;
+    let mut profiles = detect_profiles();
+    profiles.retain(|p| !settings.terminal_profiles.iter().any(|custom| custom.name == p.name));
+    profiles.extend(settings.terminal_profiles);
+    Ok(profiles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_args_are_expanded_without_a_shell() {
+        let kitty = builtin_profiles().into_iter().find(|p| p.name == "kitty").unwrap();
+        let args = expand_args(&kitty.command_args, "/home/me/my project", "echo 'hi' && claude");
+        assert_eq!(args, vec!["--directory", "/home/me/my project", "bash", "-c", "echo 'hi' && claude"]);
+    }
+
+    #[test]
+    fn test_resolve_profile_prefers_settings_then_installed() {
+        let installed = vec![profile("foot", &[], &[])];
+        let mut settings = Settings::default();
+        assert_eq!(resolve_profile(&settings, &installed).unwrap().name, "foot");
+        assert!(resolve_profile(&settings, &[]).is_err());
+
+        settings.terminal_profile = Some("konsole".to_string());
+        assert_eq!(resolve_profile(&settings, &installed).unwrap().program, "konsole");
+
+        let mut custom = profile("konsole", &["-e", "{command}"], &[]);
+        custom.program = "/opt/konsole/bin/konsole".to_string();
+        settings.terminal_profiles.push(custom.clone());
+        assert_eq!(resolve_profile(&settings, &installed).unwrap(), custom);
+
+        settings.terminal_profile = Some("missing".to_string());
+        assert!(resolve_profile(&settings, &installed).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+// tmux-backed Claude Code sessions
+// With tmux mode on, each project gets a detached tmux session named vibe-<project>-<hash>.
+// Claude runs inside it, so it survives closing the terminal, and its state and output
+// can be read back with has-session / capture-pane.
+
+use std::path::Path;
+use std::process::{Command, Output};
+use tauri::AppHandle;
+
+use crate::commands::settings::read_settings_file;
+use crate::commands::terminal;
+use crate::utils::path_hash;
+
+/// Lines of scrollback returned by capture_pane when the caller doesn't ask for a number
+const DEFAULT_CAPTURE_LINES: usize = 200;
+
+/// tmux session name for a project (tmux doesn't allow '.' or ':' in names)
+///
+/// The folder name is followed by a hash of the full path, so projects in folders of the same
+/// name elsewhere get sessions of their own.
+pub(crate) fn session_name(project_path: &str) -> String {
+    let project_name = Path::new(project_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("project");
+
+    let sanitized: String = project_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let full_path = std::fs::canonicalize(project_path)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|_| project_path.to_string());
+    // Cut down to six hex digits
+    let hash = path_hash(&full_path);
+    format!("vibe-{}-{:06x}", sanitized, hash & 0xffffff)
+}
+
+// "=" makes tmux match the session name exactly instead of by prefix
+fn session_target(name: &str) -> String {
+    format!("={}", name)
+}
+
+fn window_target(name: &str) -> String {
+    format!("={}:", name)
+}
+
+fn tmux(args: &[&str]) -> Result<Output, String> {
+    Command::new("tmux")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run tmux: {}. Make sure tmux is installed.", e))
+}
+
+fn tmux_ok(args: &[&str]) -> Result<(), String> {
+    let output = tmux(args)?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!("tmux {} failed: {}", args[0], String::from_utf8_lossy(&output.stderr).trim()))
+    }
+}
+
+/// Whether tmux mode is turned on in settings
+pub(crate) fn enabled(app: &AppHandle) -> bool {
+    read_settings_file(app).map(|s| s.tmux_sessions).unwrap_or(false)
+}
+
+pub(crate) fn has_session(name: &str) -> bool {
+    tmux(&["has-session", "-t", &session_target(name)])
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Run a bash script in the project's tmux session, in a new window if the session exists
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn run_in_session(name: &str, cwd: &str, script: &str) -> Result<(), String> {
+    if has_session(name) {
+        tmux_ok(&["new-window", "-t", &window_target(name), "-c", cwd, "bash", "-c", script])
+    } else {
+        tmux_ok(&["new-session", "-d", "-s", name, "-c", cwd, "bash", "-c", script])
+    }
+}
+
+/// The last `lines` lines of the session's active pane
+pub(crate) fn capture_pane(name: &str, lines: usize) -> Result<String, String> {
+    let start = format!("-{}", lines);
+    let output = tmux(&["capture-pane", "-p", "-J", "-t", &window_target(name), "-S", &start])?;
+    if !output.status.success() {
+        return Err(format!("tmux capture-pane failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim_end().to_string())
+}
+
+/// Open the configured terminal attached to the session
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn attach_in_terminal(app: &AppHandle, name: &str, cwd: &str) -> Result<(), String> {
+    terminal::spawn_terminal(app, cwd, Some(&format!("exec tmux attach-session -t '{}'", session_target(name))))
+}
+
+/// Recent output of a project's tmux session
+#[tauri::command]
+pub async fn get_session_output(project_path: String, lines: Option<usize>) -> Result<String, String> {
+    let name = session_name(&project_path);
+    if !has_session(&name) {
+        return Err(format!("No tmux session '{}' is running", name));
+    }
+
+    capture_pane(&name, lines.unwrap_or(DEFAULT_CAPTURE_LINES))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_name_is_safe_for_tmux() {
+        let name = session_name("/home/me/projects/vibe-hub");
+        assert!(name.starts_with("vibe-vibe-hub-") && name.len() == "vibe-vibe-hub-".len() + 6);
+        assert!(session_name("/home/me/projects/my.app: v2").starts_with("vibe-my-app--v2-"));
+        assert_eq!(session_name("/home/me/projects/vibe-hub"), name);
+    }
+
+    #[test]
+    fn test_projects_with_the_same_folder_name_get_different_sessions() {
+        let work = session_name("/home/me/work/app");
+        let personal = session_name("/home/me/personal/app");
+        assert!(work.starts_with("vibe-app-") && personal.starts_with("vibe-app-"));
+        assert_ne!(work, personal);
+    }
+}
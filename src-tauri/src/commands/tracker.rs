@@ -0,0 +1,334 @@
+// Issue tracker backends behind a common trait
+// GitHub (including Enterprise), GitLab and Gitea are chosen from the project's metadata
+// ("Tracker:" line), host entries in settings, or the remote URL's host name.
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::commands::github::{self, IssueListing, IssuePatch, RemoteComment, RemoteIssue};
+use crate::commands::gitea::GiteaTracker;
+use crate::commands::gitlab::GitlabTracker;
+use crate::models::settings::Settings;
+use crate::models::TrackerKind;
+
+/// Operations the sync needs from an issue tracker
+///
+/// Every backend returns GitHub-shaped RemoteIssue/RemoteComment values, with states
+/// normalised to "open" and "closed".
+#[async_trait]
+pub(crate) trait IssueTracker: Send + Sync {
+    fn kind(&self) -> TrackerKind;
+
+    /// List issues with the given state ("open", "closed" or "all"), following pagination.
+    /// With `since`, only issues updated at or after that time are returned.
+    async fn list_issues(&self, state: &str, since: Option<&str>) -> Result<IssueListing, String>;
+
+    async fn get_issue(&self, number: u64) -> Result<RemoteIssue, String>;
+
+    async fn create_issue(&self, title: &str, body: &str, labels: &[String]) -> Result<RemoteIssue, String>;
+
+    /// Apply a patch to an issue and return the updated issue
+    async fn update_issue(&self, number: u64, patch: &IssuePatch) -> Result<RemoteIssue, String>;
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>, String>;
+
+    async fn post_comment(&self, number: u64, body: &str) -> Result<RemoteComment, String>;
+}
+
+/// Where a repository lives, parsed from its remote or web URL
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RepoLocation {
+    pub host: String,  // Includes the port, if any
+    pub owner: String, // May contain '/' for GitLab subgroups
+    pub repo: String,
+}
+
+impl RepoLocation {
+    /// Browser URL for the repository
+    pub fn web_url(&self) -> String {
+        format!("https://{}/{}/{}", self.host, self.owner, self.repo)
+    }
+}
+
+/// Parse a repository URL on any host
+/// Supports formats like:
+/// - https://host/owner/repo(.git)
+/// - ssh://git@host:2222/owner/repo.git
+/// - git@host:group/subgroup/repo.git
+pub(crate) fn parse_remote_url(url: &str) -> Result<RepoLocation, String> {
+    let url = url.trim();
+
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .or_else(|| url.strip_prefix("ssh://"))
+    {
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        // Drop any user info ("git@", "user:token@")
+        (authority.rsplit('@').next().unwrap_or(authority), path)
+    } else if let Some((user_host, path)) = url.split_once(':') {
+        // scp-like syntax: user@host:path
+        match user_host.split_once('@') {
+            Some((_, host)) => (host, path),
+            None => return Err(format!("Invalid repository URL format: {}", url)),
+        }
+    } else {
+        return Err(format!("Invalid repository URL format: {}", url));
+    };
+
+    // ssh:// URLs carry the port in the host, https:// ones keep it for the web URL
+    let host = if url.starts_with("ssh://") {
+        host.split(':').next().unwrap_or(host)
+    } else {
+        host
+    };
+
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+    if host.is_empty() || parts.len() < 2 {
+        return Err(format!("Invalid repository URL format: {}", url));
+    }
+
+    Ok(RepoLocation {
+        host: host.to_lowercase(),
+        owner: parts[..parts.len() - 1].join("/"),
+        repo: parts[parts.len() - 1].to_string(),
+    })
+}
+
+/// Convert a git remote (SSH or HTTPS) into a browser URL, leaving unparseable URLs as they are
+pub(crate) fn normalize_remote_url(url: &str) -> String {
+    parse_remote_url(url)
+        .map(|location| location.web_url())
+        .unwrap_or_else(|_| url.trim().trim_end_matches(".git").to_string())
+}
+
+/// Guess the tracker from a well-known host name
+pub(crate) fn kind_from_host(host: &str) -> Option<TrackerKind> {
+    let host = host.to_lowercase();
+    if host == "github.com" || host.starts_with("github.") {
+        Some(TrackerKind::Github)
+    } else if host.contains("gitlab") {
+        Some(TrackerKind::Gitlab)
+    } else if host.contains("gitea") || host.contains("forgejo") || host == "codeberg.org" {
+        Some(TrackerKind::Gitea)
+    } else {
+        None
+    }
+}
+
+/// Tracker set explicitly in .vibe/metadata.md ("Tracker: gitlab")
+fn kind_from_metadata(project_path: &Path) -> Option<TrackerKind> {
+    let content = fs::read_to_string(project_path.join(".vibe").join("metadata.md")).ok()?;
+
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("Tracker:")?.trim().to_lowercase();
+        serde_json::from_value(serde_json::Value::String(value)).ok()
+    })
+}
+
+/// Standard API base URL for a tracker kind on a host
+fn default_api_url(kind: TrackerKind, host: &str) -> String {
+    match kind {
+        TrackerKind::Github if host == "github.com" => "https://api.github.com".to_string(),
+        TrackerKind::Github => format!("https://{}/api/v3", host),
+        TrackerKind::Gitlab => format!("https://{}/api/v4", host),
+        TrackerKind::Gitea => format!("https://{}/api/v1", host),
+    }
+}
+
+/// Resolved connection details for a repository's tracker
+pub(crate) struct TrackerConfig {
+    pub kind: TrackerKind,
+    pub api_url: String,
+    pub token: String,
+    pub location: RepoLocation,
+}
+
+/// Which tracker a repository URL uses: the project's "Tracker:" line, then the hosts in
+/// settings, then the host name itself
+pub(crate) fn resolve_tracker_kind(
+    settings: &Settings,
+    project_path: Option<&Path>,
+    url: &str,
+) -> Result<TrackerKind, String> {
+    let location = parse_remote_url(url)?;
+    let host_config = settings
+        .tracker_hosts
+        .iter()
+        .find(|h| h.host.trim().eq_ignore_ascii_case(&location.host));
+
+    project_path
+        .and_then(kind_from_metadata)
+        .or_else(|| host_config.map(|h| h.kind))
+        .or_else(|| kind_from_host(&location.host))
+        .ok_or_else(|| format!(
+            "Unknown issue tracker for {}. Add the host in Settings or set \"Tracker:\" in .vibe/metadata.md.",
+            location.host
+        ))
+}
+
+/// Work out which tracker a repository URL uses and how to reach it
+pub(crate) fn resolve_tracker(
+    settings: &Settings,
+    project_path: Option<&Path>,
+    url: &str,
+) -> Result<TrackerConfig, String> {
+    let kind = resolve_tracker_kind(settings, project_path, url)?;
+    let location = parse_remote_url(url)?;
+    let host_config = settings
+        .tracker_hosts
+        .iter()
+        .find(|h| h.host.trim().eq_ignore_ascii_case(&location.host));
+
+    let api_url = host_config
+        .and_then(|h| h.api_url.clone())
+        .unwrap_or_else(|| default_api_url(kind, &location.host));
+
+    let token = host_config
+        .and_then(|h| h.token.clone())
+        .or_else(|| if location.host == "github.com" { settings.github_token.clone() } else { None })
+        .ok_or_else(|| match location.host.as_str() {
+            "github.com" => "GitHub token not configured. Please add your token in Settings.".to_string(),
+            host => format!("No token configured for {}. Please add one in Settings.", host),
+        })?;
+
+    Ok(TrackerConfig { kind, api_url, token, location })
+}
+
+/// Build the tracker for a repository URL from settings and (optionally) the project's metadata
+pub(crate) fn tracker_for(
+    settings: &Settings,
+    project_path: Option<&Path>,
+    url: &str,
+) -> Result<Box<dyn IssueTracker>, String> {
+    let TrackerConfig { kind, api_url, token, location } = resolve_tracker(settings, project_path, url)?;
+
+    Ok(match kind {
+        TrackerKind::Github => Box::new(github::GithubTracker::new(&api_url, &token, &location.owner, &location.repo)?),
+        TrackerKind::Gitlab => Box::new(GitlabTracker::new(&api_url, &token, &location.owner, &location.repo)),
+        TrackerKind::Gitea => Box::new(GiteaTracker::new(&api_url, &token, &location.owner, &location.repo)),
+    })
+}
+
+/// Build the tracker for a repository from the app settings, checking that the integration is enabled
+pub(crate) fn tracker_from_settings(
+    app: &AppHandle,
+    project_path: Option<&Path>,
+    url: &str,
+) -> Result<Box<dyn IssueTracker>, String> {
+    let settings = github::read_settings(app)?;
+
+    // Check if issue tracker integration is enabled globally
+    if !settings.github_integration_enabled {
+        return Err("GitHub integration is disabled in settings. Please enable it in Settings.".to_string());
+    }
+
+    tracker_for(&settings, project_path, url)
+}
+
+/// Decode a JSON response from a REST tracker, turning HTTP errors into messages
+pub(crate) async fn read_json<T: DeserializeOwned>(
+    response: reqwest::Result<reqwest::Response>,
+    action: &str,
+) -> Result<T, String> {
+    let response = response.map_err(|e| format!("Failed to {}: {}", action, e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Failed to {}: HTTP {} {}", action, status, body.trim()));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to {}: {}", action, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::tracker::TrackerHost;
+
+    #[test]
+    fn test_parse_remote_url_formats() {
+        let gitlab = parse_remote_url("git@gitlab.example.com:team/backend/api.git").unwrap();
+        assert_eq!(gitlab.host, "gitlab.example.com");
+        assert_eq!(gitlab.owner, "team/backend");
+        assert_eq!(gitlab.repo, "api");
+
+        let gitea = parse_remote_url("ssh://git@git.home.lan:2222/me/notes.git").unwrap();
+        assert_eq!(gitea.web_url(), "https://git.home.lan/me/notes");
+
+        let https = parse_remote_url("https://git.home.lan:3000/me/notes/").unwrap();
+        assert_eq!(https.host, "git.home.lan:3000");
+
+        assert!(parse_remote_url("not-a-url").is_err());
+        assert!(parse_remote_url("https://github.com/owner").is_err());
+    }
+
+    #[test]
+    fn test_parse_remote_url_github_https() {
+        let location = parse_remote_url("https://github.com/owner/repo").unwrap();
+        assert_eq!(location.owner, "owner");
+        assert_eq!(location.repo, "repo");
+    }
+
+    #[test]
+    fn test_parse_remote_url_github_https_with_git() {
+        let location = parse_remote_url("https://github.com/owner/repo.git").unwrap();
+        assert_eq!(location.owner, "owner");
+        assert_eq!(location.repo, "repo");
+    }
+
+    #[test]
+    fn test_parse_remote_url_github_ssh() {
+        let location = parse_remote_url("git@github.com:owner/repo.git").unwrap();
+        assert_eq!(location.owner, "owner");
+        assert_eq!(location.repo, "repo");
+    }
+
+    #[test]
+    fn test_parse_remote_url_github_invalid() {
+        let result = parse_remote_url("not-a-github-url");
+        assert!(result.is_err());
+    }
+
+    // Octocrab needs a Tokio runtime to build its client
+    #[tokio::test]
+    async fn test_tracker_kind_from_settings_or_host() {
+        let mut settings = Settings {
+            github_token: Some("gh".to_string()),
+            ..Default::default()
+        };
+        settings.tracker_hosts.push(TrackerHost {
+            host: "git.home.lan".to_string(),
+            kind: TrackerKind::Gitea,
+            api_url: None,
+            token: Some("tea".to_string()),
+        });
+
+        let kind = |url: &str| tracker_for(&settings, None, url).map(|t| t.kind());
+        assert_eq!(kind("https://github.com/owner/repo"), Ok(TrackerKind::Github));
+        assert_eq!(kind("git@git.home.lan:me/notes.git"), Ok(TrackerKind::Gitea));
+        // Known host but no token for it
+        assert!(kind("https://gitlab.com/group/project").is_err());
+        assert!(kind("https://code.internal/owner/repo").is_err());
+    }
+
+    #[test]
+    fn test_tracker_kind_from_metadata() {
+        let project = std::env::temp_dir().join(format!("vibe-hub-tracker-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(project.join(".vibe")).unwrap();
+        fs::write(project.join(".vibe").join("metadata.md"), "Name: Test\nTracker: GitLab\n").unwrap();
+
+        assert_eq!(kind_from_metadata(&project), Some(TrackerKind::Gitlab));
+        // The metadata line makes an unrecognised self-hosted remote syncable, token or not
+        assert_eq!(resolve_tracker_kind(&Settings::default(), Some(&project), "https://code.internal/owner/repo"), Ok(TrackerKind::Gitlab));
+        assert_eq!(default_api_url(TrackerKind::Github, "ghe.corp"), "https://ghe.corp/api/v3");
+    }
+}
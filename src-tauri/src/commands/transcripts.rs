@@ -0,0 +1,194 @@
+// Session transcripts
+// Every launched session (terminal or embedded) gets a record in .vibe/sessions/<id>.json:
+// the prompt and what it was for when it starts, then the outcome, git HEAD and the commits
+// made in the meantime when it ends. Headless runs keep their own records (see runs.rs).
+
+use chrono::Utc;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::projects::git_command;
+use crate::models::{SessionCommit, SessionContext, SessionTranscript};
+
+const SESSIONS_DIR: &str = "sessions";
+// Most commits recorded for one session
+const MAX_COMMITS: usize = 200;
+
+fn sessions_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".vibe").join(SESSIONS_DIR)
+}
+
+fn transcript_path(project_path: &Path, id: &str) -> PathBuf {
+    sessions_dir(project_path).join(format!("{}.json", id))
+}
+
+fn write_transcript(transcript: &SessionTranscript) -> Result<(), String> {
+    let dir = sessions_dir(Path::new(&transcript.project_path));
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create sessions directory: {}", e))?;
+    let content = serde_json::to_string_pretty(transcript)
+        .map_err(|e| format!("Failed to serialize session transcript: {}", e))?;
+    fs::write(dir.join(format!("{}.json", transcript.id)), content)
+        .map_err(|e| format!("Failed to write session transcript: {}", e))
+}
+
+fn read_transcript(path: &Path) -> Result<SessionTranscript, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read session transcript: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse session transcript: {}", e))
+}
+
+/// The checked-out commit, or None outside a repository or before the first commit
+fn git_head(dir: &Path) -> Option<String> {
+    let output = git_command().args(["rev-parse", "HEAD"]).current_dir(dir).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Commits reachable from `after` but not `before`, oldest first
+fn commits_between(dir: &Path, before: Option<&str>, after: &str) -> Vec<SessionCommit> {
+    let range = match before {
+        Some(before) => format!("{}..{}", before, after),
+        None => after.to_string(),
+    };
+    let max = format!("--max-count={}", MAX_COMMITS);
+    let Ok(output) = git_command()
+        .args(["log", "--reverse", &max, "--format=%H%x09%s", &range])
+        .current_dir(dir)
+        .output()
+    else {
+        return Vec::new();
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(hash, summary)| SessionCommit { hash: hash.to_string(), summary: summary.to_string() })
+        .collect()
+}
+
+/// Record a session as it starts
+pub(crate) fn begin_transcript(
+    id: &str,
+    project_path: &str,
+    work_dir: &str,
+    kind: &str,
+    prompt: &str,
+    context: SessionContext,
+) -> Result<(), String> {
+    write_transcript(&SessionTranscript {
+        id: id.to_string(),
+        project_path: project_path.to_string(),
+        work_dir: work_dir.to_string(),
+        kind: kind.to_string(),
+        prompt: prompt.to_string(),
+        context,
+        started_at: Utc::now().to_rfc3339(),
+        ended_at: None,
+        outcome: None,
+        exit_code: None,
+        head_before: git_head(Path::new(work_dir)),
+        head_after: None,
+        commits: Vec::new(),
+    })
+}
+
+/// Complete a session's record with its outcome and the commits it made
+pub(crate) fn finish_transcript(project_path: &str, id: &str, outcome: &str, exit_code: Option<i32>) -> Result<(), String> {
+    let mut transcript = read_transcript(&transcript_path(Path::new(project_path), id))?;
+    let work_dir = PathBuf::from(&transcript.work_dir);
+
+    transcript.ended_at = Some(Utc::now().to_rfc3339());
+    transcript.outcome = Some(outcome.to_string());
+    transcript.exit_code = exit_code;
+    transcript.head_after = git_head(&work_dir);
+    if let Some(after) = &transcript.head_after {
+        if transcript.head_before.as_ref() != Some(after) {
+            transcript.commits = commits_between(&work_dir, transcript.head_before.as_deref(), after);
+        }
+    }
+    write_transcript(&transcript)
+}
+
+fn read_all(project_path: &Path) -> Result<Vec<SessionTranscript>, String> {
+    let dir = sessions_dir(project_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read sessions directory: {}", e))?;
+    let mut transcripts: Vec<SessionTranscript> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| read_transcript(&path).ok())
+        .collect();
+    transcripts.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(transcripts)
+}
+
+/// A project's sessions, most recent first
+#[tauri::command]
+pub async fn list_sessions(project_path: String) -> Result<Vec<SessionTranscript>, String> {
+    read_all(Path::new(&project_path))
+}
+
+/// Sessions launched for an issue, most recent first
+#[tauri::command]
+pub async fn list_issue_sessions(project_path: String, issue_id: String) -> Result<Vec<SessionTranscript>, String> {
+    let mut transcripts = read_all(Path::new(&project_path))?;
+    transcripts.retain(|t| t.context.issue_ids.contains(&issue_id));
+    Ok(transcripts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = git_command()
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[tokio::test]
+    async fn test_transcript_records_commits_made_during_the_session() {
+        let project = std::env::temp_dir().join(format!("vibe-hub-transcript-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&project).unwrap();
+        git(&project, &["init", "-q"]);
+        git(&project, &["commit", "-q", "--allow-empty", "-m", "Initial commit"]);
+        let path = project.to_string_lossy().to_string();
+
+        let context = SessionContext {
+            prompt_name: Some("issueFix".to_string()),
+            issue_ids: vec!["issue-1".to_string()],
+            feedback_ids: Vec::new(),
+        };
+        begin_transcript("s1", &path, &path, "terminal", "Fix issue-1", context).unwrap();
+        begin_transcript("s2", &path, &path, "embedded", "", SessionContext::default()).unwrap();
+
+        git(&project, &["commit", "-q", "--allow-empty", "-m", "Fix login"]);
+        git(&project, &["commit", "-q", "--allow-empty", "-m", "Add test"]);
+        finish_transcript(&path, "s1", "exited", Some(0)).unwrap();
+
+        let issue_sessions = list_issue_sessions(path.clone(), "issue-1".to_string()).await.unwrap();
+        assert_eq!(issue_sessions.len(), 1);
+        let transcript = &issue_sessions[0];
+        assert_eq!(transcript.prompt, "Fix issue-1");
+        assert_eq!(transcript.exit_code, Some(0));
+        let summaries: Vec<&str> = transcript.commits.iter().map(|c| c.summary.as_str()).collect();
+        assert_eq!(summaries, vec!["Fix login", "Add test"]);
+        assert_eq!(transcript.head_after.as_deref(), Some(transcript.commits[1].hash.as_str()));
+
+        assert_eq!(list_sessions(path).await.unwrap().len(), 2);
+        fs::remove_dir_all(&project).unwrap();
+    }
+}
@@ -0,0 +1,457 @@
+// Work queue for running issues through the agent unattended
+// Jobs (project + issue + prompt) are kept in work-queue.json in the app data directory. A
+// background dispatcher starts queued jobs as headless runs, at most one per project and
+// `max_concurrent` overall, retrying failures up to each job's attempt limit. Every job
+// change is emitted as a "work-queue-progress" event.
+
+use chrono::Utc;
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::{Mutex, Notify};
+use uuid::Uuid;
+
+use crate::commands::issues::read_issues_file;
+use crate::commands::runs::{self, RunLimits};
+use crate::models::{WorkJob, WorkQueue};
+
+const QUEUE_FILE: &str = "work-queue.json";
+const DEFAULT_PROMPT: &str = "issueFix";
+
+/// How often the dispatcher checks for work without being woken
+const DISPATCH_INTERVAL_SECS: u64 = 30;
+
+lazy_static! {
+    // Serialises queue file updates between commands, the dispatcher and running jobs
+    static ref QUEUE_LOCK: Mutex<()> = Mutex::new(());
+    // Wakes the dispatcher when jobs are queued or finish
+    static ref WAKE: Notify = Notify::new();
+    // Cancellation signals for running jobs, by job id
+    static ref CANCELS: std::sync::Mutex<HashMap<String, Arc<Notify>>> = std::sync::Mutex::new(HashMap::new());
+}
+
+fn queue_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get data directory: {}", e))
+        .map(|path| path.join(QUEUE_FILE))
+}
+
+fn read_queue(path: &Path) -> Result<WorkQueue, String> {
+    if !path.exists() {
+        return Ok(WorkQueue::default());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read work queue: {}", e))?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse work queue: {}", e))
+}
+
+fn write_queue(path: &Path, queue: &WorkQueue) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string_pretty(queue)
+        .map_err(|e| format!("Failed to serialize work queue: {}", e))?;
+
+    fs::write(path, content)
+        .map_err(|e| format!("Failed to write work queue: {}", e))
+}
+
+fn is_finished(job: &WorkJob) -> bool {
+    matches!(job.status.as_str(), "succeeded" | "failed" | "cancelled")
+}
+
+/// Indexes of queued jobs to start now, oldest first
+fn jobs_to_start(queue: &WorkQueue) -> Vec<usize> {
+    if queue.paused {
+        return Vec::new();
+    }
+
+    let mut busy_projects: HashSet<&str> = queue
+        .jobs
+        .iter()
+        .filter(|job| job.status == "running")
+        .map(|job| job.project_path.as_str())
+        .collect();
+    let free_slots = queue.max_concurrent.max(1).saturating_sub(busy_projects.len());
+
+    let mut selected = Vec::new();
+    for (index, job) in queue.jobs.iter().enumerate() {
+        if selected.len() == free_slots {
+            break;
+        }
+        if job.status == "queued" && busy_projects.insert(job.project_path.as_str()) {
+            selected.push(index);
+        }
+    }
+    selected
+}
+
+/// Record the outcome of an attempt: a failure goes back in the queue while attempts remain
+fn finish_attempt(job: &mut WorkJob, run_id: Option<String>, outcome: Result<(), String>, cancelled: bool) {
+    job.run_id = run_id.or(job.run_id.take());
+    match outcome {
+        Ok(()) => {
+            job.status = "succeeded".to_string();
+            job.last_error = None;
+        }
+        Err(error) => {
+            job.status = if cancelled {
+                "cancelled"
+            } else if job.attempts < job.max_attempts {
+                "queued"
+            } else {
+                "failed"
+            }
+            .to_string();
+            job.last_error = Some(error);
+        }
+    }
+    if is_finished(job) {
+        job.finished_at = Some(Utc::now().to_rfc3339());
+    }
+}
+
+/// Apply a change to one job, save the queue and tell the frontend
+async fn update_job(app: &AppHandle, job_id: &str, f: impl FnOnce(&mut WorkJob)) -> Result<Option<WorkJob>, String> {
+    let path = queue_path(app)?;
+    let _guard = QUEUE_LOCK.lock().await;
+    let mut queue = read_queue(&path)?;
+
+    let Some(job) = queue.jobs.iter_mut().find(|job| job.id == job_id) else {
+        return Ok(None);
+    };
+    f(job);
+    let job = job.clone();
+    write_queue(&path, &queue)?;
+
+    let _ = app.emit("work-queue-progress", &job);
+    Ok(Some(job))
+}
+
+async fn run_job(app: AppHandle, job: WorkJob, cancel: Arc<Notify>) {
+    let limits = RunLimits {
+        timeout: Some(Duration::from_secs(job.timeout_secs.max(60))),
+        cancel: Some(cancel),
+    };
+
+    let (run_id, outcome) = match runs::prepare_run(&job.project_path, &job.issue_id, Some(job.prompt_name.clone()), None) {
+        Ok(prepared) => {
+            let run = runs::complete_run(&app, prepared, limits).await;
+            let outcome = match run.error {
+                Some(error) => Err(error),
+                None => Ok(()),
+            };
+            (Some(run.id), outcome)
+        }
+        Err(e) => (None, Err(e)),
+    };
+
+    let cancelled = CANCELS.lock().unwrap().remove(&job.id).is_none();
+    log::info!(
+        project = job.project_path.as_str();
+        "Work queue job {} (issue {}) finished attempt {}: {:?}", job.id, job.issue_id, job.attempts, outcome
+    );
+    if let Err(e) = update_job(&app, &job.id, |job| finish_attempt(job, run_id, outcome, cancelled)).await {
+        log::error!("Failed to update work queue: {}", e);
+    }
+    WAKE.notify_one();
+}
+
+/// Start whatever queued jobs fit in the free slots
+async fn dispatch(app: &AppHandle) -> Result<(), String> {
+    let path = queue_path(app)?;
+    let started: Vec<WorkJob> = {
+        let _guard = QUEUE_LOCK.lock().await;
+        let mut queue = read_queue(&path)?;
+        let indexes = jobs_to_start(&queue);
+        if indexes.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let started = indexes
+            .into_iter()
+            .map(|index| {
+                let job = &mut queue.jobs[index];
+                job.status = "running".to_string();
+                job.attempts += 1;
+                job.started_at = Some(now.clone());
+                job.clone()
+            })
+            .collect();
+        write_queue(&path, &queue)?;
+        started
+    };
+
+    for job in started {
+        let _ = app.emit("work-queue-progress", &job);
+        let cancel = Arc::new(Notify::new());
+        CANCELS.lock().unwrap().insert(job.id.clone(), cancel.clone());
+        tauri::async_runtime::spawn(run_job(app.clone(), job, cancel));
+    }
+    Ok(())
+}
+
+/// Start the background dispatcher. Jobs left running by a previous session are requeued.
+pub fn start_dispatcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Ok(path) = queue_path(&app) {
+            let _guard = QUEUE_LOCK.lock().await;
+            if let Ok(mut queue) = read_queue(&path) {
+                let mut interrupted = false;
+                for job in queue.jobs.iter_mut().filter(|job| job.status == "running") {
+                    job.status = "queued".to_string();
+                    interrupted = true;
+                }
+                if interrupted {
+                    let _ = write_queue(&path, &queue);
+                }
+            }
+        }
+
+        loop {
+            if let Err(e) = dispatch(&app).await {
+                log::error!("Failed to dispatch jobs: {}", e);
+            }
+            let _ = tokio::time::timeout(Duration::from_secs(DISPATCH_INTERVAL_SECS), WAKE.notified()).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_work_queue(app: AppHandle) -> Result<WorkQueue, String> {
+    let path = queue_path(&app)?;
+    let _guard = QUEUE_LOCK.lock().await;
+    read_queue(&path)
+}
+
+/// Queue issues for headless runs (all pending and needs-rework issues, by priority, if no
+/// ids are given). Issues that already have an unfinished job are skipped.
+#[tauri::command]
+pub async fn enqueue_issues(
+    app: AppHandle,
+    project_path: String,
+    issue_ids: Option<Vec<String>>,
+    prompt_name: Option<String>,
+) -> Result<Vec<WorkJob>, String> {
+    let mut issues = read_issues_file(Path::new(&project_path))?.issues;
+    let issues: Vec<_> = match issue_ids {
+        Some(ids) => ids
+            .iter()
+            .map(|id| {
+                issues
+                    .iter()
+                    .find(|issue| &issue.id == id)
+                    .cloned()
+                    .ok_or_else(|| format!("Issue {} not found", id))
+            })
+            .collect::<Result<_, _>>()?,
+        None => {
+            issues.retain(|issue| issue.status == "pending" || issue.status == "needs-rework");
+            issues.sort_by_key(|issue| issue.priority);
+            issues
+        }
+    };
+
+    let path = queue_path(&app)?;
+    let added = {
+        let _guard = QUEUE_LOCK.lock().await;
+        let mut queue = read_queue(&path)?;
+        let now = Utc::now().to_rfc3339();
+
+        let mut added = Vec::new();
+        for issue in issues {
+            let already_queued = queue
+                .jobs
+                .iter()
+                .any(|job| job.project_path == project_path && job.issue_id == issue.id && !is_finished(job));
+            if already_queued {
+                continue;
+            }
+
+            let job = WorkJob {
+                id: Uuid::new_v4().to_string(),
+                project_path: project_path.clone(),
+                issue_id: issue.id,
+                issue_title: issue.title,
+                prompt_name: prompt_name.clone().unwrap_or_else(|| DEFAULT_PROMPT.to_string()),
+                status: "queued".to_string(),
+                attempts: 0,
+                max_attempts: queue.max_attempts.max(1),
+                timeout_secs: queue.timeout_secs,
+                created_at: now.clone(),
+                started_at: None,
+                finished_at: None,
+                run_id: None,
+                last_error: None,
+            };
+            queue.jobs.push(job.clone());
+            added.push(job);
+        }
+
+        write_queue(&path, &queue)?;
+        added
+    };
+
+    for job in &added {
+        let _ = app.emit("work-queue-progress", job);
+    }
+    WAKE.notify_one();
+    Ok(added)
+}
+
+async fn set_paused(app: &AppHandle, paused: bool) -> Result<WorkQueue, String> {
+    let path = queue_path(app)?;
+    let queue = {
+        let _guard = QUEUE_LOCK.lock().await;
+        let mut queue = read_queue(&path)?;
+        queue.paused = paused;
+        write_queue(&path, &queue)?;
+        queue
+    };
+    WAKE.notify_one();
+    Ok(queue)
+}
+
+/// Stop starting new jobs; running jobs are left to finish
+#[tauri::command]
+pub async fn pause_work_queue(app: AppHandle) -> Result<WorkQueue, String> {
+    set_paused(&app, true).await
+}
+
+#[tauri::command]
+pub async fn resume_work_queue(app: AppHandle) -> Result<WorkQueue, String> {
+    set_paused(&app, false).await
+}
+
+/// Cancel a queued job, or stop a running one (its issue gets the cancellation as its run error)
+#[tauri::command]
+pub async fn cancel_work_job(app: AppHandle, job_id: String) -> Result<(), String> {
+    if let Some(cancel) = CANCELS.lock().unwrap().remove(&job_id) {
+        cancel.notify_one();
+        return Ok(());
+    }
+
+    update_job(&app, &job_id, |job| {
+        if job.status == "queued" {
+            job.status = "cancelled".to_string();
+            job.finished_at = Some(Utc::now().to_rfc3339());
+        }
+    })
+    .await?
+    .map(|_| ())
+    .ok_or_else(|| "Job not found".to_string())
+}
+
+/// Update the concurrency limit and the defaults for newly queued jobs
+#[tauri::command]
+pub async fn update_work_queue_settings(
+    app: AppHandle,
+    max_concurrent: usize,
+    timeout_secs: u64,
+    max_attempts: u32,
+) -> Result<WorkQueue, String> {
+    if max_concurrent == 0 || max_attempts == 0 || timeout_secs < 60 {
+        return Err("Concurrency and attempts must be at least 1, and the timeout at least a minute".to_string());
+    }
+
+    let path = queue_path(&app)?;
+    let queue = {
+        let _guard = QUEUE_LOCK.lock().await;
+        let mut queue = read_queue(&path)?;
+        queue.max_concurrent = max_concurrent;
+        queue.timeout_secs = timeout_secs;
+        queue.max_attempts = max_attempts;
+        write_queue(&path, &queue)?;
+        queue
+    };
+    WAKE.notify_one();
+    Ok(queue)
+}
+
+/// Remove succeeded, failed and cancelled jobs
+#[tauri::command]
+pub async fn clear_finished_work_jobs(app: AppHandle) -> Result<WorkQueue, String> {
+    let path = queue_path(&app)?;
+    let _guard = QUEUE_LOCK.lock().await;
+    let mut queue = read_queue(&path)?;
+    queue.jobs.retain(|job| !is_finished(job));
+    write_queue(&path, &queue)?;
+    Ok(queue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(project: &str, status: &str) -> WorkJob {
+        WorkJob {
+            id: Uuid::new_v4().to_string(),
+            project_path: project.to_string(),
+            issue_id: "issue".to_string(),
+            issue_title: "Issue".to_string(),
+            prompt_name: DEFAULT_PROMPT.to_string(),
+            status: status.to_string(),
+            attempts: 0,
+            max_attempts: 2,
+            timeout_secs: 600,
+            created_at: Utc::now().to_rfc3339(),
+            started_at: None,
+            finished_at: None,
+            run_id: None,
+            last_error: None,
+        }
+    }
+
+    #[test]
+    fn test_jobs_start_one_per_project_within_the_limit() {
+        let mut queue = WorkQueue {
+            max_concurrent: 2,
+            jobs: vec![
+                job("/a", "running"),
+                job("/a", "queued"),
+                job("/b", "succeeded"),
+                job("/b", "queued"),
+                job("/c", "queued"),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(jobs_to_start(&queue), vec![3]);
+
+        queue.max_concurrent = 5;
+        assert_eq!(jobs_to_start(&queue), vec![3, 4]);
+
+        queue.paused = true;
+        assert!(jobs_to_start(&queue).is_empty());
+    }
+
+    #[test]
+    fn test_failed_attempts_are_retried_until_the_limit() {
+        let mut job = job("/a", "running");
+        job.attempts = 1;
+        finish_attempt(&mut job, Some("run-1".to_string()), Err("timed out".to_string()), false);
+        assert_eq!(job.status, "queued");
+        assert!(job.finished_at.is_none());
+
+        job.attempts = 2;
+        finish_attempt(&mut job, None, Err("exit code 1".to_string()), false);
+        assert_eq!(job.status, "failed");
+        assert_eq!(job.run_id.as_deref(), Some("run-1"));
+        assert_eq!(job.last_error.as_deref(), Some("exit code 1"));
+
+        let mut cancelled = self::job("/a", "running");
+        cancelled.attempts = 1;
+        finish_attempt(&mut cancelled, None, Err("Cancelled".to_string()), true);
+        assert_eq!(cancelled.status, "cancelled");
+    }
+}
@@ -0,0 +1,391 @@
+// Git worktrees for working on issues in isolation
+// Each issue gets its own checkout in .vibe/worktrees/<issue-short-id> on a vibe/<issue-short-id>
+// branch, so agents working on different issues don't touch each other's files. Worktrees are
+// merged back into the branch they were created from, and removed once their issue completes.
+
+use chrono::Utc;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::commands::issues::{read_issues_archive_file, read_issues_file};
+use crate::commands::launcher;
+use crate::commands::projects::git_command;
+use crate::commands::runs::{self, RunLimits};
+use crate::models::{Issue, SessionContext, WorktreeFile, WorktreeInfo, WorktreeRecord};
+
+const WORKTREES_DIR: &str = "worktrees";
+const RECORDS_FILE: &str = "worktrees.json";
+const BRANCH_PREFIX: &str = "vibe/";
+
+fn worktrees_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".vibe").join(WORKTREES_DIR)
+}
+
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let output = git_command()
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(format!("git {} failed: {}", args[0], String::from_utf8_lossy(&output.stderr).trim()))
+    }
+}
+
+/// First eight characters of the issue id, safe for branch and directory names
+fn short_id(issue_id: &str) -> String {
+    issue_id.chars().filter(|c| c.is_ascii_alphanumeric()).take(8).collect()
+}
+
+fn read_records(project_path: &Path) -> Result<WorktreeFile, String> {
+    let path = worktrees_dir(project_path).join(RECORDS_FILE);
+    if !path.exists() {
+        return Ok(WorktreeFile::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read worktrees file: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse worktrees file: {}", e))
+}
+
+fn write_records(project_path: &Path, file: &WorktreeFile) -> Result<(), String> {
+    let dir = worktrees_dir(project_path);
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create worktrees directory: {}", e))?;
+    let content = serde_json::to_string_pretty(file)
+        .map_err(|e| format!("Failed to serialize worktrees: {}", e))?;
+    fs::write(dir.join(RECORDS_FILE), content)
+        .map_err(|e| format!("Failed to write worktrees file: {}", e))
+}
+
+fn find_record(project_path: &Path, issue_id: &str) -> Result<WorktreeRecord, String> {
+    read_records(project_path)?
+        .worktrees
+        .into_iter()
+        .find(|w| w.issue_id == issue_id)
+        .ok_or_else(|| "This issue has no worktree".to_string())
+}
+
+fn find_issue(project_path: &Path, issue_id: &str) -> Result<Issue, String> {
+    read_issues_file(project_path)?
+        .issues
+        .into_iter()
+        .find(|i| i.id == issue_id)
+        .ok_or_else(|| "Issue not found".to_string())
+}
+
+/// The issue's worktree, created on its vibe/<short-id> branch if it doesn't exist yet
+pub(crate) fn ensure_worktree(project_path: &Path, issue_id: &str) -> Result<WorktreeRecord, String> {
+    let mut records = read_records(project_path)?;
+    if let Some(record) = records.worktrees.iter().find(|w| w.issue_id == issue_id) {
+        if Path::new(&record.path).exists() {
+            return Ok(record.clone());
+        }
+    }
+    records.worktrees.retain(|w| w.issue_id != issue_id);
+
+    let short = short_id(issue_id);
+    if short.is_empty() {
+        return Err("Issue id can't be used as a branch name".to_string());
+    }
+    let branch = format!("{}{}", BRANCH_PREFIX, short);
+    let dir = worktrees_dir(project_path);
+    let path = dir.join(&short);
+
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create worktrees directory: {}", e))?;
+    // Keep the worktrees out of the project's own git status
+    fs::write(dir.join(".gitignore"), "*\n")
+        .map_err(|e| format!("Failed to write worktrees .gitignore: {}", e))?;
+
+    let base_branch = git(project_path, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+    if base_branch == "HEAD" {
+        return Err("The project is in detached HEAD state; check out a branch first".to_string());
+    }
+
+    // Forget worktrees whose directories were deleted by hand
+    git(project_path, &["worktree", "prune"])?;
+    let path_arg = path.to_string_lossy().to_string();
+    let branch_exists = git(project_path, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)]).is_ok();
+    if branch_exists {
+        git(project_path, &["worktree", "add", &path_arg, &branch])?;
+    } else {
+        git(project_path, &["worktree", "add", "-b", &branch, &path_arg, "HEAD"])?;
+    }
+    log::info!(project = &*project_path.to_string_lossy(); "Created worktree {} on {} for issue {}", path_arg, branch, issue_id);
+
+    let record = WorktreeRecord {
+        issue_id: issue_id.to_string(),
+        branch,
+        path: path_arg,
+        base_branch,
+        created_at: Utc::now().to_rfc3339(),
+        merged_at: None,
+    };
+    records.worktrees.push(record.clone());
+    write_records(project_path, &records)?;
+    Ok(record)
+}
+
+fn worktree_info(project_path: &Path, record: WorktreeRecord) -> WorktreeInfo {
+    let path = Path::new(&record.path);
+    let exists = path.exists();
+    let dirty = exists
+        && git(path, &["status", "--porcelain"])
+            .map(|status| !status.is_empty())
+            .unwrap_or(false);
+    let ahead = git(project_path, &["rev-list", "--count", &format!("{}..{}", record.base_branch, record.branch)])
+        .ok()
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(0);
+
+    WorktreeInfo { record, exists, dirty, ahead }
+}
+
+/// Commit anything the agent left uncommitted in the worktree
+fn commit_pending_changes(record: &WorktreeRecord, message: &str) -> Result<(), String> {
+    let path = Path::new(&record.path);
+    if git(path, &["status", "--porcelain"])?.is_empty() {
+        return Ok(());
+    }
+    git(path, &["add", "-A"])?;
+    git(path, &["commit", "-m", message]).map(|_| ())
+}
+
+/// Merge an issue's branch into its base branch, which must be checked out in the project
+fn merge(project_path: &Path, record: &WorktreeRecord, strategy: &str, message: &str) -> Result<(), String> {
+    let current = git(project_path, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+    if current != record.base_branch {
+        return Err(format!(
+            "Check out {} in the project before merging (currently on {})",
+            record.base_branch, current
+        ));
+    }
+
+    commit_pending_changes(record, message)?;
+    let ahead = git(project_path, &["rev-list", "--count", &format!("{}..{}", record.base_branch, record.branch)])?;
+    if ahead == "0" {
+        return Err(format!("{} has no changes to merge", record.branch));
+    }
+
+    match strategy {
+        "fast-forward" => git(project_path, &["merge", "--ff-only", &record.branch]).map(|_| ()),
+        "squash" => {
+            if let Err(e) = git(project_path, &["merge", "--squash", &record.branch]) {
+                // A squash leaves no MERGE_HEAD, so `merge --abort` can't undo a conflict
+                let _ = git(project_path, &["reset", "--merge"]);
+                return Err(e);
+            }
+            git(project_path, &["commit", "-m", message]).map(|_| ())
+        }
+        _ => Err(format!("Unknown merge strategy '{}'", strategy)),
+    }
+}
+
+fn remove(project_path: &Path, record: &WorktreeRecord, delete_branch: bool) -> Result<(), String> {
+    if Path::new(&record.path).exists() {
+        git(project_path, &["worktree", "remove", "--force", &record.path])?;
+    }
+    git(project_path, &["worktree", "prune"])?;
+    if delete_branch {
+        // The branch may already be gone; that's fine
+        let _ = git(project_path, &["branch", "-D", &record.branch]);
+    }
+
+    let mut records = read_records(project_path)?;
+    records.worktrees.retain(|w| w.issue_id != record.issue_id);
+    write_records(project_path, &records)?;
+    log::info!(project = &*project_path.to_string_lossy(); "Removed worktree {} for issue {}", record.path, record.issue_id);
+    Ok(())
+}
+
+/// Remove a completed issue's worktree and branch if its work was merged back.
+/// Unmerged worktrees are kept so nothing is lost. Returns whether one was removed.
+pub(crate) fn cleanup_issue_worktree(project_path: &Path, issue_id: &str) -> Result<bool, String> {
+    let record = match read_records(project_path)?.worktrees.into_iter().find(|w| w.issue_id == issue_id) {
+        Some(record) if record.merged_at.is_some() => record,
+        _ => return Ok(false),
+    };
+    remove(project_path, &record, true)?;
+    Ok(true)
+}
+
+/// Create a worktree for an issue and start the agent in it, headless or in a terminal
+#[tauri::command]
+pub async fn start_issue_in_worktree(
+    app: AppHandle,
+    project_path: String,
+    issue_id: String,
+    headless: bool,
+) -> Result<WorktreeInfo, String> {
+    let path = Path::new(&project_path);
+    let record = ensure_worktree(path, &issue_id)?;
+
+    if headless {
+        let prepared = runs::prepare_run(&project_path, &issue_id, None, Some(&record))?;
+        let run_app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            runs::complete_run(&run_app, prepared, RunLimits::default()).await;
+        });
+    } else {
+        let issue = find_issue(path, &issue_id)?;
+        let prompt = runs::issue_prompt(&project_path, &issue, "issueFix", &[], Some(&record))?;
+        let context = SessionContext {
+            prompt_name: Some("issueFix".to_string()),
+            issue_ids: vec![issue_id.clone()],
+            feedback_ids: Vec::new(),
+        };
+        launcher::launch_session(app, project_path.clone(), record.path.clone(), prompt, context).await?;
+    }
+
+    Ok(worktree_info(path, record))
+}
+
+#[tauri::command]
+pub async fn create_worktree(project_path: String, issue_id: String) -> Result<WorktreeInfo, String> {
+    let path = Path::new(&project_path);
+    let record = ensure_worktree(path, &issue_id)?;
+    Ok(worktree_info(path, record))
+}
+
+#[tauri::command]
+pub async fn list_worktrees(project_path: String) -> Result<Vec<WorktreeInfo>, String> {
+    let path = Path::new(&project_path);
+    Ok(read_records(path)?
+        .worktrees
+        .into_iter()
+        .map(|record| worktree_info(path, record))
+        .collect())
+}
+
+/// Open an issue's worktree in a terminal, VS Code or the file explorer
+#[tauri::command]
+pub async fn open_worktree(app: AppHandle, project_path: String, issue_id: String, target: String) -> Result<(), String> {
+    let record = find_record(Path::new(&project_path), &issue_id)?;
+    match target.as_str() {
+        "terminal" => launcher::open_in_terminal(app, record.path).await,
+        "vscode" => launcher::open_in_vscode(record.path).await,
+        "explorer" => launcher::open_in_explorer(record.path).await,
+        _ => Err(format!("Unknown target '{}'", target)),
+    }
+}
+
+/// Merge an issue's branch back with "fast-forward" or "squash"
+#[tauri::command]
+pub async fn merge_worktree(project_path: String, issue_id: String, strategy: String) -> Result<WorktreeInfo, String> {
+    let path = Path::new(&project_path);
+    let mut record = find_record(path, &issue_id)?;
+    let message = find_issue(path, &issue_id)
+        .or_else(|_| {
+            read_issues_archive_file(path)?
+                .issues
+                .into_iter()
+                .find(|i| i.id == issue_id)
+                .ok_or_else(|| "Issue not found".to_string())
+        })
+        .map(|issue| issue.title)
+        .unwrap_or_else(|_| format!("Work on {}", record.branch));
+
+    merge(path, &record, &strategy, &message)?;
+
+    record.merged_at = Some(Utc::now().to_rfc3339());
+    let mut records = read_records(path)?;
+    if let Some(existing) = records.worktrees.iter_mut().find(|w| w.issue_id == issue_id) {
+        *existing = record.clone();
+    }
+    write_records(path, &records)?;
+    Ok(worktree_info(path, record))
+}
+
+#[tauri::command]
+pub async fn remove_worktree(project_path: String, issue_id: String, delete_branch: bool) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let record = find_record(path, &issue_id)?;
+    remove(path, &record, delete_branch)
+}
+
+/// Remove merged worktrees of completed issues. Returns the issue ids cleaned up.
+#[tauri::command]
+pub async fn cleanup_worktrees(project_path: String) -> Result<Vec<String>, String> {
+    let path = Path::new(&project_path);
+    let completed: Vec<String> = read_issues_archive_file(path)?
+        .issues
+        .into_iter()
+        .map(|issue| issue.id)
+        .collect();
+
+    let mut removed = Vec::new();
+    for record in read_records(path)?.worktrees {
+        if completed.contains(&record.issue_id) && cleanup_issue_worktree(path, &record.issue_id)? {
+            removed.push(record.issue_id);
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temp_repo() -> PathBuf {
+        let repo = std::env::temp_dir().join(format!("vibe-hub-worktree-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]).unwrap();
+        git(&repo, &["config", "user.email", "test@example.com"]).unwrap();
+        git(&repo, &["config", "user.name", "Test"]).unwrap();
+        fs::write(repo.join("README.md"), "hello\n").unwrap();
+        git(&repo, &["add", "-A"]).unwrap();
+        git(&repo, &["commit", "-q", "-m", "Initial commit"]).unwrap();
+        repo
+    }
+
+    #[test]
+    fn test_worktree_is_squash_merged_and_cleaned_up() {
+        let repo = temp_repo();
+        let issue_id = "3f2a9c1e-aaaa-bbbb-cccc-000000000000";
+
+        let record = ensure_worktree(&repo, issue_id).unwrap();
+        assert_eq!(record.branch, "vibe/3f2a9c1e");
+        assert_eq!(record.base_branch, "main");
+        assert!(git(&repo, &["status", "--porcelain"]).unwrap().is_empty());
+
+        // The agent leaves its change uncommitted; merging commits it first
+        fs::write(Path::new(&record.path).join("fix.txt"), "fixed\n").unwrap();
+        assert!(worktree_info(&repo, record.clone()).dirty);
+        merge(&repo, &record, "squash", "Fix the bug").unwrap();
+        assert_eq!(fs::read_to_string(repo.join("fix.txt")).unwrap(), "fixed\n");
+        assert_eq!(git(&repo, &["log", "-1", "--format=%s"]).unwrap(), "Fix the bug");
+
+        // Not merged yet as far as the records know, so cleanup keeps it
+        assert!(!cleanup_issue_worktree(&repo, issue_id).unwrap());
+
+        let mut records = read_records(&repo).unwrap();
+        records.worktrees[0].merged_at = Some(Utc::now().to_rfc3339());
+        write_records(&repo, &records).unwrap();
+        assert!(cleanup_issue_worktree(&repo, issue_id).unwrap());
+        assert!(!Path::new(&record.path).exists());
+        assert!(git(&repo, &["rev-parse", "--verify", "--quiet", "refs/heads/vibe/3f2a9c1e"]).is_err());
+    }
+
+    #[test]
+    fn test_conflicting_squash_merge_leaves_the_project_clean() {
+        let repo = temp_repo();
+        let record = ensure_worktree(&repo, "7b1d0e2f-aaaa-bbbb-cccc-000000000000").unwrap();
+
+        // Both sides change the same line
+        fs::write(Path::new(&record.path).join("README.md"), "from the agent\n").unwrap();
+        fs::write(repo.join("README.md"), "from the user\n").unwrap();
+        git(&repo, &["commit", "-q", "-am", "Edit README"]).unwrap();
+
+        assert!(merge(&repo, &record, "squash", "Fix the bug").is_err());
+        assert!(git(&repo, &["status", "--porcelain"]).unwrap().is_empty());
+        assert_eq!(fs::read_to_string(repo.join("README.md")).unwrap(), "from the user\n");
+        assert_eq!(git(&repo, &["log", "-1", "--format=%s"]).unwrap(), "Edit README");
+    }
+}
@@ -2,15 +2,35 @@ mod models;
 mod commands;
 mod utils;
 
-use commands::{settings, projects, feedback, issues, launcher, prompts, npm, watcher, github};
+use commands::{settings, projects, feedback, issues, launcher, logs, sessions, transcripts, terminal, tmux, agents, spawn, pty, runs, work_queue, worktrees, prompts, npm, tasks, dev_servers, ports, watcher, github, github_sync, github_mapping, github_board, outbound, repo_status, credentials};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Agent sessions launched in a terminal run through this executable (see commands/spawn.rs)
+    if let Some(exit_code) = spawn::run_helper_from_args() {
+        std::process::exit(exit_code);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_autostart::init(tauri_plugin_autostart::MacosLauncher::LaunchAgent, Some(vec![])))
+        .setup(|app| {
+            // Everything after this logs to the app log directory
+            if let Err(e) = logs::init(app.handle()) {
+                eprintln!("[logs] {}", e);
+            }
+            // Move tokens left in settings.json by older versions into the credential store
+            if let Err(e) = credentials::migrate_plaintext_tokens(app.handle()) {
+                log::error!("Failed to migrate plaintext tokens: {}", e);
+            }
+            // Retry queued GitHub operations in the background
+            outbound::start_retry_loop(app.handle().clone());
+            // Work through queued headless agent jobs
+            work_queue::start_dispatcher(app.handle().clone());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Settings commands
             settings::get_settings,
@@ -20,6 +40,9 @@ pub fn run() {
             settings::enable_autostart,
             settings::disable_autostart,
             settings::is_autostart_enabled,
+            credentials::get_credential_status,
+            credentials::unlock_credentials,
+            credentials::set_credential_token,
             // Project commands
             projects::scan_projects,
             projects::get_project_detail,
@@ -51,6 +74,7 @@ pub fn run() {
             feedback::get_archived_feedback,
             feedback::move_feedback_to_archive,
             feedback::archive_and_close_github_feedback,
+            feedback::add_feedback_comment,
             // Issue commands
             issues::get_issues,
             issues::add_issue,
@@ -58,6 +82,7 @@ pub fn run() {
             issues::delete_issue,
             issues::migrate_completed_feedback_to_issues,
             issues::migrate_completed_issues,
+            issues::add_issue_comment,
             // Launcher commands
             launcher::launch_claude_code,
             launcher::open_in_explorer,
@@ -67,13 +92,62 @@ pub fn run() {
             launcher::open_in_fork,
             launcher::get_debug_log_path,
             launcher::log_debug,
-            launcher::get_session_status,
+            logs::query_logs,
+            sessions::get_session_status,
+            sessions::get_session_history,
+            transcripts::list_sessions,
+            transcripts::list_issue_sessions,
+            terminal::get_terminal_profiles,
+            tmux::get_session_output,
             launcher::focus_claude_terminal,
+            // Agent profile commands
+            agents::get_agent_profiles,
+            agents::get_project_agent_profile,
+            agents::set_project_agent_profile,
+            // Embedded terminal commands
+            pty::spawn_pty,
+            pty::write_pty,
+            pty::resize_pty,
+            pty::kill_pty,
+            pty::close_pty,
+            pty::list_pty_sessions,
+            pty::get_pty_screen,
+            // Headless agent run commands
+            runs::start_headless_run,
+            runs::get_agent_runs,
+            runs::get_run_transcript,
+            // Work queue commands
+            work_queue::get_work_queue,
+            work_queue::enqueue_issues,
+            work_queue::pause_work_queue,
+            work_queue::resume_work_queue,
+            work_queue::cancel_work_job,
+            work_queue::update_work_queue_settings,
+            work_queue::clear_finished_work_jobs,
+            // Worktree commands
+            worktrees::start_issue_in_worktree,
+            worktrees::create_worktree,
+            worktrees::list_worktrees,
+            worktrees::open_worktree,
+            worktrees::merge_worktree,
+            worktrees::remove_worktree,
+            worktrees::cleanup_worktrees,
             // Prompts commands
             prompts::get_prompt,
             // NPM commands
             npm::detect_npm_scripts,
             npm::run_npm_script,
+            tasks::detect_project_tasks,
+            // Dev server commands
+            dev_servers::start_dev_server,
+            dev_servers::stop_dev_server,
+            dev_servers::restart_dev_server,
+            dev_servers::get_dev_server_status,
+            dev_servers::get_dev_server_output,
+            dev_servers::list_dev_servers,
+            ports::get_project_port,
+            ports::set_project_port,
+            ports::get_port_status,
             // Watcher commands
             watcher::check_project_files_modified,
             watcher::get_project_files_timestamps,
@@ -81,7 +155,26 @@ pub fn run() {
             github::fetch_github_issues,
             github::sync_all_github_issues,
             github::close_github_issue,
+            github::create_github_issue,
+            github_sync::sync_github_issues,
+            repo_status::fetch_repo_status,
+            repo_status::get_repo_status,
+            github_mapping::get_github_mapping_rules,
+            github_mapping::update_github_mapping_rules,
+            github_board::get_github_board_config,
+            github_board::update_github_board_config,
+            github_board::sync_github_board,
+            // Outbound queue commands
+            outbound::get_outbound_queue,
+            outbound::retry_outbound_queue,
+            outbound::clear_outbound_queue,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            // Dev servers are children of the app and shouldn't outlive it
+            if let tauri::RunEvent::Exit = event {
+                dev_servers::stop_all();
+            }
+        });
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How to start an agent CLI, for interactive sessions and for headless runs
+///
+/// The prompt mode says how the prompt reaches the agent: "argument" replaces "{prompt}" in
+/// the arguments, "file" writes it to a file and replaces "{prompt_file}" with its path, and
+/// "stdin" pipes it in. Without a placeholder the prompt (or file) becomes the last argument.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentProfile {
+    pub name: String,
+    pub program: String, // Executable name or path
+    pub args: Vec<String>, // Interactive sessions, e.g. ["{prompt}"]
+    pub prompt_mode: String, // "argument", "file" or "stdin"
+    #[serde(default)]
+    pub headless_args: Vec<String>, // Headless runs, which must print the result and exit
+    #[serde(default = "default_headless_prompt_mode")]
+    pub headless_prompt_mode: String,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+}
+
+fn default_headless_prompt_mode() -> String {
+    "stdin".to_string()
+}
+
+/// The agent profile a project uses instead of the default from settings (.vibe/agent.json)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectAgentConfig {
+    pub profile: Option<String>,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A comment in the discussion thread of a feedback item or issue.
+/// Comments written locally have no `githubCommentId` until they are pushed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Comment {
+    pub id: String,
+    pub author: String,
+    pub body: String,
+    #[serde(alias = "created_at", rename = "createdAt")]
+    pub created_at: String,
+    #[serde(
+        alias = "githubCommentId",
+        rename = "githubCommentId",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_comment_id: Option<u64>,
+}
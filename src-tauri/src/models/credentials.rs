@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Where tokens are stored and which ones are set, without exposing the tokens themselves
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus {
+    pub backend: String, // "keyring" | "encrypted-file"
+    pub locked: bool, // Encrypted file store waiting for its passphrase
+    pub has_encrypted_file: bool, // A passphrase has been set before
+    pub github_token_set: bool,
+    pub tracker_token_hosts: Vec<String>, // Tracker hosts with a stored token
+}
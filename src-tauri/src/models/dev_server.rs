@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+/// A dev server Vibe Hub started and supervises for a project; also the payload of the
+/// "dev-server-changed" event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevServerInfo {
+    pub project_path: String,
+    pub script_name: String,
+    pub script_type: String, // "npm", "bat", "sh" or "command" (the project's dev command)
+    pub command: String,     // For display
+    pub port: Option<u16>,   // The port it was told to use
+    pub pid: Option<u32>,
+    pub status: String,      // "running" | "exited" | "stopped"
+    pub exit_code: Option<i32>,
+    pub started_at: String,
+    pub url: Option<String>, // Where it serves, once it has said so in its output
+    pub ready: bool,         // Printed its URL or finished starting up
+}
+
+/// A line of dev server output; also the payload of the "dev-server-output" event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevServerLine {
+    pub project_path: String,
+    pub stream: String, // "stdout" | "stderr"
+    pub text: String,   // With terminal escape codes removed
+    pub error: bool,    // Looks like an error message
+    pub timestamp: String,
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use super::comment::Comment;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FeedbackItem {
@@ -45,6 +46,45 @@ pub struct FeedbackItem {
         default
     )]
     pub github_issue_url: Option<String>,
+    #[serde(
+        alias = "updatedAt",
+        rename = "updatedAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub comments: Option<Vec<Comment>>,
+    #[serde(
+        alias = "githubSyncedAt",
+        rename = "githubSyncedAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_synced_at: Option<String>,
+    #[serde(
+        alias = "githubUpdatedAt",
+        rename = "githubUpdatedAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_updated_at: Option<String>,
+    #[serde(
+        alias = "githubDiscussionNumber",
+        rename = "githubDiscussionNumber",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_discussion_number: Option<u64>,
+    #[serde(
+        alias = "githubDiscussionUrl",
+        rename = "githubDiscussionUrl",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_discussion_url: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -57,6 +97,7 @@ pub struct NewFeedbackItem {
     pub related_issue_id: Option<String>,
     pub github_issue_number: Option<u64>,
     pub github_issue_url: Option<String>,
+    pub labels: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -70,6 +111,7 @@ pub struct UpdateFeedbackItem {
     pub related_issue_id: Option<String>,
     pub github_issue_number: Option<u64>,
     pub github_issue_url: Option<String>,
+    pub labels: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use super::comment::Comment;
 
 fn default_complexity() -> u8 {
     3 // Default to "Moderate" complexity
@@ -64,6 +65,38 @@ pub struct Issue {
         default
     )]
     pub github_issue_url: Option<String>,
+    #[serde(
+        alias = "updatedAt",
+        rename = "updatedAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub comments: Option<Vec<Comment>>,
+    #[serde(
+        alias = "githubSyncedAt",
+        rename = "githubSyncedAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_synced_at: Option<String>,
+    #[serde(
+        alias = "githubUpdatedAt",
+        rename = "githubUpdatedAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub github_updated_at: Option<String>,
+    #[serde(
+        alias = "lastRunError",
+        rename = "lastRunError",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub last_run_error: Option<String>, // Why the last headless agent run failed
 }
 
 #[derive(Debug, Deserialize)]
@@ -80,6 +113,7 @@ pub struct NewIssue {
     pub github_issue_number: Option<u64>,
     #[serde(alias = "githubIssueUrl")]
     pub github_issue_url: Option<String>,
+    pub labels: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -106,6 +140,7 @@ pub struct UpdateIssue {
     pub github_issue_number: Option<u64>,
     #[serde(alias = "githubIssueUrl")]
     pub github_issue_url: Option<String>,
+    pub labels: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// One line of the app log (JSON lines in the app log directory)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub timestamp: String, // RFC 3339, UTC
+    pub level: String, // "ERROR" | "WARN" | "INFO" | "DEBUG" | "TRACE"
+    pub module: String, // e.g. "runs", "github_sync", "frontend"
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>, // Project path the entry is about
+}
@@ -2,8 +2,44 @@ pub mod project;
 pub mod feedback;
 pub mod issue;
 pub mod settings;
+pub mod comment;
+pub mod sync;
+pub mod tracker;
+pub mod outbound;
+pub mod repo_status;
+pub mod credentials;
+pub mod session;
+pub mod terminal;
+pub mod pty;
+pub mod run;
+pub mod work_queue;
+pub mod worktree;
+pub mod agent;
+pub mod spawn;
+pub mod logs;
+pub mod dev_server;
+pub mod port;
+pub mod task;
 
 pub use project::Project;
 pub use feedback::{FeedbackItem, FeedbackFile, NewFeedbackItem, UpdateFeedbackItem};
 pub use issue::{Issue, IssueFile, NewIssue, UpdateIssue};
 pub use settings::Settings;
+pub use comment::Comment;
+pub use sync::{GithubBoardConfig, GithubMappingRules, GithubSyncState, ProjectSyncResult, SyncChange, SyncReport, SyncSummary};
+pub use tracker::TrackerKind;
+pub use outbound::{OutboundAction, OutboundOperation, OutboundQueue};
+pub use repo_status::{CiStatus, PullRequestSummary, RepoStatus};
+pub use credentials::CredentialStatus;
+pub use session::{SessionCommit, SessionContext, SessionHistory, SessionInfo, SessionRecord, SessionTranscript};
+pub use terminal::TerminalProfile;
+pub use pty::{PtyExit, PtyScreen, PtySessionInfo};
+pub use run::AgentRun;
+pub use work_queue::{WorkJob, WorkQueue};
+pub use worktree::{WorktreeFile, WorktreeInfo, WorktreeRecord};
+pub use agent::{AgentProfile, ProjectAgentConfig};
+pub use spawn::SpawnSpec;
+pub use logs::LogEntry;
+pub use dev_server::{DevServerInfo, DevServerLine};
+pub use port::{PortOwner, PortRegistry, PortStatus};
+pub use task::ProjectTask;
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// A change to push to an issue tracker
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum OutboundAction {
+    Close {
+        issue_number: u64,
+        comment: Option<String>,
+    },
+    Comment {
+        issue_number: u64,
+        body: String,
+        /// Local comment to link to the posted one
+        local_id: Option<String>,
+    },
+    Labels {
+        issue_number: u64,
+        labels: Vec<String>,
+    },
+    Create {
+        title: String,
+        body: String,
+        labels: Vec<String>,
+        /// Local feedback/issue to link to the created issue
+        local_id: Option<String>,
+    },
+}
+
+impl OutboundAction {
+    /// Whether two actions change the same thing, so only the newer one needs to stay queued
+    pub fn same_target(&self, other: &OutboundAction) -> bool {
+        use OutboundAction::*;
+        match (self, other) {
+            (Close { issue_number: a, .. }, Close { issue_number: b, .. }) => a == b,
+            (Labels { issue_number: a, .. }, Labels { issue_number: b, .. }) => a == b,
+            (Comment { local_id: Some(a), .. }, Comment { local_id: Some(b), .. }) => a == b,
+            (Create { local_id: Some(a), .. }, Create { local_id: Some(b), .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// An outbound operation waiting to be (re)tried
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundOperation {
+    pub id: String,
+    pub project_path: Option<String>,
+    pub repo_url: String,
+    pub action: OutboundAction,
+    pub status: String, // "pending" | "failed" - failed operations are only retried on request
+    pub attempts: u32,
+    pub created_at: String,
+    pub last_attempt_at: Option<String>,
+    pub next_attempt_at: String,
+    pub last_error: Option<String>,
+}
+
+/// Persistent queue stored in the app data directory (outbound-queue.json)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OutboundQueue {
+    pub operations: Vec<OutboundOperation>,
+}
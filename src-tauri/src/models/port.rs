@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Dev server ports by project path, stored in ports.json in the app data directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortRegistry {
+    #[serde(default)]
+    pub ports: BTreeMap<String, u16>,
+}
+
+/// A process listening on a port
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortOwner {
+    pub pid: u32,
+    pub name: String,
+    pub project_path: Option<String>, // Set when it's a dev server Vibe Hub started
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortStatus {
+    pub port: u16,
+    pub available: bool,
+    pub owner: Option<PortOwner>, // Who holds it, if that could be found out
+}
@@ -26,6 +26,9 @@ pub struct Project {
     pub has_git_repo: bool,
     pub dev_command: Option<String>, // Custom dev command (overrides auto-detection)
     pub build_command: Option<String>, // Custom build command (overrides auto-detection)
+    pub open_pull_requests: Option<usize>, // From the cached GitHub status, None if never fetched
+    pub ci_status: Option<String>, // Default-branch CI: "success", "failure", "pending", ...
+    pub repo_status_fetched_at: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+/// A command running in a pseudo-terminal owned by Vibe Hub
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PtySessionInfo {
+    pub id: String,
+    pub project_path: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub pid: Option<u32>,
+    pub cols: u16,
+    pub rows: u16,
+    pub status: String, // "running" | "exited"
+    pub exit_code: Option<u32>,
+    pub started_at: String,
+}
+
+/// The rendered screen of a PTY session, sent with every "pty-output" event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PtyScreen {
+    pub id: String,
+    pub lines: Vec<String>,
+    pub cursor_row: u16,
+    pub cursor_col: u16,
+    pub hide_cursor: bool,
+}
+
+/// Payload of the "pty-exit" event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PtyExit {
+    pub id: String,
+    pub project_path: String,
+    pub exit_code: Option<u32>,
+}
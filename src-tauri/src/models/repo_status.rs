@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// An open pull request on the project's GitHub repository
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestSummary {
+    pub number: u64,
+    pub title: String,
+    pub author: Option<String>,
+    pub url: String,
+    pub draft: bool,
+    pub review_state: String, // "approved" | "changes_requested" | "commented" | "pending"
+    pub mergeable: Option<bool>, // None while GitHub is still computing it
+    pub updated_at: String,
+}
+
+/// Latest CI result for the default branch, from workflow runs or check suites
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CiStatus {
+    pub branch: String,
+    pub status: String, // "queued" | "in_progress" | "completed"
+    pub conclusion: Option<String>, // "success", "failure", "cancelled", ... once completed
+    pub name: Option<String>, // Workflow name
+    pub url: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl CiStatus {
+    /// One-word state for badges: the conclusion once completed, otherwise "pending"
+    pub fn summary(&self) -> String {
+        match (&self.status[..], &self.conclusion) {
+            ("completed", Some(conclusion)) => conclusion.clone(),
+            ("completed", None) => "unknown".to_string(),
+            _ => "pending".to_string(),
+        }
+    }
+}
+
+/// Cached pull request and CI overview, stored in .vibe/github-status.json
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoStatus {
+    pub default_branch: String,
+    pub pull_requests: Vec<PullRequestSummary>,
+    pub ci: Option<CiStatus>, // None if the branch has no runs or checks
+    pub fetched_at: String,
+}
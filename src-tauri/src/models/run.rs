@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A headless agent run working on one issue, stored in .vibe/runs/<id>.json
+/// next to its transcript (<id>.log)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentRun {
+    pub id: String,
+    pub project_path: String,
+    pub issue_id: String,
+    pub prompt_name: String,
+    pub work_dir: Option<String>, // Worktree the agent ran in, if not the project itself
+    pub status: String, // "running" | "succeeded" | "failed"
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub exit_code: Option<i32>,
+    pub summary: Option<String>, // The agent's final output on success
+    pub error: Option<String>,
+}
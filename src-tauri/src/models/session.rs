@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+/// A Claude Code session that is currently running for a project
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionInfo {
+    pub id: Option<String>,
+    pub project_path: String,
+    pub status: String, // "running" | "not_started"
+    pub pid: Option<u32>, // Known once the terminal has started the agent
+    pub started_at: Option<String>,
+    pub tmux_session: Option<String>, // Set when Claude runs in a tmux session
+    #[serde(skip)]
+    pub created_at: Option<std::time::Instant>,
+}
+
+impl SessionInfo {
+    pub fn not_started(project_path: String) -> Self {
+        Self {
+            id: None,
+            project_path,
+            status: "not_started".to_string(),
+            pid: None,
+            started_at: None,
+            tmux_session: None,
+            created_at: None,
+        }
+    }
+}
+
+/// A finished session, kept in the project's session history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRecord {
+    pub id: String,
+    pub project_path: String,
+    pub pid: Option<u32>,
+    pub started_at: String,
+    pub ended_at: String,
+    pub duration_secs: u64,
+    pub exit_code: Option<i32>, // None if the terminal was closed before the agent exited
+    pub outcome: String, // "exited" | "closed" | "failed_to_start"
+}
+
+/// Session history stored in .vibe/session-history.json, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SessionHistory {
+    pub sessions: Vec<SessionRecord>,
+}
+
+/// What a session was launched for, sent along with the prompt
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionContext {
+    pub prompt_name: Option<String>, // Template from prompts.json
+    #[serde(default)]
+    pub issue_ids: Vec<String>,
+    #[serde(default)]
+    pub feedback_ids: Vec<String>,
+}
+
+/// A commit made while a session was running
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCommit {
+    pub hash: String,
+    pub summary: String,
+}
+
+/// Full record of one launched session, in .vibe/sessions/<id>.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTranscript {
+    pub id: String,
+    pub project_path: String,
+    pub work_dir: String, // Where the agent ran: the project or an issue's worktree
+    pub kind: String, // "terminal" | "embedded"
+    pub prompt: String,
+    #[serde(flatten)]
+    pub context: SessionContext,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub outcome: Option<String>, // As in SessionRecord, once the session is over
+    pub exit_code: Option<i32>,
+    pub head_before: Option<String>,
+    pub head_after: Option<String>,
+    #[serde(default)]
+    pub commits: Vec<SessionCommit>, // Oldest first
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use super::agent::AgentProfile;
+use super::terminal::TerminalProfile;
+use super::tracker::TrackerHost;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
@@ -10,10 +14,23 @@ pub struct Settings {
     pub launch_on_startup: bool,
     #[serde(default = "default_auto_refine_on_startup")]
     pub auto_refine_on_startup: bool,
-    #[serde(default)]
+    // Tokens live in the credential store; they are only read from here to migrate old settings files
+    #[serde(default, skip_serializing)]
     pub github_token: Option<String>,
     #[serde(default = "default_github_integration_enabled")]
-    pub github_integration_enabled: bool,
+    pub github_integration_enabled: bool, // Also gates GitLab/Gitea trackers
+    #[serde(default)]
+    pub tracker_hosts: Vec<TrackerHost>,
+    #[serde(default)]
+    pub terminal_profile: Option<String>, // Linux terminal to launch; None uses the first one installed
+    #[serde(default)]
+    pub terminal_profiles: Vec<TerminalProfile>, // User-defined, in addition to the built-in profiles
+    #[serde(default)]
+    pub tmux_sessions: bool, // Run Claude Code in a per-project tmux session (Linux)
+    #[serde(default)]
+    pub agent_profile: Option<String>, // Default agent CLI; None uses Claude Code
+    #[serde(default)]
+    pub agent_profiles: Vec<AgentProfile>, // User-defined, in addition to the built-in profiles
 }
 
 fn default_sound_effects_enabled() -> bool {
@@ -41,6 +58,12 @@ impl Default for Settings {
             auto_refine_on_startup: false,
             github_token: None,
             github_integration_enabled: false,
+            tracker_hosts: Vec::new(),
+            terminal_profile: None,
+            terminal_profiles: Vec::new(),
+            tmux_sessions: false,
+            agent_profile: None,
+            agent_profiles: Vec::new(),
         }
     }
 }
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// What a launched session runs, written to spec.json in its run directory
+///
+/// Everything is passed as structured values: the program is started directly, never
+/// through a shell, so prompts and paths need no quoting.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpawnSpec {
+    pub program: String,
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: Vec<(String, String)>,
+    pub cwd: String,
+    #[serde(default)]
+    pub stdin_file: Option<String>, // Piped into the program, e.g. the prompt
+    #[serde(default)]
+    pub title: Option<String>, // Console window title (Windows)
+    #[serde(default)]
+    pub cleanup: Vec<String>, // Prompt files to delete once the program exits
+    #[serde(default = "default_keep_open")]
+    pub keep_open: bool, // Leave a shell behind once the program exits
+}
+
+fn default_keep_open() -> bool {
+    true
+}
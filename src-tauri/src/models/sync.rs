@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::tracker::TrackerKind;
+
+/// A single change applied while syncing one GitHub issue
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncChange {
+    pub issue_number: u64,
+    pub local_id: String,
+    pub kind: String, // "issue" | "feedback"
+    pub fields: Vec<String>, // "title" | "body" | "state" | "labels" | "priority" | "comments" | "status"
+    pub winner: Option<String>, // "local" | "remote" - only set for conflicts
+}
+
+/// Outcome of a two-way sync between a project and its GitHub repository
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    pub tracker: Option<TrackerKind>, // Which backend the project was synced with
+    pub since: Option<String>, // Cursor the fetch started from, None for a full fetch
+    pub fetched: usize,
+    pub pull_requests_skipped: usize,
+    pub imported: usize,
+    pub discussions_imported: usize,
+    pub board_items_added: usize,
+    pub pulled: Vec<SyncChange>,
+    pub pushed: Vec<SyncChange>,
+    pub conflicts: Vec<SyncChange>,
+    pub errors: Vec<String>,
+    pub synced_at: String,
+}
+
+/// Result of syncing one project during sync_all_github_issues
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSyncResult {
+    pub project_name: String,
+    pub project_path: String,
+    pub github_url: Option<String>,
+    pub status: String, // "synced" | "skipped" | "failed"
+    pub reason: Option<String>, // Why the project was skipped or failed
+    pub report: Option<SyncReport>,
+}
+
+/// Summary of a sync across all projects
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSummary {
+    pub projects_scanned: usize,
+    pub projects_with_metadata: usize,
+    pub projects_with_github_url: usize,
+    pub projects_with_sync_enabled: usize,
+    pub imported: usize,
+    pub projects: Vec<ProjectSyncResult>,
+}
+
+/// Per-project sync cursor stored in .vibe/github-sync.json
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubSyncState {
+    /// Latest GitHub updated_at seen; later fetches only ask for issues updated since then
+    pub since: Option<String>,
+    /// Latest Discussion updatedAt seen by the Discussions import
+    #[serde(default)]
+    pub discussions_since: Option<String>,
+    /// Project board status option each linked issue had when the board was last synced
+    #[serde(default)]
+    pub board_statuses: BTreeMap<u64, String>,
+}
+
+/// Per-project rules for mapping GitHub labels and milestones onto local fields,
+/// stored in .vibe/github-mapping.json
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubMappingRules {
+    /// Label name -> local priority (1-5), e.g. "P0" -> 1, "bug" -> 2
+    #[serde(default)]
+    pub priority_labels: BTreeMap<String, u8>,
+    /// Milestone title -> local priority, used when no priority label matches
+    #[serde(default)]
+    pub milestone_priorities: BTreeMap<String, u8>,
+    /// Labels copied to local labels; None copies every label that isn't a priority label
+    #[serde(default)]
+    pub copy_labels: Option<Vec<String>>,
+    /// Issues carrying any of these labels are never imported
+    #[serde(default)]
+    pub skip_labels: Vec<String>,
+    /// Discussion categories imported as feedback; empty leaves Discussions alone
+    #[serde(default)]
+    pub discussion_categories: Vec<String>,
+    /// Priority for imported issues that no rule matches
+    #[serde(default = "default_priority")]
+    pub default_priority: u8,
+}
+
+fn default_priority() -> u8 {
+    3
+}
+
+impl Default for GithubMappingRules {
+    fn default() -> Self {
+        Self {
+            priority_labels: BTreeMap::new(),
+            milestone_priorities: BTreeMap::new(),
+            copy_labels: None,
+            skip_labels: Vec::new(),
+            discussion_categories: Vec::new(),
+            default_priority: default_priority(),
+        }
+    }
+}
+
+/// Maps one local issue status onto an option of the board's status field
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardStatusMapping {
+    pub status: String, // "pending" | "in-progress" | "for-review" | "needs-rework" | "completed"
+    pub option: String, // Option name on the board, e.g. "In Progress"
+}
+
+/// Per-project GitHub Projects (v2) board to keep issue statuses in step with,
+/// stored in .vibe/github-project.json
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubBoardConfig {
+    /// User or organization that owns the board
+    pub owner: String,
+    /// Project number, as in github.com/orgs/<owner>/projects/<number>
+    pub number: u64,
+    /// Single-select field holding the item status
+    #[serde(default = "default_status_field")]
+    pub status_field: String,
+    /// Local status -> board option; when pulling, the first status listed for an option wins
+    #[serde(default = "default_status_options")]
+    pub status_options: Vec<BoardStatusMapping>,
+    /// Add linked local issues that aren't on the board yet
+    #[serde(default = "default_add_missing_items")]
+    pub add_missing_items: bool,
+}
+
+fn default_status_field() -> String {
+    "Status".to_string()
+}
+
+fn default_status_options() -> Vec<BoardStatusMapping> {
+    [
+        ("pending", "Todo"),
+        ("in-progress", "In Progress"),
+        ("for-review", "In Progress"),
+        ("needs-rework", "In Progress"),
+        ("completed", "Done"),
+    ]
+    .into_iter()
+    .map(|(status, option)| BoardStatusMapping { status: status.to_string(), option: option.to_string() })
+    .collect()
+}
+
+fn default_add_missing_items() -> bool {
+    true
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Something a project can run, found in one of its build files
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTask {
+    pub name: String,    // e.g. "dev", "test", "tauri dev"
+    pub command: String, // Command line that runs it from the project root
+    pub kind: String,    // Tool that runs it: "npm", "pnpm", "cargo", "uv", "make", "sh", ...
+    pub source: String,  // File it was found in, relative to the project root
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// How to start a terminal emulator on Linux
+///
+/// In the argument templates "{cwd}" is replaced with the working directory and "{command}"
+/// with a bash script. Each argument is passed as-is, without going through a shell.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminalProfile {
+    pub name: String,
+    pub program: String, // Executable name or path
+    pub command_args: Vec<String>, // Runs "{command}", e.g. ["--", "bash", "-c", "{command}"]
+    pub open_args: Vec<String>, // Opens an interactive shell in "{cwd}"
+}
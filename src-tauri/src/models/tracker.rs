@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Which issue tracker API a repository is served by
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackerKind {
+    Github, // github.com and GitHub Enterprise
+    Gitlab,
+    Gitea, // Also covers Forgejo/Codeberg
+}
+
+/// A self-hosted tracker configured in settings
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerHost {
+    pub host: String, // e.g. "gitlab.example.com"
+    pub kind: TrackerKind,
+    #[serde(default)]
+    pub api_url: Option<String>, // Defaults to the kind's standard API path on the host
+    #[serde(default, skip_serializing)]
+    pub token: Option<String>, // Kept in the credential store, never serialized
+}
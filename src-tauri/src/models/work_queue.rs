@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+fn default_max_concurrent() -> usize {
+    1
+}
+
+fn default_timeout_secs() -> u64 {
+    60 * 60
+}
+
+fn default_max_attempts() -> u32 {
+    2
+}
+
+/// One issue to run through the agent headlessly
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkJob {
+    pub id: String,
+    pub project_path: String,
+    pub issue_id: String,
+    pub issue_title: String,
+    pub prompt_name: String,
+    pub status: String, // "queued" | "running" | "succeeded" | "failed" | "cancelled"
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub timeout_secs: u64,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub run_id: Option<String>, // The latest headless run in .vibe/runs/
+    pub last_error: Option<String>,
+}
+
+/// Persistent work queue stored in the app data directory (work-queue.json)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkQueue {
+    #[serde(default)]
+    pub paused: bool,
+    /// Jobs running at once across all projects (a project only ever runs one job at a time)
+    #[serde(default = "default_max_concurrent")]
+    pub max_concurrent: usize,
+    /// Defaults for newly queued jobs
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    #[serde(default)]
+    pub jobs: Vec<WorkJob>,
+}
+
+impl Default for WorkQueue {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            max_concurrent: default_max_concurrent(),
+            timeout_secs: default_timeout_secs(),
+            max_attempts: default_max_attempts(),
+            jobs: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// A git worktree created for an issue, stored in .vibe/worktrees/worktrees.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeRecord {
+    pub issue_id: String,
+    pub branch: String, // vibe/<issue-short-id>
+    pub path: String,
+    pub base_branch: String, // The branch it was created from and merges back into
+    pub created_at: String,
+    pub merged_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WorktreeFile {
+    pub worktrees: Vec<WorktreeRecord>,
+}
+
+/// A worktree along with its current git state
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeInfo {
+    #[serde(flatten)]
+    pub record: WorktreeRecord,
+    pub exists: bool,
+    pub dirty: bool,  // Uncommitted changes in the worktree
+    pub ahead: u32,   // Commits on the branch that aren't on the base branch
+}
@@ -1 +1,5 @@
-// File I/O utilities removed - functions were unused
+/// Stable FNV-1a hash of a path, for deriving per-project names and ports
+pub(crate) fn path_hash(path: &str) -> u64 {
+    path.bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
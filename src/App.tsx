@@ -9,6 +9,7 @@ import { useSettingsStore } from './store/settingsStore';
 import { useProjectStore } from './store/projectStore';
 import { generateFeedbackRefinementPrompt } from './services/clipboard';
 import * as tauri from './services/tauri';
+import type { GithubRepoOptions } from './store/types';
 
 function AppContent() {
   const { loadSettings, settings } = useSettingsStore();
@@ -19,8 +20,8 @@ function AppContent() {
   const hasRunAutoRefine = useRef(false);
   const navigate = useNavigate();
 
-  const handleCreateProject = async (projectName: string, summary?: string) => {
-    const projectPath = await createProject(projectName, summary);
+  const handleCreateProject = async (projectName: string, summary?: string, githubRepo?: GithubRepoOptions) => {
+    const projectPath = await createProject(projectName, summary, githubRepo);
     if (projectPath) {
       navigate(`/project/${encodeURIComponent(projectPath)}`);
     }
@@ -80,7 +81,7 @@ function AppContent() {
 
           try {
             const prompt = await generateFeedbackRefinementPrompt(project.name, project.path);
-            await tauri.launchClaudeCode(project.path, prompt);
+            await tauri.launchClaudeCode(project.path, prompt, { promptName: 'feedbackRefinement' });
 
             // Small delay between launches to avoid overwhelming the system
             if (i < projectsWithFeedback.length - 1) {
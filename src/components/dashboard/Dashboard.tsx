@@ -3,6 +3,8 @@ import { RefreshCw } from 'lucide-react';
 import { useProjectStore } from '../../store/projectStore';
 import { useSettingsStore } from '../../store/settingsStore';
 import { ProjectCard } from './ProjectCard';
+import { OutboundQueuePanel } from './OutboundQueuePanel';
+import { WorkQueuePanel } from './WorkQueuePanel';
 import { Button } from '../common/Button';
 import { APP_NAME } from '../../utils/constants';
 
@@ -104,6 +106,9 @@ export function Dashboard() {
           </div>
         ) : (
           <div className="space-y-12">
+            {settings.githubIntegrationEnabled && <OutboundQueuePanel />}
+            <WorkQueuePanel />
+
             {/* Active Projects Section */}
             {activeProjects.length > 0 && (
               <section>
@@ -0,0 +1,112 @@
+import { useCallback, useEffect, useState } from 'react';
+import { AlertTriangle, RefreshCw, Trash2 } from 'lucide-react';
+import { Button } from '../common/Button';
+import * as tauri from '../../services/tauri';
+import type { OutboundAction, OutboundOperation } from '../../store/types';
+
+function describeAction(action: OutboundAction): string {
+  switch (action.type) {
+    case 'close':
+      return `Close issue #${action.issueNumber}`;
+    case 'comment':
+      return `Comment on issue #${action.issueNumber}`;
+    case 'labels':
+      return `Update labels on issue #${action.issueNumber}`;
+    case 'create':
+      return `Create issue "${action.title}"`;
+  }
+}
+
+// Shows tracker operations that couldn't be sent and are waiting to be retried
+export function OutboundQueuePanel() {
+  const [operations, setOperations] = useState<OutboundOperation[]>([]);
+  const [isRetrying, setIsRetrying] = useState(false);
+
+  const loadQueue = useCallback(async () => {
+    try {
+      setOperations(await tauri.getOutboundQueue());
+    } catch (error) {
+      console.error('Failed to load outbound queue:', error);
+    }
+  }, []);
+
+  useEffect(() => {
+    loadQueue();
+    // The backend retries on its own; poll to pick up its progress
+    const interval = setInterval(loadQueue, 30000);
+    return () => clearInterval(interval);
+  }, [loadQueue]);
+
+  const handleRetry = async () => {
+    setIsRetrying(true);
+    try {
+      setOperations(await tauri.retryOutboundQueue());
+    } catch (error) {
+      alert(`Failed to retry queued operations: ${error}`);
+    } finally {
+      setIsRetrying(false);
+    }
+  };
+
+  const handleClear = async (ids?: string[]) => {
+    const message = ids
+      ? 'Discard this queued operation? It will not be sent.'
+      : 'Discard all queued operations? They will not be sent.';
+    if (!confirm(message)) return;
+    try {
+      setOperations(await tauri.clearOutboundQueue(ids));
+    } catch (error) {
+      alert(`Failed to clear queued operations: ${error}`);
+    }
+  };
+
+  if (operations.length === 0) return null;
+
+  const failedCount = operations.filter(op => op.status === 'failed').length;
+  const pendingCount = operations.length - failedCount;
+
+  return (
+    <section className="border-4 border-black rounded-lg bg-yellow-50 p-4">
+      <div className="flex items-center justify-between mb-3">
+        <h2 className="text-lg font-bold flex items-center gap-2">
+          <AlertTriangle size={18} className="text-yellow-600" />
+          Unsent Tracker Updates
+          <span className="text-sm font-normal text-gray-600">
+            {pendingCount} pending{failedCount > 0 ? `, ${failedCount} failed` : ''}
+          </span>
+        </h2>
+        <div className="flex gap-2">
+          <Button variant="secondary" size="sm" onClick={handleRetry} disabled={isRetrying}>
+            <RefreshCw size={14} className={`inline mr-1 ${isRetrying ? 'animate-spin' : ''}`} />
+            Retry Now
+          </Button>
+          <Button variant="danger" size="sm" onClick={() => handleClear()}>
+            <Trash2 size={14} className="inline mr-1" />
+            Clear All
+          </Button>
+        </div>
+      </div>
+      <ul className="space-y-2">
+        {operations.map((op) => (
+          <li key={op.id} className="flex items-start justify-between gap-4 text-sm">
+            <div className="min-w-0">
+              <div className="font-medium">
+                {describeAction(op.action)}
+                <span className="text-gray-500 font-normal"> — {op.repoUrl}</span>
+              </div>
+              <div className={op.status === 'failed' ? 'text-red-600' : 'text-gray-600'}>
+                {op.status === 'failed'
+                  ? `Gave up after ${op.attempts} attempts`
+                  : `Attempt ${op.attempts}, next retry ${new Date(op.nextAttemptAt).toLocaleTimeString()}`}
+                {op.lastError && <span className="block truncate">{op.lastError}</span>}
+              </div>
+            </div>
+            <button onClick={() => handleClear([op.id])} className="text-red-500 hover:text-red-700 shrink-0">
+              <Trash2 size={14} />
+            </button>
+          </li>
+        ))}
+      </ul>
+    </section>
+  );
+}
@@ -1,5 +1,5 @@
 import { useNavigate } from 'react-router-dom';
-import { ExternalLink } from 'lucide-react';
+import { ExternalLink, GitPullRequest, CheckCircle, XCircle, Clock } from 'lucide-react';
 import type { Project } from '../../store/types';
 import { STATUS_LABELS, STATUS_COLORS, PRIORITY_COLORS } from '../../store/types';
 import { formatRelativeTime } from '../../utils/formatters';
@@ -93,6 +93,18 @@ export function ProjectCard({ project }: ProjectCardProps) {
             Deployed
           </span>
         )}
+        {project.openPullRequests !== undefined && project.openPullRequests !== null && project.openPullRequests > 0 && (
+          <span className="flex items-center gap-1" title="Open pull requests">
+            <GitPullRequest size={16} />
+            {project.openPullRequests}
+          </span>
+        )}
+        {project.ciStatus && (
+          <span className="flex items-center gap-1" title={`CI: ${project.ciStatus}`}>
+            {project.ciStatus === 'success' ? <CheckCircle size={16} /> : project.ciStatus === 'pending' ? <Clock size={16} /> : <XCircle size={16} />}
+            CI
+          </span>
+        )}
       </div>
     </div>
   );
@@ -0,0 +1,151 @@
+import { useCallback, useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { Bot, Pause, Play, Trash2, X } from 'lucide-react';
+import { Button } from '../common/Button';
+import * as tauri from '../../services/tauri';
+import type { WorkJob, WorkQueue } from '../../store/types';
+
+const STATUS_STYLES: Record<WorkJob['status'], string> = {
+  queued: 'text-gray-600',
+  running: 'text-blue-600',
+  succeeded: 'text-green-600',
+  failed: 'text-red-600',
+  cancelled: 'text-gray-400',
+};
+
+function projectName(path: string): string {
+  return path.split(/[\\/]/).filter(Boolean).pop() || path;
+}
+
+// Issues queued for headless agent runs across all projects
+export function WorkQueuePanel() {
+  const [queue, setQueue] = useState<WorkQueue | null>(null);
+
+  const loadQueue = useCallback(async () => {
+    try {
+      setQueue(await tauri.getWorkQueue());
+    } catch (error) {
+      console.error('Failed to load work queue:', error);
+    }
+  }, []);
+
+  useEffect(() => {
+    loadQueue();
+    const unlisten = listen<WorkJob>('work-queue-progress', () => loadQueue());
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [loadQueue]);
+
+  const run = async (action: () => Promise<unknown>, failure: string) => {
+    try {
+      await action();
+      await loadQueue();
+    } catch (error) {
+      alert(`${failure}: ${error}`);
+    }
+  };
+
+  const handleSettingChange = (key: 'maxConcurrent' | 'timeoutSecs' | 'maxAttempts', value: number) => {
+    if (!queue || !Number.isFinite(value)) return;
+    const next = { ...queue, [key]: value };
+    run(
+      () => tauri.updateWorkQueueSettings(next.maxConcurrent, next.timeoutSecs, next.maxAttempts),
+      'Failed to update work queue settings'
+    );
+  };
+
+  if (!queue || queue.jobs.length === 0) return null;
+
+  const count = (status: WorkJob['status']) => queue.jobs.filter(job => job.status === status).length;
+
+  return (
+    <section className="border-4 border-black rounded-lg bg-blue-50 p-4">
+      <div className="flex items-center justify-between mb-3">
+        <h2 className="text-lg font-bold flex items-center gap-2">
+          <Bot size={18} className="text-blue-600" />
+          Work Queue
+          <span className="text-sm font-normal text-gray-600">
+            {count('running')} running, {count('queued')} queued{queue.paused ? ' (paused)' : ''}
+          </span>
+        </h2>
+        <div className="flex gap-2">
+          {queue.paused ? (
+            <Button variant="secondary" size="sm" onClick={() => run(tauri.resumeWorkQueue, 'Failed to resume queue')}>
+              <Play size={14} className="inline mr-1" />
+              Resume
+            </Button>
+          ) : (
+            <Button variant="secondary" size="sm" onClick={() => run(tauri.pauseWorkQueue, 'Failed to pause queue')}>
+              <Pause size={14} className="inline mr-1" />
+              Pause
+            </Button>
+          )}
+          <Button variant="secondary" size="sm" onClick={() => run(tauri.clearFinishedWorkJobs, 'Failed to clear finished jobs')}>
+            <Trash2 size={14} className="inline mr-1" />
+            Clear Finished
+          </Button>
+        </div>
+      </div>
+
+      <div className="flex flex-wrap gap-4 mb-3 text-sm text-gray-700">
+        <label className="flex items-center gap-2">
+          Concurrent jobs
+          <input
+            type="number"
+            min={1}
+            value={queue.maxConcurrent}
+            onChange={(e) => handleSettingChange('maxConcurrent', parseInt(e.target.value, 10))}
+            className="w-16 border-2 border-black rounded px-2 py-1"
+          />
+        </label>
+        <label className="flex items-center gap-2">
+          Timeout (minutes)
+          <input
+            type="number"
+            min={1}
+            value={Math.round(queue.timeoutSecs / 60)}
+            onChange={(e) => handleSettingChange('timeoutSecs', parseInt(e.target.value, 10) * 60)}
+            className="w-16 border-2 border-black rounded px-2 py-1"
+          />
+        </label>
+        <label className="flex items-center gap-2">
+          Attempts
+          <input
+            type="number"
+            min={1}
+            value={queue.maxAttempts}
+            onChange={(e) => handleSettingChange('maxAttempts', parseInt(e.target.value, 10))}
+            className="w-16 border-2 border-black rounded px-2 py-1"
+          />
+        </label>
+      </div>
+
+      <ul className="space-y-2">
+        {queue.jobs.map((job) => (
+          <li key={job.id} className="flex items-start justify-between gap-4 text-sm">
+            <div className="min-w-0">
+              <div className="font-medium">
+                {job.issueTitle}
+                <span className="text-gray-500 font-normal"> — {projectName(job.projectPath)}</span>
+              </div>
+              <div className={STATUS_STYLES[job.status]}>
+                {job.status} · attempt {job.attempts}/{job.maxAttempts}
+                {job.lastError && <span className="block truncate">{job.lastError}</span>}
+              </div>
+            </div>
+            {(job.status === 'queued' || job.status === 'running') && (
+              <button
+                title="Cancel"
+                onClick={() => run(() => tauri.cancelWorkJob(job.id), 'Failed to cancel job')}
+                className="text-red-500 hover:text-red-700 shrink-0"
+              >
+                <X size={14} />
+              </button>
+            )}
+          </li>
+        ))}
+      </ul>
+    </section>
+  );
+}
@@ -1,11 +1,15 @@
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
 import { X, CheckCircle, AlertCircle } from 'lucide-react';
 import { Button } from '../common/Button';
+import * as tauri from '../../services/tauri';
+import { formatDateTime } from '../../utils/formatters';
 
 interface IssueReviewModalProps {
   isOpen: boolean;
   onClose: () => void;
+  projectPath: string;
   issue: {
+    id: string;
     title: string;
     description: string;
     subtasks: string[];
@@ -17,10 +21,19 @@ interface IssueReviewModalProps {
   onReportBug: (bugNotes: string) => Promise<void>;
 }
 
-export function IssueReviewModal({ isOpen, onClose, issue, onApprove, onReportBug }: IssueReviewModalProps) {
+export function IssueReviewModal({ isOpen, onClose, projectPath, issue, onApprove, onReportBug }: IssueReviewModalProps) {
   const [bugNotes, setBugNotes] = useState('');
   const [isSubmitting, setIsSubmitting] = useState(false);
   const [mode, setMode] = useState<'initial' | 'bug-report'>('initial');
+  const [sessions, setSessions] = useState<tauri.SessionTranscript[]>([]);
+
+  // Agent sessions launched for this issue, with the commits each one made
+  useEffect(() => {
+    if (!isOpen) return;
+    tauri.listIssueSessions(projectPath, issue.id)
+      .then(setSessions)
+      .catch((error) => console.error('Failed to load issue sessions:', error));
+  }, [isOpen, projectPath, issue.id]);
 
   if (!isOpen) return null;
 
@@ -143,6 +156,31 @@ export function IssueReviewModal({ isOpen, onClose, issue, onApprove, onReportBu
             </div>
           )}
 
+          {/* Sessions launched for this issue */}
+          {sessions.length > 0 && (
+            <div>
+              <label className="block text-sm font-semibold text-gray-700 mb-2">
+                Sessions:
+              </label>
+              <div className="bg-gray-50 border-2 border-gray-300 rounded-lg p-3 space-y-2 text-gray-800 text-sm">
+                {sessions.map((session) => (
+                  <div key={session.id}>
+                    <div className="font-medium">
+                      {formatDateTime(session.startedAt)}
+                      {session.outcome ? ` · ${session.outcome}` : ' · running'}
+                      {` · ${session.commits.length} commit(s)`}
+                    </div>
+                    {session.commits.map((commit) => (
+                      <div key={commit.hash} className="font-mono text-xs ml-4">
+                        {commit.hash.slice(0, 7)} {commit.summary}
+                      </div>
+                    ))}
+                  </div>
+                ))}
+              </div>
+            </div>
+          )}
+
           {/* Bug Report Mode */}
           {mode === 'bug-report' && (
             <div>
@@ -1,7 +1,9 @@
 import { useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
 import { Terminal, Circle } from 'lucide-react';
 import { Button } from '../common/Button';
 import * as tauri from '../../services/tauri';
+import { formatDateTime, formatDuration } from '../../utils/formatters';
 
 interface ClaudeSessionPanelProps {
   projectPath: string;
@@ -11,6 +13,11 @@ interface ClaudeSessionPanelProps {
 
 export function ClaudeSessionPanel({ projectPath, textColor = '#FFFFFF', bgColor }: ClaudeSessionPanelProps) {
   const [sessionStatus, setSessionStatus] = useState<tauri.SessionInfo | null>(null);
+  const [history, setHistory] = useState<tauri.SessionRecord[]>([]);
+  const [transcripts, setTranscripts] = useState<Record<string, tauri.SessionTranscript>>({});
+  const [expandedId, setExpandedId] = useState<string | null>(null);
+  const [output, setOutput] = useState('');
+  const [showOutput, setShowOutput] = useState(false);
   const [isLoading, setIsLoading] = useState(true);
 
   useEffect(() => {
@@ -21,20 +28,49 @@ export function ClaudeSessionPanel({ projectPath, textColor = '#FFFFFF', bgColor
       loadSessionStatus();
     }, 5000);
 
-    return () => clearInterval(interval);
+    // Refresh straight away when a session for this project starts or stops
+    const unlisteners = ['session-started', 'session-stopped'].map((event) =>
+      listen<{ projectPath: string }>(event, ({ payload }) => {
+        if (payload.projectPath === projectPath) {
+          loadSessionStatus();
+        }
+      })
+    );
+
+    return () => {
+      clearInterval(interval);
+      unlisteners.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
   }, [projectPath]);
 
   const loadSessionStatus = async () => {
     try {
-      const status = await tauri.getSessionStatus(projectPath);
+      const [status, sessions, recorded] = await Promise.all([
+        tauri.getSessionStatus(projectPath),
+        tauri.getSessionHistory(projectPath),
+        tauri.listSessions(projectPath).catch(() => []),
+      ]);
       setSessionStatus(status);
+      setHistory(sessions);
+      setTranscripts(Object.fromEntries(recorded.map((t) => [t.id, t])));
       setIsLoading(false);
+      if (status.tmuxSession) {
+        setOutput(await tauri.getSessionOutput(projectPath, 40).catch(() => ''));
+      }
     } catch (error) {
       console.error('Failed to get session status:', error);
       setIsLoading(false);
     }
   };
 
+  const handleAttach = async () => {
+    try {
+      await tauri.focusClaudeTerminal(projectPath);
+    } catch (error) {
+      alert(`Failed to attach to session: ${error}`);
+    }
+  };
+
   const handleOpenTerminal = async () => {
     try {
       await tauri.openInTerminal(projectPath);
@@ -59,6 +95,12 @@ export function ClaudeSessionPanel({ projectPath, textColor = '#FFFFFF', bgColor
     not_started: 'Not Started'
   };
 
+  const outcomeLabel = (session: tauri.SessionRecord) => {
+    if (session.outcome === 'exited') return `exit code ${session.exitCode}`;
+    if (session.outcome === 'closed') return 'terminal closed';
+    return 'failed to start';
+  };
+
   return (
     <div
       className="border-4 border-black rounded-lg p-4 mb-6 bg-opacity-20"
@@ -78,12 +120,37 @@ export function ClaudeSessionPanel({ projectPath, textColor = '#FFFFFF', bgColor
               />
               <span className="text-sm" style={{ color: textColor, opacity: 0.9 }}>
                 {statusLabels[sessionStatus.status]}
+                {sessionStatus.pid !== null && ` (PID ${sessionStatus.pid})`}
               </span>
             </div>
           </div>
         </div>
 
-        {sessionStatus.status === 'running' && (
+        {sessionStatus.status === 'running' && sessionStatus.tmuxSession && (
+          <div className="flex gap-2">
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={() => setShowOutput(!showOutput)}
+              invertedBgColor={textColor}
+              invertedTextColor={bgColor}
+            >
+              {showOutput ? 'Hide Output' : 'Show Output'}
+            </Button>
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={handleAttach}
+              invertedBgColor={textColor}
+              invertedTextColor={bgColor}
+            >
+              <Terminal size={16} className="inline mr-2" />
+              Attach
+            </Button>
+          </div>
+        )}
+
+        {sessionStatus.status === 'running' && !sessionStatus.tmuxSession && (
           <Button
             variant="secondary"
             size="sm"
@@ -96,6 +163,46 @@ export function ClaudeSessionPanel({ projectPath, textColor = '#FFFFFF', bgColor
           </Button>
         )}
       </div>
+
+      {showOutput && sessionStatus.tmuxSession && (
+        <pre className="mt-3 p-3 bg-black/60 text-green-200 text-xs rounded max-h-64 overflow-auto whitespace-pre-wrap">
+          {output || 'No output yet.'}
+        </pre>
+      )}
+
+      {history.length > 0 && (
+        <ul className="mt-3 space-y-1 text-sm" style={{ color: textColor, opacity: 0.8 }}>
+          {history.slice(0, 5).map((session) => {
+            const transcript = transcripts[session.id];
+            const expanded = expandedId === session.id;
+            return (
+              <li key={session.id}>
+                <button
+                  className="text-left disabled:cursor-default"
+                  disabled={!transcript}
+                  onClick={() => setExpandedId(expanded ? null : session.id)}
+                >
+                  {formatDateTime(session.startedAt)} · {formatDuration(session.durationSecs)} · {outcomeLabel(session)}
+                  {transcript?.promptName && ` · ${transcript.promptName}`}
+                  {transcript && ` · ${transcript.commits.length} commit(s)`}
+                </button>
+                {expanded && transcript && (
+                  <div className="mt-1 ml-4 space-y-1">
+                    {transcript.commits.map((commit) => (
+                      <div key={commit.hash} className="font-mono text-xs">
+                        {commit.hash.slice(0, 7)} {commit.summary}
+                      </div>
+                    ))}
+                    <pre className="p-2 bg-black/40 text-xs rounded max-h-48 overflow-auto whitespace-pre-wrap">
+                      {transcript.prompt || 'Started without a prompt.'}
+                    </pre>
+                  </div>
+                )}
+              </li>
+            );
+          })}
+        </ul>
+      )}
     </div>
   );
 }
@@ -0,0 +1,128 @@
+import { useCallback, useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { Server, Square, RotateCw, Circle, ExternalLink, AlertTriangle } from 'lucide-react';
+import * as tauri from '../../services/tauri';
+import type { DevServerInfo, DevServerLine } from '../../store/types';
+import { formatDateTime } from '../../utils/formatters';
+
+interface DevServerPanelProps {
+  projectPath: string;
+  textColor?: string;
+}
+
+// Output lines kept in the panel
+const MAX_LINES = 500;
+
+// The project's supervised dev server: status, URL and output, with stop and restart
+export function DevServerPanel({ projectPath, textColor = '#FFFFFF' }: DevServerPanelProps) {
+  const [server, setServer] = useState<DevServerInfo | null>(null);
+  const [output, setOutput] = useState<DevServerLine[]>([]);
+  const [showOutput, setShowOutput] = useState(false);
+  const [busy, setBusy] = useState(false);
+
+  const loadServer = useCallback(async () => {
+    try {
+      const [status, lines] = await Promise.all([
+        tauri.getDevServerStatus(projectPath),
+        tauri.getDevServerOutput(projectPath, MAX_LINES),
+      ]);
+      setServer(status);
+      setOutput(lines);
+    } catch (error) {
+      console.error('Failed to get dev server status:', error);
+    }
+  }, [projectPath]);
+
+  useEffect(() => {
+    loadServer();
+    const unlisteners = [
+      listen<DevServerInfo>('dev-server-changed', ({ payload }) => {
+        if (payload.projectPath !== projectPath) return;
+        // Only a server that has just started is running but not ready; its output starts afresh
+        if (payload.status === 'running' && !payload.ready) setOutput([]);
+        setServer(payload);
+      }),
+      listen<DevServerLine>('dev-server-output', ({ payload }) => {
+        if (payload.projectPath === projectPath) {
+          setOutput((lines) => [...lines.slice(-(MAX_LINES - 1)), payload]);
+        }
+      }),
+    ];
+    return () => {
+      unlisteners.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
+  }, [projectPath, loadServer]);
+
+  const run = async (action: () => Promise<unknown>, failure: string) => {
+    setBusy(true);
+    try {
+      await action();
+    } catch (error) {
+      alert(`${failure}: ${error}`);
+    }
+    setBusy(false);
+    await loadServer();
+  };
+
+  if (!server) return null;
+
+  const running = server.status === 'running';
+  const statusLabel = running
+    ? `${server.ready ? 'ready' : 'starting'} (PID ${server.pid})`
+    : server.status === 'stopped' ? 'stopped' : `exited with code ${server.exitCode ?? 'unknown'}`;
+  const errorCount = output.filter((line) => line.error).length;
+
+  return (
+    <div className="border-4 border-black rounded-lg p-4 mb-6 bg-black/20" style={{ color: textColor }}>
+      <div className="flex items-center justify-between gap-4">
+        <div className="min-w-0">
+          <h3 className="font-bold text-lg flex items-center gap-2">
+            <Server size={20} />
+            Dev Server
+          </h3>
+          <div className="text-sm flex items-center gap-2">
+            <Circle size={10} className={`${running ? (server.ready ? 'bg-green-500' : 'bg-yellow-500') : 'bg-gray-500'} fill-current rounded-full`} />
+            <span className="font-mono truncate">{server.command}</span>
+            {server.port && <span className="opacity-80">· port {server.port}</span>}
+            <span className="opacity-80">· {statusLabel} · started {formatDateTime(server.startedAt)}</span>
+          </div>
+          {running && server.url && (
+            <button className="text-sm underline flex items-center gap-1" onClick={() => tauri.openUrl(server.url!)}>
+              {server.url} <ExternalLink size={14} />
+            </button>
+          )}
+        </div>
+        <div className="flex items-center gap-3 shrink-0">
+          {errorCount > 0 && (
+            <span className="flex items-center gap-1 text-sm text-red-300" title="Error lines in the output">
+              <AlertTriangle size={16} /> {errorCount}
+            </span>
+          )}
+          <button className="text-sm underline" onClick={() => setShowOutput(!showOutput)}>
+            {showOutput ? 'Hide output' : 'Show output'}
+          </button>
+          {running && (
+            <button title="Stop" disabled={busy} onClick={() => run(() => tauri.stopDevServer(projectPath), 'Failed to stop dev server')}>
+              <Square size={16} />
+            </button>
+          )}
+          <button title="Restart" disabled={busy} onClick={() => run(() => tauri.restartDevServer(projectPath), 'Failed to restart dev server')}>
+            <RotateCw size={16} />
+          </button>
+        </div>
+      </div>
+
+      {showOutput && (
+        <pre className="mt-3 p-3 bg-black/60 text-gray-200 text-xs rounded max-h-64 overflow-auto whitespace-pre-wrap">
+          {output.length === 0
+            ? 'No output yet.'
+            : output.map((line, index) => (
+                <div key={index} className={line.error ? 'text-red-300' : line.stream === 'stderr' ? 'text-yellow-100' : undefined}>
+                  {line.text}
+                </div>
+              ))}
+        </pre>
+      )}
+    </div>
+  );
+}
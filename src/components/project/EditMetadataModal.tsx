@@ -3,7 +3,7 @@ import { Modal } from '../common/Modal';
 import { Button } from '../common/Button';
 import { Input } from '../common/Input';
 import { Upload, Trash2 } from 'lucide-react';
-import type { Project } from '../../store/types';
+import type { AgentProfile, Project } from '../../store/types';
 import { STATUS_LABELS } from '../../store/types';
 import * as tauri from '../../services/tauri';
 import { useProjectStore } from '../../store/projectStore';
@@ -40,6 +40,11 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
   const [githubUrl, setGithubUrl] = useState<string | null>(null);
   const [devCommand, setDevCommand] = useState('');
   const [buildCommand, setBuildCommand] = useState('');
+  const [agentProfiles, setAgentProfiles] = useState<AgentProfile[]>([]);
+  const [agentProfile, setAgentProfile] = useState('');
+  const [originalAgentProfile, setOriginalAgentProfile] = useState('');
+  const [devPort, setDevPort] = useState('');
+  const [originalDevPort, setOriginalDevPort] = useState('');
 
   useEffect(() => {
     if (isOpen && project) {
@@ -68,7 +73,9 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
 
             if (scripts.dev_script_name) {
               if (scripts.dev_script_type === 'npm') {
-                detectedDevCmd = `npm run ${scripts.dev_script_name}`;
+                detectedDevCmd = `${scripts.package_manager || 'npm'} run ${scripts.dev_script_name}`;
+              } else if (scripts.dev_script_type === 'command') {
+                detectedDevCmd = scripts.dev_script_name;
               } else if (scripts.dev_script_type === 'bat') {
                 detectedDevCmd = `.\\${scripts.dev_script_name}`;
               } else if (scripts.dev_script_type === 'sh') {
@@ -76,8 +83,9 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
               }
             }
 
-            if (scripts.has_build) {
-              detectedBuildCmd = 'npm run build';
+            const buildTask = scripts.tasks.find(task => task.name === 'build');
+            if (buildTask) {
+              detectedBuildCmd = buildTask.command;
             }
 
             setDevCommand(detectedDevCmd);
@@ -89,6 +97,29 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
           });
       }
 
+      // Agent profiles to choose from, and the one this project uses ('' = default from settings)
+      tauri.getAgentProfiles().then(setAgentProfiles).catch(() => setAgentProfiles([]));
+      tauri.getProjectAgentProfile(project.path)
+        .then(profile => {
+          setAgentProfile(profile || '');
+          setOriginalAgentProfile(profile || '');
+        })
+        .catch(() => {
+          setAgentProfile('');
+          setOriginalAgentProfile('');
+        });
+
+      // The dev server's port from the port registry
+      tauri.getProjectPort(project.path)
+        .then(port => {
+          setDevPort(String(port));
+          setOriginalDevPort(String(port));
+        })
+        .catch(() => {
+          setDevPort('');
+          setOriginalDevPort('');
+        });
+
       // Fetch GitHub URL from git remote
       tauri.getGithubUrl(project.path)
         .then(url => setGithubUrl(url || null))
@@ -167,6 +198,15 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
         buildCommand: buildCommand.trim(),
       });
 
+      if (agentProfile !== originalAgentProfile) {
+        await tauri.setProjectAgentProfile(project.path, agentProfile || null);
+      }
+
+      // An emptied field asks for a freshly assigned port
+      if (devPort.trim() !== originalDevPort) {
+        await tauri.setProjectPort(project.path, devPort.trim() ? Number(devPort.trim()) : null);
+      }
+
       // Handle GitHub sync toggle if it changed
       if (githubSyncEnabled !== originalGithubSyncEnabled) {
         console.log(`[EditMetadataModal] GitHub sync changed to: ${githubSyncEnabled}`);
@@ -330,7 +370,24 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
             placeholder="npm run dev"
           />
           <p className="text-xs text-gray-500 mt-1">
-            Command to run the development server (auto-detected from package.json)
+            Command to run the development server (auto-detected from the project's build files). Use {'{port}'} for the dev server port
+          </p>
+        </div>
+
+        <div>
+          <label className="block text-sm font-medium text-gray-900 mb-1">
+            Dev Server Port
+          </label>
+          <Input
+            type="number"
+            min={1024}
+            max={65535}
+            value={devPort}
+            onChange={(e) => setDevPort(e.target.value)}
+            placeholder="Assigned automatically"
+          />
+          <p className="text-xs text-gray-500 mt-1">
+            Passed to the dev server as PORT and, for Vite, Next and similar tools, as --port
           </p>
         </div>
 
@@ -349,6 +406,25 @@ export function EditMetadataModal({ isOpen, onClose, onSave, project }: EditMeta
           </p>
         </div>
 
+        <div>
+          <label className="block text-sm font-medium text-gray-900 mb-1">
+            Agent
+          </label>
+          <select
+            value={agentProfile}
+            onChange={(e) => setAgentProfile(e.target.value)}
+            className="w-full px-3 py-2 border border-gray-300 rounded-md shadow-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent text-gray-900 bg-white"
+          >
+            <option value="">Default from settings</option>
+            {agentProfiles.map((profile) => (
+              <option key={profile.name} value={profile.name}>{profile.name}</option>
+            ))}
+          </select>
+          <p className="text-xs text-gray-500 mt-1">
+            Agent CLI used for sessions and headless runs in this project
+          </p>
+        </div>
+
         {githubUrl && (
           <div className="border-t pt-4">
             <div className="flex items-center justify-between">
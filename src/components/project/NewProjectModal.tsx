@@ -1,11 +1,13 @@
 import { useState } from 'react';
 import { Modal } from '../common/Modal';
 import { Button } from '../common/Button';
+import { useSettingsStore } from '../../store/settingsStore';
+import type { GithubRepoOptions } from '../../store/types';
 
 interface NewProjectModalProps {
   isOpen: boolean;
   onClose: () => void;
-  onCreate: (projectName: string, summary?: string) => Promise<void>;
+  onCreate: (projectName: string, summary?: string, githubRepo?: GithubRepoOptions) => Promise<void>;
 }
 
 export function NewProjectModal({ isOpen, onClose, onCreate }: NewProjectModalProps) {
@@ -13,6 +15,10 @@ export function NewProjectModal({ isOpen, onClose, onCreate }: NewProjectModalPr
   const [summary, setSummary] = useState('');
   const [error, setError] = useState('');
   const [isCreating, setIsCreating] = useState(false);
+  const [publishToGithub, setPublishToGithub] = useState(false);
+  const [isPrivate, setIsPrivate] = useState(true);
+  const { credentialStatus } = useSettingsStore();
+  const hasGithubToken = !!credentialStatus?.githubTokenSet;
 
   // Convert project name to folder name format
   const getFolderName = (name: string) => {
@@ -37,12 +43,14 @@ export function NewProjectModal({ isOpen, onClose, onCreate }: NewProjectModalPr
     setError('');
 
     try {
-      await onCreate(projectName.trim(), summary.trim() || undefined);
+      const githubRepo = publishToGithub && hasGithubToken ? { private: isPrivate } : undefined;
+      await onCreate(projectName.trim(), summary.trim() || undefined, githubRepo);
       setProjectName('');
       setSummary('');
+      setPublishToGithub(false);
       onClose();
     } catch (err) {
-      setError(err instanceof Error ? err.message : 'Failed to create project');
+      setError(err instanceof Error ? err.message : typeof err === 'string' ? err : 'Failed to create project');
     } finally {
       setIsCreating(false);
     }
@@ -100,11 +108,39 @@ export function NewProjectModal({ isOpen, onClose, onCreate }: NewProjectModalPr
           </p>
         </div>
 
+        {hasGithubToken && (
+          <div className="space-y-2">
+            <label className="flex items-center gap-2 text-sm font-semibold text-gray-900">
+              <input
+                type="checkbox"
+                checked={publishToGithub}
+                onChange={(e) => setPublishToGithub(e.target.checked)}
+                disabled={isCreating}
+              />
+              Create GitHub repository and push
+            </label>
+            {publishToGithub && (
+              <label className="flex items-center gap-2 text-sm text-gray-700 ml-6">
+                <input
+                  type="checkbox"
+                  checked={isPrivate}
+                  onChange={(e) => setIsPrivate(e.target.checked)}
+                  disabled={isCreating}
+                />
+                Private repository
+              </label>
+            )}
+          </div>
+        )}
+
         <div className="bg-blue-50 border-2 border-blue-200 rounded-lg p-4">
           <h4 className="text-sm font-semibold text-blue-900 mb-2">What happens next?</h4>
           <ul className="text-sm text-blue-800 space-y-1">
             <li>✓ Project folder created with .vibe structure</li>
             <li>✓ Git repository initialized</li>
+            {publishToGithub && hasGithubToken && (
+              <li>✓ GitHub repository created, initial commit pushed and sync enabled</li>
+            )}
             <li>✓ Ready to write your project pitch!</li>
           </ul>
         </div>
@@ -7,8 +7,13 @@ import { Button } from '../common/Button';
 import { FeedbackModal } from '../feedback/FeedbackModal';
 import { ReviewModal } from '../feedback/ReviewModal';
 import { IssueReviewModal } from '../issues/IssueReviewModal';
+import { DevServerPanel } from './DevServerPanel';
 import { EditMetadataModal } from './EditMetadataModal';
 import { ProjectSetupCard } from './ProjectSetupCard';
+import { RepoStatusPanel } from './RepoStatusPanel';
+import { TasksPanel } from './TasksPanel';
+import { TerminalPanel } from './TerminalPanel';
+import { WorktreesPanel } from './WorktreesPanel';
 import { FeedbackTab } from './tabs/FeedbackTab';
 import { IssuesTab } from './tabs/IssuesTab';
 import { CompletedTab } from './tabs/CompletedTab';
@@ -196,8 +201,13 @@ export function ProjectDetail() {
     if (!currentProject || !currentProject.githubUrl) return;
 
     try {
-      const count = await tauri.fetchGithubIssues(currentProject.path, currentProject.githubUrl);
-      alert(`Successfully imported ${count} issue(s) from GitHub!`);
+      const report = await tauri.syncGithubIssues(currentProject.path, currentProject.githubUrl);
+      const summary = `Imported ${report.imported}, pulled ${report.pulled.length}, pushed ${report.pushed.length} change(s)`;
+      const discussions = report.discussionsImported > 0 ? `\n${report.discussionsImported} discussion(s) imported as feedback` : '';
+      const board = report.boardItemsAdded > 0 ? `\n${report.boardItemsAdded} issue(s) added to the project board` : '';
+      const conflicts = report.conflicts.length > 0 ? `\n${report.conflicts.length} conflict(s) resolved by most recent edit` : '';
+      const errors = report.errors.length > 0 ? `\n\nErrors:\n${report.errors.join('\n')}` : '';
+      alert(`GitHub sync complete. ${summary}.${discussions}${board}${conflicts}${errors}`);
       // Refresh project to show new feedback items
       await refreshProject(currentProject.path);
     } catch (error) {
@@ -254,6 +264,38 @@ export function ProjectDetail() {
     }
   };
 
+  const handleRunHeadless = async (issue: Issue) => {
+    if (!currentProject) return;
+    try {
+      await tauri.startHeadlessRun(currentProject.path, issue.id);
+      await refreshProject(currentProject.id);
+    } catch (error) {
+      alert(`Failed to start headless run: ${error}`);
+    }
+  };
+
+  const handleStartInWorktree = async (issue: Issue) => {
+    if (!currentProject) return;
+    try {
+      await tauri.startIssueInWorktree(currentProject.path, issue.id, false);
+      await refreshProject(currentProject.id);
+    } catch (error) {
+      alert(`Failed to start work in a worktree: ${error}`);
+    }
+  };
+
+  const handleQueueAllIssues = async () => {
+    if (!currentProject) return;
+    try {
+      const jobs = await tauri.enqueueIssues(currentProject.path);
+      alert(jobs.length > 0
+        ? `Queued ${jobs.length} issue(s) for headless runs.`
+        : 'All pending issues are already queued.');
+    } catch (error) {
+      alert(`Failed to queue issues: ${error}`);
+    }
+  };
+
   const handleReviewIssue = (issue: Issue) => {
     setReviewingIssue(issue);
     setIsIssueReviewModalOpen(true);
@@ -324,6 +366,10 @@ export function ProjectDetail() {
     await launchClaudeCode(currentProject.path, feedbackIds);
   };
 
+  // Issues the fix prompts ask the agent to work through
+  const openIssueIds = () =>
+    issues.filter(i => i.status === 'pending' || i.status === 'in-progress' || i.status === 'needs-rework').map(i => i.id);
+
   const handleRefineAllFeedback = async () => {
     if (!currentProject) return;
     try {
@@ -331,7 +377,10 @@ export function ProjectDetail() {
         currentProject.name,
         currentProject.path
       );
-      await tauri.launchClaudeCode(currentProject.path, prompt);
+      await tauri.launchClaudeCode(currentProject.path, prompt, {
+        promptName: 'feedbackRefinement',
+        feedbackIds: feedback.filter(f => f.status === 'pending').map(f => f.id),
+      });
     } catch (error) {
       console.error('Failed to launch feedback refinement:', error);
     }
@@ -344,7 +393,10 @@ export function ProjectDetail() {
         currentProject.name,
         currentProject.path
       );
-      await tauri.launchClaudeCode(currentProject.path, prompt);
+      await tauri.launchClaudeCode(currentProject.path, prompt, {
+        promptName: 'issueFix',
+        issueIds: openIssueIds(),
+      });
     } catch (error) {
       console.error('Failed to launch issue fix:', error);
     }
@@ -357,7 +409,11 @@ export function ProjectDetail() {
         currentProject.name,
         currentProject.path
       );
-      await tauri.launchClaudeCode(currentProject.path, prompt);
+      await tauri.launchClaudeCode(currentProject.path, prompt, {
+        promptName: 'automatedWorkflow',
+        issueIds: openIssueIds(),
+        feedbackIds: feedback.filter(f => f.status === 'pending').map(f => f.id),
+      });
     } catch (error) {
       console.error('Failed to launch automated workflow:', error);
     }
@@ -377,23 +433,47 @@ export function ProjectDetail() {
     }
   };
 
+  const handleCreateGithubIssue = async (item: FeedbackItem) => {
+    if (!currentProject?.githubUrl) return;
+    try {
+      const created = await tauri.createGithubIssue(currentProject.path, currentProject.githubUrl, item.id);
+      if (!created) {
+        alert('GitHub could not be reached. The issue was queued and will be created automatically.');
+      }
+      await refreshProject(currentProject.id);
+    } catch (error) {
+      alert(`Failed to create GitHub issue: ${error}`);
+    }
+  };
+
   const handleRunDev = async () => {
     if (!currentProject || !availableScripts?.dev_script_name) return;
     try {
-      await tauri.runNpmScript(
-        currentProject.path,
-        availableScripts.dev_script_name,
-        availableScripts.dev_script_type || undefined
-      );
-    } catch {
-      // Silently handle error
+      // A dev command set in the project's metadata overrides the detected script
+      if (currentProject.devCommand) {
+        await tauri.startDevServer(currentProject.path, currentProject.devCommand, 'command');
+      } else {
+        await tauri.startDevServer(
+          currentProject.path,
+          availableScripts.dev_script_name,
+          availableScripts.dev_script_type || undefined
+        );
+      }
+    } catch (error) {
+      alert(`Failed to start dev server: ${error}`);
     }
   };
 
   const handleRunBuild = async () => {
     if (!currentProject) return;
+    // The first "build" task: package.json's if there is one, else Cargo's, Go's, make's, ...
+    const buildTask = availableScripts?.tasks.find((task) => task.name === 'build');
     try {
-      await tauri.runNpmScript(currentProject.path, 'build');
+      if (buildTask) {
+        await tauri.runNpmScript(currentProject.path, buildTask.command, 'command');
+      } else {
+        await tauri.runNpmScript(currentProject.path, 'build');
+      }
     } catch {
       // Silently handle error
     }
@@ -493,7 +573,7 @@ export function ProjectDetail() {
       const prompt = generateCleanupPrompt(currentProject.displayName || currentProject.name);
 
       // Launch Claude Code directly - the counter will reset when the cleanup commit is made
-      await tauri.launchClaudeCode(currentProject.path, prompt);
+      await tauri.launchClaudeCode(currentProject.path, prompt, { promptName: 'cleanup' });
     } catch {
       // Silently handle error
     }
@@ -775,6 +855,43 @@ export function ProjectDetail() {
           )}
         </div>
 
+        {/* Pull requests and CI */}
+        {currentProject.githubUrl && currentProject.githubIntegrationEnabled && (
+          <RepoStatusPanel
+            projectPath={currentProject.path}
+            githubUrl={currentProject.githubUrl}
+            textColor={currentProject.textColor}
+          />
+        )}
+
+        {/* Supervised dev server */}
+        <DevServerPanel
+          projectPath={currentProject.path}
+          textColor={currentProject.textColor}
+        />
+
+        {/* Tasks from the project's build files */}
+        {availableScripts && availableScripts.tasks.length > 0 && (
+          <TasksPanel
+            projectPath={currentProject.path}
+            tasks={availableScripts.tasks}
+            textColor={currentProject.textColor}
+          />
+        )}
+
+        {/* Issue worktrees */}
+        <WorktreesPanel
+          projectPath={currentProject.path}
+          issues={issues}
+          textColor={currentProject.textColor}
+        />
+
+        {/* Embedded terminals */}
+        <TerminalPanel
+          projectPath={currentProject.path}
+          textColor={currentProject.textColor}
+        />
+
         {/* Documentation Section */}
         {docs.length > 0 && (
           <div className="mb-6">
@@ -919,6 +1036,7 @@ export function ProjectDetail() {
               onAutomatedWorkflow={handleAutomatedWorkflow}
               onSyncGithub={handleSyncGithub}
               onMarkAsImplemented={handleMarkAsImplemented}
+              onCreateGithubIssue={currentProject.githubUrl && currentProject.githubIntegrationEnabled ? handleCreateGithubIssue : undefined}
             />
           )}
 
@@ -931,6 +1049,9 @@ export function ProjectDetail() {
               onReviewIssue={handleReviewIssue}
               onToggleComplete={handleToggleIssueComplete}
               onDeleteIssue={handleDeleteIssue}
+              onRunHeadless={handleRunHeadless}
+              onQueueAll={handleQueueAllIssues}
+              onStartInWorktree={handleStartInWorktree}
             />
           )}
 
@@ -1007,7 +1128,9 @@ export function ProjectDetail() {
             setIsIssueReviewModalOpen(false);
             setReviewingIssue(undefined);
           }}
+          projectPath={currentProject.path}
           issue={{
+            id: reviewingIssue.id,
             title: reviewingIssue.title,
             description: reviewingIssue.description,
             subtasks: reviewingIssue.subtasks,
@@ -0,0 +1,104 @@
+import { useCallback, useEffect, useState } from 'react';
+import { GitPullRequest, RefreshCw, CheckCircle, XCircle, Clock } from 'lucide-react';
+import * as tauri from '../../services/tauri';
+import type { PullRequestSummary, RepoStatus } from '../../store/types';
+
+interface RepoStatusPanelProps {
+  projectPath: string;
+  githubUrl: string;
+  textColor?: string;
+}
+
+const REVIEW_LABELS: Record<PullRequestSummary['reviewState'], string> = {
+  approved: 'Approved',
+  changes_requested: 'Changes requested',
+  commented: 'Commented',
+  pending: 'Review pending',
+};
+
+function CiBadge({ status }: { status: RepoStatus }) {
+  if (!status.ci) {
+    return <span className="text-sm opacity-70">No CI runs on {status.defaultBranch}</span>;
+  }
+
+  const { ci } = status;
+  const state = ci.status !== 'completed' ? 'pending' : ci.conclusion ?? 'unknown';
+  const [Icon, classes] =
+    state === 'success' ? [CheckCircle, 'bg-green-100 text-green-700'] :
+    state === 'pending' ? [Clock, 'bg-yellow-100 text-yellow-700'] :
+    [XCircle, 'bg-red-100 text-red-700'];
+
+  const badge = (
+    <span className={`inline-flex items-center gap-1 text-sm px-2 py-0.5 rounded ${classes}`}>
+      <Icon size={14} />
+      {ci.branch}: {state}{ci.name ? ` (${ci.name})` : ''}
+    </span>
+  );
+
+  return ci.url ? <button onClick={() => tauri.openUrl(ci.url!)}>{badge}</button> : badge;
+}
+
+// Open pull requests and default-branch CI for the project's GitHub repository
+export function RepoStatusPanel({ projectPath, githubUrl, textColor }: RepoStatusPanelProps) {
+  const [status, setStatus] = useState<RepoStatus | null>(null);
+  const [isRefreshing, setIsRefreshing] = useState(false);
+  const [error, setError] = useState('');
+
+  const refresh = useCallback(async () => {
+    setIsRefreshing(true);
+    setError('');
+    try {
+      setStatus(await tauri.fetchRepoStatus(projectPath, githubUrl));
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setIsRefreshing(false);
+    }
+  }, [projectPath, githubUrl]);
+
+  useEffect(() => {
+    // Show the cached status straight away, then refresh it in the background
+    tauri.getRepoStatus(projectPath).then(setStatus).catch(console.error);
+    refresh();
+  }, [projectPath, refresh]);
+
+  const color = textColor || '#FFFFFF';
+
+  return (
+    <div className="mb-6" style={{ color }}>
+      <div className="flex items-center gap-3 mb-3">
+        <h2 className="text-2xl font-bold uppercase" style={{ textShadow: '2px 2px 0px rgba(0,0,0,1)' }}>Pull Requests</h2>
+        {status && <CiBadge status={status} />}
+        <button onClick={refresh} disabled={isRefreshing} className="opacity-70 hover:opacity-100" title="Refresh">
+          <RefreshCw size={16} className={isRefreshing ? 'animate-spin' : ''} />
+        </button>
+      </div>
+
+      {error && <p className="text-sm text-red-300 mb-2">{error}</p>}
+
+      {status && status.pullRequests.length === 0 && (
+        <p className="text-sm italic opacity-70">No open pull requests.</p>
+      )}
+
+      {status && status.pullRequests.length > 0 && (
+        <ul className="space-y-1">
+          {status.pullRequests.map((pr) => (
+            <li key={pr.number}>
+              <button
+                onClick={() => tauri.openUrl(pr.url)}
+                className="flex flex-wrap items-center gap-2 text-sm text-left hover:underline"
+              >
+                <GitPullRequest size={14} />
+                <span className="font-semibold">#{pr.number} {pr.title}</span>
+                {pr.author && <span className="opacity-70">by {pr.author}</span>}
+                {pr.draft && <span className="bg-gray-100 text-gray-700 px-2 rounded">Draft</span>}
+                <span className="bg-blue-100 text-blue-700 px-2 rounded">{REVIEW_LABELS[pr.reviewState]}</span>
+                {pr.mergeable === false && <span className="bg-red-100 text-red-700 px-2 rounded">Conflicts</span>}
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
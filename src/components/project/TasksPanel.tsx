@@ -0,0 +1,60 @@
+import { useState } from 'react';
+import { ListChecks, Play } from 'lucide-react';
+import * as tauri from '../../services/tauri';
+import type { ProjectTask } from '../../store/types';
+
+interface TasksPanelProps {
+  projectPath: string;
+  tasks: ProjectTask[];
+  textColor?: string;
+}
+
+// Every task the project's build files define, grouped by file; each runs in a terminal
+export function TasksPanel({ projectPath, tasks, textColor = '#FFFFFF' }: TasksPanelProps) {
+  const [expanded, setExpanded] = useState(false);
+
+  const sources = [...new Set(tasks.map((task) => task.source))];
+
+  const runTask = async (task: ProjectTask) => {
+    try {
+      await tauri.runNpmScript(projectPath, task.command, 'command');
+    } catch (error) {
+      alert(`Failed to run ${task.name}: ${error}`);
+    }
+  };
+
+  return (
+    <div className="border-4 border-black rounded-lg p-4 mb-6 bg-black/20" style={{ color: textColor }}>
+      <div className="flex items-center justify-between">
+        <h3 className="font-bold text-lg flex items-center gap-2">
+          <ListChecks size={20} />
+          Tasks ({tasks.length})
+        </h3>
+        <button className="text-sm underline" onClick={() => setExpanded(!expanded)}>
+          {expanded ? 'Hide' : 'Show'}
+        </button>
+      </div>
+
+      {expanded && (
+        <div className="mt-3 space-y-3">
+          {sources.map((source) => (
+            <div key={source}>
+              <div className="text-xs font-mono opacity-70 mb-1">{source}</div>
+              {tasks
+                .filter((task) => task.source === source)
+                .map((task) => (
+                  <div key={`${task.kind}:${task.name}`} className="flex items-center gap-3 text-sm py-0.5">
+                    <button title={`Run ${task.name}`} onClick={() => runTask(task)}>
+                      <Play size={14} />
+                    </button>
+                    <span className="font-bold">{task.name}</span>
+                    <span className="font-mono opacity-80 truncate">{task.command}</span>
+                  </div>
+                ))}
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,265 @@
+import { useEffect, useRef, useState } from 'react';
+import type { ClipboardEvent, KeyboardEvent } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { SquareTerminal, Plus, X, Square } from 'lucide-react';
+import { Button } from '../common/Button';
+import * as tauri from '../../services/tauri';
+
+interface TerminalPanelProps {
+  projectPath: string;
+  textColor?: string;
+  bgColor?: string;
+}
+
+const SHELL = navigator.userAgent.includes('Windows') ? 'powershell.exe' : 'bash';
+
+// Escape sequences for keys that don't produce text
+const SPECIAL_KEYS: Record<string, string> = {
+  Enter: '\r',
+  Backspace: '\x7f',
+  Tab: '\t',
+  Escape: '\x1b',
+  ArrowUp: '\x1b[A',
+  ArrowDown: '\x1b[B',
+  ArrowRight: '\x1b[C',
+  ArrowLeft: '\x1b[D',
+  Home: '\x1b[H',
+  End: '\x1b[F',
+  Delete: '\x1b[3~',
+  PageUp: '\x1b[5~',
+  PageDown: '\x1b[6~',
+};
+
+// What a key press sends to the terminal, or null if it should be left to the browser
+function keyToInput(event: KeyboardEvent): string | null {
+  if (event.key === 'Tab' && event.shiftKey) return '\x1b[Z';
+  if (SPECIAL_KEYS[event.key]) return SPECIAL_KEYS[event.key];
+
+  if (event.ctrlKey && !event.altKey && event.key.length === 1) {
+    const code = event.key.toUpperCase().charCodeAt(0);
+    // Ctrl+A..Ctrl+Z and Ctrl+[ \ ] ^ _ map to control characters 1-31
+    if (code >= 64 && code <= 95) return String.fromCharCode(code - 64);
+    return null;
+  }
+
+  if (event.metaKey || event.key.length !== 1) return null;
+  return event.altKey ? `\x1b${event.key}` : event.key;
+}
+
+export function TerminalPanel({ projectPath, textColor = '#FFFFFF', bgColor }: TerminalPanelProps) {
+  const [sessions, setSessions] = useState<tauri.PtySessionInfo[]>([]);
+  const [activeId, setActiveId] = useState<string | null>(null);
+  const [screens, setScreens] = useState<Record<string, tauri.PtyScreen>>({});
+  const [size, setSize] = useState({ cols: 80, rows: 24 });
+  const screenRef = useRef<HTMLDivElement>(null);
+  const charRef = useRef<HTMLSpanElement>(null);
+
+  const loadSessions = async () => {
+    try {
+      const list = await tauri.listPtySessions(projectPath);
+      setSessions(list);
+      setActiveId((current) =>
+        current && list.some((s) => s.id === current) ? current : list[list.length - 1]?.id ?? null
+      );
+    } catch (error) {
+      console.error('Failed to list terminal sessions:', error);
+    }
+  };
+
+  useEffect(() => {
+    loadSessions();
+
+    const unlistenOutput = listen<tauri.PtyScreen>('pty-output', ({ payload }) => {
+      setScreens((current) => ({ ...current, [payload.id]: payload }));
+    });
+    const unlistenExit = listen<tauri.PtyExit>('pty-exit', ({ payload }) => {
+      if (payload.projectPath === projectPath) {
+        loadSessions();
+      }
+    });
+
+    return () => {
+      unlistenOutput.then((fn) => fn());
+      unlistenExit.then((fn) => fn());
+    };
+  }, [projectPath]);
+
+  // Render the current screen straight away when switching to a session
+  useEffect(() => {
+    if (activeId && !screens[activeId]) {
+      tauri.getPtyScreen(activeId)
+        .then((screen) => setScreens((current) => ({ ...current, [screen.id]: screen })))
+        .catch(() => {});
+    }
+  }, [activeId]);
+
+  // Fit the terminal grid to the panel
+  useEffect(() => {
+    const element = screenRef.current;
+    if (!element) return;
+
+    const observer = new ResizeObserver(() => {
+      const char = charRef.current?.getBoundingClientRect();
+      if (!char || char.width === 0 || char.height === 0) return;
+      const cols = Math.max(20, Math.floor((element.clientWidth - 16) / char.width));
+      const rows = Math.max(5, Math.floor((element.clientHeight - 16) / char.height));
+      setSize((current) => (current.cols === cols && current.rows === rows ? current : { cols, rows }));
+    });
+    observer.observe(element);
+    return () => observer.disconnect();
+  }, [sessions.length > 0]);
+
+  useEffect(() => {
+    const active = sessions.find((s) => s.id === activeId);
+    if (active?.status === 'running' && (active.cols !== size.cols || active.rows !== size.rows)) {
+      tauri.resizePty(active.id, size.cols, size.rows)
+        .then(() => setSessions((current) =>
+          current.map((s) => (s.id === active.id ? { ...s, cols: size.cols, rows: size.rows } : s))
+        ))
+        .catch((error) => console.error('Failed to resize terminal:', error));
+    }
+  }, [activeId, size, sessions]);
+
+  const handleNewSession = async (command?: string) => {
+    try {
+      const session = await tauri.spawnPty(projectPath, size.cols, size.rows, command);
+      setSessions((current) => [...current, session]);
+      setActiveId(session.id);
+      screenRef.current?.focus();
+    } catch (error) {
+      alert(`Failed to start terminal: ${error}`);
+    }
+  };
+
+  const handleClose = async (id: string) => {
+    try {
+      await tauri.closePty(id);
+      setScreens(({ [id]: _closed, ...rest }) => rest);
+      await loadSessions();
+    } catch (error) {
+      console.error('Failed to close terminal:', error);
+    }
+  };
+
+  const sendInput = (data: string) => {
+    const active = sessions.find((s) => s.id === activeId);
+    if (active?.status === 'running') {
+      tauri.writePty(active.id, data).catch((error) => console.error('Failed to write to terminal:', error));
+    }
+  };
+
+  const handleKeyDown = (event: KeyboardEvent<HTMLDivElement>) => {
+    // Leave Ctrl+Shift+C/V to the browser for copy and paste
+    if (event.ctrlKey && event.shiftKey) return;
+    const input = keyToInput(event);
+    if (input !== null) {
+      event.preventDefault();
+      sendInput(input);
+    }
+  };
+
+  const handlePaste = (event: ClipboardEvent<HTMLDivElement>) => {
+    event.preventDefault();
+    sendInput(event.clipboardData.getData('text').replace(/\r?\n/g, '\r'));
+  };
+
+  const active = sessions.find((s) => s.id === activeId);
+  const screen = activeId ? screens[activeId] : undefined;
+
+  return (
+    <div
+      className="border-4 border-black rounded-lg p-4 mb-6 bg-opacity-20"
+      style={{ backgroundColor: bgColor || 'rgba(0, 0, 0, 0.2)' }}
+    >
+      <div className="flex items-center justify-between mb-3">
+        <div className="flex items-center gap-3">
+          <SquareTerminal size={24} style={{ color: textColor }} />
+          <h3 className="font-bold text-lg" style={{ color: textColor }}>
+            Terminals
+          </h3>
+        </div>
+        <div className="flex gap-2">
+          <Button
+            variant="secondary"
+            size="sm"
+            onClick={() => handleNewSession()}
+            invertedBgColor={textColor}
+            invertedTextColor={bgColor}
+          >
+            <Plus size={16} className="inline mr-2" />
+            Claude
+          </Button>
+          <Button
+            variant="secondary"
+            size="sm"
+            onClick={() => handleNewSession(SHELL)}
+            invertedBgColor={textColor}
+            invertedTextColor={bgColor}
+          >
+            <Plus size={16} className="inline mr-2" />
+            Shell
+          </Button>
+        </div>
+      </div>
+
+      {sessions.length > 0 && (
+        <>
+          <div className="flex flex-wrap gap-1 mb-2">
+            {sessions.map((session) => (
+              <div
+                key={session.id}
+                className={`flex items-center gap-2 px-3 py-1 rounded-t text-sm font-mono cursor-pointer ${
+                  session.id === activeId ? 'bg-black text-green-200' : 'bg-black/40 text-gray-300'
+                }`}
+                onClick={() => setActiveId(session.id)}
+              >
+                <span>
+                  {session.command === 'cmd.exe' ? session.args.slice(1).join(' ') : session.command}
+                  {session.status === 'exited' && ` (exit ${session.exitCode ?? '?'})`}
+                </span>
+                {session.status === 'running' && (
+                  <button
+                    title="Stop"
+                    onClick={(e) => { e.stopPropagation(); tauri.killPty(session.id); }}
+                  >
+                    <Square size={12} />
+                  </button>
+                )}
+                <button
+                  title="Close"
+                  onClick={(e) => { e.stopPropagation(); handleClose(session.id); }}
+                >
+                  <X size={14} />
+                </button>
+              </div>
+            ))}
+          </div>
+
+          <div
+            ref={screenRef}
+            tabIndex={0}
+            onKeyDown={handleKeyDown}
+            onPaste={handlePaste}
+            className="relative h-96 p-2 bg-black text-gray-100 text-xs font-mono rounded overflow-hidden outline-none focus:ring-2 focus:ring-green-400"
+          >
+            <span ref={charRef} className="absolute invisible whitespace-pre leading-tight">M</span>
+            {screen?.lines.map((line, row) => {
+              const showCursor = active?.status === 'running' && !screen.hideCursor && row === screen.cursorRow;
+              if (!showCursor) {
+                return <div key={row} className="whitespace-pre leading-tight">{line || ' '}</div>;
+              }
+              const padded = line.padEnd(screen.cursorCol + 1, ' ');
+              return (
+                <div key={row} className="whitespace-pre leading-tight">
+                  {padded.slice(0, screen.cursorCol)}
+                  <span className="bg-gray-100 text-black">{padded[screen.cursorCol]}</span>
+                  {padded.slice(screen.cursorCol + 1)}
+                </div>
+              );
+            })}
+          </div>
+        </>
+      )}
+    </div>
+  );
+}
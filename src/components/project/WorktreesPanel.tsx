@@ -0,0 +1,98 @@
+import { useCallback, useEffect, useState } from 'react';
+import { GitBranch, GitMerge, Terminal, Code, Trash2 } from 'lucide-react';
+import * as tauri from '../../services/tauri';
+import type { Issue, WorktreeInfo } from '../../store/types';
+
+interface WorktreesPanelProps {
+  projectPath: string;
+  issues: Issue[];
+  textColor?: string;
+}
+
+// Worktrees issues are being worked on in, with merge-back and cleanup
+export function WorktreesPanel({ projectPath, issues, textColor = '#FFFFFF' }: WorktreesPanelProps) {
+  const [worktrees, setWorktrees] = useState<WorktreeInfo[]>([]);
+
+  const loadWorktrees = useCallback(async () => {
+    try {
+      setWorktrees(await tauri.listWorktrees(projectPath));
+    } catch (error) {
+      console.error('Failed to list worktrees:', error);
+    }
+  }, [projectPath]);
+
+  useEffect(() => {
+    loadWorktrees();
+  }, [loadWorktrees, issues]);
+
+  const run = async (action: () => Promise<unknown>, failure: string) => {
+    try {
+      await action();
+    } catch (error) {
+      alert(`${failure}: ${error}`);
+    }
+    await loadWorktrees();
+  };
+
+  const handleMerge = (worktree: WorktreeInfo, strategy: 'fast-forward' | 'squash') =>
+    run(() => tauri.mergeWorktree(projectPath, worktree.issueId, strategy), 'Failed to merge worktree');
+
+  const handleRemove = (worktree: WorktreeInfo) => {
+    const unmerged = !worktree.mergedAt && (worktree.ahead > 0 || worktree.dirty);
+    if (unmerged && !confirm(`${worktree.branch} has changes that were never merged. Remove it and delete the branch anyway?`)) {
+      return;
+    }
+    run(() => tauri.removeWorktree(projectPath, worktree.issueId, true), 'Failed to remove worktree');
+  };
+
+  if (worktrees.length === 0) return null;
+
+  const issueTitle = (issueId: string) => issues.find(i => i.id === issueId)?.title ?? 'Completed or deleted issue';
+
+  return (
+    <div className="border-4 border-black rounded-lg p-4 mb-6 bg-black/20">
+      <h3 className="font-bold text-lg mb-3 flex items-center gap-2" style={{ color: textColor }}>
+        <GitBranch size={20} />
+        Worktrees
+      </h3>
+      <ul className="space-y-2">
+        {worktrees.map((worktree) => (
+          <li key={worktree.issueId} className="flex items-center justify-between gap-4 text-sm" style={{ color: textColor }}>
+            <div className="min-w-0">
+              <div className="font-medium truncate">{issueTitle(worktree.issueId)}</div>
+              <div className="font-mono opacity-80">
+                {worktree.branch} → {worktree.baseBranch}
+                {' · '}
+                {worktree.mergedAt
+                  ? 'merged'
+                  : `${worktree.ahead} commit(s) ahead${worktree.dirty ? ', uncommitted changes' : ''}`}
+                {!worktree.exists && ' · missing'}
+              </div>
+            </div>
+            <div className="flex items-center gap-3 shrink-0">
+              <button title="Open terminal" onClick={() => run(() => tauri.openWorktree(projectPath, worktree.issueId, 'terminal'), 'Failed to open worktree')}>
+                <Terminal size={16} />
+              </button>
+              <button title="Open in VS Code" onClick={() => run(() => tauri.openWorktree(projectPath, worktree.issueId, 'vscode'), 'Failed to open worktree')}>
+                <Code size={16} />
+              </button>
+              {!worktree.mergedAt && (
+                <>
+                  <button title="Fast-forward merge" onClick={() => handleMerge(worktree, 'fast-forward')} className="flex items-center gap-1">
+                    <GitMerge size={16} /> FF
+                  </button>
+                  <button title="Squash merge" onClick={() => handleMerge(worktree, 'squash')} className="flex items-center gap-1">
+                    <GitMerge size={16} /> Squash
+                  </button>
+                </>
+              )}
+              <button title="Remove worktree and branch" onClick={() => handleRemove(worktree)} className="text-red-300 hover:text-red-100">
+                <Trash2 size={16} />
+              </button>
+            </div>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
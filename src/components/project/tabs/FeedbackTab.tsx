@@ -1,4 +1,4 @@
-import { Plus, Edit, Trash2, Wrench, Github, Zap, CheckCircle } from 'lucide-react';
+import { Plus, Edit, Trash2, Wrench, Github, Zap, CheckCircle, Upload, MessageSquare } from 'lucide-react';
 import { Button } from '../../common/Button';
 import type { FeedbackItem, Project } from '../../../store/types';
 import { PRIORITY_LABELS, PRIORITY_COLORS } from '../../../store/types';
@@ -15,6 +15,7 @@ interface FeedbackTabProps {
   onAutomatedWorkflow: () => void;
   onSyncGithub?: () => void;
   onMarkAsImplemented?: (item: FeedbackItem) => void;
+  onCreateGithubIssue?: (item: FeedbackItem) => void;
 }
 
 export function FeedbackTab({
@@ -28,6 +29,7 @@ export function FeedbackTab({
   onAutomatedWorkflow,
   onSyncGithub,
   onMarkAsImplemented,
+  onCreateGithubIssue,
 }: FeedbackTabProps) {
   const pendingFeedback = feedback.filter(f => f.status === 'pending' || f.status === 'needs-review');
 
@@ -151,6 +153,19 @@ export function FeedbackTab({
                         <p className="text-white/90 text-sm font-semibold">Click to answer clarification question</p>
                       </div>
                     )}
+                    {item.comments && item.comments.length > 0 && (
+                      <div className="mt-3 space-y-2 border-l-2 border-white/40 pl-3">
+                        {item.comments.map((comment) => (
+                          <div key={comment.id} className="text-sm">
+                            <span className="text-white/80 font-semibold">{comment.author}</span>
+                            {formatDate(comment.createdAt) && (
+                              <span className="text-white/60 ml-2">{formatDate(comment.createdAt)}</span>
+                            )}
+                            <p className="text-white whitespace-pre-wrap">{comment.body}</p>
+                          </div>
+                        ))}
+                      </div>
+                    )}
                   </div>
                   <div className="flex flex-col gap-2 items-end">
                     {item.status === 'needs-review' && (
@@ -170,6 +185,18 @@ export function FeedbackTab({
                         #{item.githubIssueNumber}
                       </a>
                     )}
+                    {item.githubDiscussionNumber && (
+                      <a
+                        href={item.githubDiscussionUrl || '#'}
+                        target="_blank"
+                        rel="noopener noreferrer"
+                        className="bg-gray-900 text-white text-xs font-bold px-2 py-1 rounded flex items-center gap-1 hover:bg-gray-700 transition-colors"
+                        onClick={(e) => e.stopPropagation()}
+                      >
+                        <MessageSquare size={12} />
+                        #{item.githubDiscussionNumber}
+                      </a>
+                    )}
                     <span className={`${PRIORITY_COLORS[item.priority]} text-white text-base px-2 py-1 rounded whitespace-nowrap`}>
                       {PRIORITY_LABELS[item.priority]}
                     </span>
@@ -186,6 +213,12 @@ export function FeedbackTab({
                         Already Implemented
                       </button>
                     )}
+                    {!item.githubIssueNumber && onCreateGithubIssue && (
+                      <button onClick={() => onCreateGithubIssue(item)} className="text-blue-300 hover:text-blue-100">
+                        <Upload size={14} className="inline mr-1" />
+                        Create on GitHub
+                      </button>
+                    )}
                     <button onClick={() => onEditFeedback(item)} className="text-yellow-300 hover:text-yellow-100">
                       <Edit size={14} className="inline mr-1" />
                       Edit
@@ -1,4 +1,4 @@
-import { Hammer, Trash2, CheckCircle } from 'lucide-react';
+import { Hammer, Trash2, CheckCircle, Bot, ListPlus, GitBranch } from 'lucide-react';
 import { Button } from '../../common/Button';
 import type { Issue, Project } from '../../../store/types';
 import { PRIORITY_LABELS, PRIORITY_COLORS, COMPLEXITY_LABELS, COMPLEXITY_COLORS } from '../../../store/types';
@@ -10,6 +10,9 @@ interface IssuesTabProps {
   onReviewIssue: (issue: Issue) => void;
   onToggleComplete: (id: string) => void;
   onDeleteIssue: (id: string) => void;
+  onRunHeadless?: (issue: Issue) => void;
+  onQueueAll?: () => void;
+  onStartInWorktree?: (issue: Issue) => void;
 }
 
 export function IssuesTab({
@@ -19,6 +22,9 @@ export function IssuesTab({
   onReviewIssue,
   onToggleComplete,
   onDeleteIssue,
+  onRunHeadless,
+  onQueueAll,
+  onStartInWorktree,
 }: IssuesTabProps) {
   const pendingIssues = issues.filter(i => i.status !== 'completed');
   const reviewIssues = issues.filter(i => i.status === 'for-review');
@@ -40,6 +46,17 @@ export function IssuesTab({
               Fix All
             </Button>
           )}
+          {onQueueAll && (activeIssues.length > 0 || needsReworkIssues.length > 0) && (
+            <Button
+              variant="secondary"
+              onClick={onQueueAll}
+              invertedBgColor={currentProject.textColor}
+              invertedTextColor={currentProject.color}
+            >
+              <ListPlus size={18} className="inline mr-2" />
+              Queue All
+            </Button>
+          )}
         </div>
       </div>
 
@@ -112,6 +129,12 @@ export function IssuesTab({
                               <p className="text-white text-sm">{issue.bugReport}</p>
                             </div>
                           )}
+                          {issue.lastRunError && (
+                            <div className="bg-red-900/50 border-2 border-red-400 rounded p-3 mb-3">
+                              <p className="text-xs font-bold text-red-200 mb-1">LAST HEADLESS RUN FAILED:</p>
+                              <p className="text-white text-sm whitespace-pre-wrap">{issue.lastRunError}</p>
+                            </div>
+                          )}
                           <div className="flex items-center gap-4 text-sm">
                             <span className={`${COMPLEXITY_COLORS[issue.complexity]} text-white px-2 py-1 rounded font-semibold`}>
                               {COMPLEXITY_LABELS[issue.complexity]}
@@ -119,6 +142,18 @@ export function IssuesTab({
                             <span className={`${PRIORITY_COLORS[issue.priority]} text-white px-2 py-1 rounded`}>
                               {PRIORITY_LABELS[issue.priority]}
                             </span>
+                            {onStartInWorktree && (
+                              <button onClick={() => onStartInWorktree(issue)} className="text-white/80 hover:text-white">
+                                <GitBranch size={14} className="inline mr-1" />
+                                Worktree
+                              </button>
+                            )}
+                            {onRunHeadless && issue.status !== 'in-progress' && (
+                              <button onClick={() => onRunHeadless(issue)} className="text-white/80 hover:text-white">
+                                <Bot size={14} className="inline mr-1" />
+                                Run Headless
+                              </button>
+                            )}
                             <button onClick={() => onDeleteIssue(issue.id)} className="text-red-200 hover:text-white">
                               <Trash2 size={14} className="inline mr-1" />
                               Delete
@@ -151,6 +186,12 @@ export function IssuesTab({
                             <h3 className="text-white font-bold text-lg">{issue.title}</h3>
                           </div>
                           <p className="text-white/90 mb-3">{issue.description}</p>
+                          {issue.lastRunError && (
+                            <div className="bg-red-900/50 border-2 border-red-400 rounded p-3 mb-3">
+                              <p className="text-xs font-bold text-red-200 mb-1">LAST HEADLESS RUN FAILED:</p>
+                              <p className="text-white text-sm whitespace-pre-wrap">{issue.lastRunError}</p>
+                            </div>
+                          )}
                           <div className="flex items-center gap-4 text-sm">
                             <span className={`${COMPLEXITY_COLORS[issue.complexity]} text-white px-2 py-1 rounded font-semibold`}>
                               {COMPLEXITY_LABELS[issue.complexity]}
@@ -158,6 +199,18 @@ export function IssuesTab({
                             <span className={`${PRIORITY_COLORS[issue.priority]} text-white px-2 py-1 rounded`}>
                               {PRIORITY_LABELS[issue.priority]}
                             </span>
+                            {onStartInWorktree && (
+                              <button onClick={() => onStartInWorktree(issue)} className="text-white/80 hover:text-white">
+                                <GitBranch size={14} className="inline mr-1" />
+                                Worktree
+                              </button>
+                            )}
+                            {onRunHeadless && issue.status !== 'in-progress' && (
+                              <button onClick={() => onRunHeadless(issue)} className="text-white/80 hover:text-white">
+                                <Bot size={14} className="inline mr-1" />
+                                Run Headless
+                              </button>
+                            )}
                             <button onClick={() => onToggleComplete(issue.id)} className="text-green-300 hover:text-green-100">
                               <CheckCircle size={14} className="inline mr-1" />
                               Complete
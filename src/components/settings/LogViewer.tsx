@@ -0,0 +1,117 @@
+import { useCallback, useEffect, useState } from 'react';
+import { RefreshCw } from 'lucide-react';
+import { Button } from '../common/Button';
+import { useProjectStore } from '../../store/projectStore';
+import * as tauri from '../../services/tauri';
+import type { LogEntry, LogLevel } from '../../store/types';
+
+const LEVEL_STYLES: Record<LogLevel, string> = {
+  ERROR: 'text-red-600',
+  WARN: 'text-amber-600',
+  INFO: 'text-gray-700',
+  DEBUG: 'text-gray-400',
+  TRACE: 'text-gray-400',
+};
+
+// Time range choices, in hours back from now
+const RANGES: { label: string; hours?: number }[] = [
+  { label: 'Last hour', hours: 1 },
+  { label: 'Last 24 hours', hours: 24 },
+  { label: 'Last 7 days', hours: 24 * 7 },
+  { label: 'All time' },
+];
+
+// Searchable view of the app log, for diagnosing problems without opening the files
+export function LogViewer() {
+  const { projects } = useProjectStore();
+  const [entries, setEntries] = useState<LogEntry[]>([]);
+  const [level, setLevel] = useState<LogLevel>('INFO');
+  const [module, setModule] = useState('');
+  const [projectPath, setProjectPath] = useState('');
+  const [rangeHours, setRangeHours] = useState<number | undefined>(24);
+
+  const loadEntries = useCallback(async () => {
+    try {
+      const since = rangeHours ? new Date(Date.now() - rangeHours * 3600 * 1000).toISOString() : undefined;
+      setEntries(await tauri.queryLogs({
+        level,
+        module: module.trim() || undefined,
+        projectPath: projectPath || undefined,
+        since,
+      }));
+    } catch (error) {
+      console.error('Failed to query logs:', error);
+    }
+  }, [level, module, projectPath, rangeHours]);
+
+  useEffect(() => {
+    loadEntries();
+  }, [loadEntries]);
+
+  const projectName = (path: string) => {
+    const project = projects.find(p => p.path === path);
+    return project?.displayName || project?.name || path;
+  };
+
+  return (
+    <div className="mt-4">
+      <div className="flex flex-wrap gap-2 mb-2">
+        <select
+          value={level}
+          onChange={(e) => setLevel(e.target.value as LogLevel)}
+          className="border border-gray-300 rounded-lg px-3 py-2 text-sm"
+        >
+          <option value="ERROR">Errors</option>
+          <option value="WARN">Warnings and up</option>
+          <option value="INFO">Info and up</option>
+          <option value="DEBUG">Everything</option>
+        </select>
+        <input
+          value={module}
+          onChange={(e) => setModule(e.target.value)}
+          placeholder="Module, e.g. runs"
+          className="w-40 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+        />
+        <select
+          value={projectPath}
+          onChange={(e) => setProjectPath(e.target.value)}
+          className="border border-gray-300 rounded-lg px-3 py-2 text-sm"
+        >
+          <option value="">All projects</option>
+          {projects.map((project) => (
+            <option key={project.id} value={project.path}>{project.displayName || project.name}</option>
+          ))}
+        </select>
+        <select
+          value={rangeHours ?? ''}
+          onChange={(e) => setRangeHours(e.target.value ? Number(e.target.value) : undefined)}
+          className="border border-gray-300 rounded-lg px-3 py-2 text-sm"
+        >
+          {RANGES.map((range) => (
+            <option key={range.label} value={range.hours ?? ''}>{range.label}</option>
+          ))}
+        </select>
+        <Button onClick={loadEntries} variant="secondary" size="sm">
+          <RefreshCw size={14} className="inline mr-1" />
+          Refresh
+        </Button>
+      </div>
+
+      {entries.length === 0 ? (
+        <p className="text-gray-500 text-sm">No log entries match.</p>
+      ) : (
+        <ul className="max-h-96 overflow-y-auto border border-gray-200 rounded-lg divide-y divide-gray-100 font-mono text-xs">
+          {entries.map((entry, index) => (
+            <li key={index} className="px-3 py-1">
+              <span className="text-gray-400">{new Date(entry.timestamp).toLocaleString()}</span>{' '}
+              <span className={`font-bold ${LEVEL_STYLES[entry.level]}`}>{entry.level}</span>{' '}
+              <span className="text-blue-600">[{entry.module}]</span>{' '}
+              {entry.project && <span className="text-purple-600">{projectName(entry.project)}: </span>}
+              <span className="whitespace-pre-wrap break-words">{entry.message}</span>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
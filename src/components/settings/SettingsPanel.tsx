@@ -1,15 +1,31 @@
 import { useNavigate } from 'react-router-dom';
-import { ArrowLeft, Folder, FileText, Github } from 'lucide-react';
+import { ArrowLeft, Folder, FileText, Github, Trash2, Lock } from 'lucide-react';
 import { useSettingsStore } from '../../store/settingsStore';
 import { Button } from '../common/Button';
+import { LogViewer } from './LogViewer';
 import { APP_NAME } from '../../utils/constants';
 import * as tauri from '../../services/tauri';
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
+import type { AgentProfile, PromptMode, TerminalProfile, TrackerHost, TrackerKind } from '../../store/types';
 
 export function SettingsPanel() {
   const navigate = useNavigate();
-  const { settings, selectDirectory, updateSoundEffectsEnabled, updateLaunchOnStartup, updateAutoRefineOnStartup, updateGithubToken, updateGithubIntegrationEnabled } = useSettingsStore();
-  const [githubTokenInput, setGithubTokenInput] = useState(settings?.githubToken || '');
+  const { settings, credentialStatus, selectDirectory, updateSoundEffectsEnabled, updateLaunchOnStartup, updateAutoRefineOnStartup, updateGithubToken, updateGithubIntegrationEnabled, updateTrackerHosts, updateTerminalProfile, updateTerminalProfiles, updateTmuxSessions, updateAgentProfile, updateAgentProfiles, unlockCredentials } = useSettingsStore();
+  const [githubTokenInput, setGithubTokenInput] = useState('');
+  const [passphraseInput, setPassphraseInput] = useState('');
+  const [trackerHostsInput, setTrackerHostsInput] = useState<TrackerHost[]>(settings?.trackerHosts || []);
+  const [terminalProfiles, setTerminalProfiles] = useState<TerminalProfile[]>([]);
+  const [customTerminalsInput, setCustomTerminalsInput] = useState<TerminalProfile[]>(settings?.terminalProfiles || []);
+  const [agentProfiles, setAgentProfiles] = useState<AgentProfile[]>([]);
+  const [customAgentsInput, setCustomAgentsInput] = useState<AgentProfile[]>(settings?.agentProfiles || []);
+
+  useEffect(() => {
+    tauri.getTerminalProfiles().then(setTerminalProfiles).catch(console.error);
+  }, [settings?.terminalProfiles]);
+
+  useEffect(() => {
+    tauri.getAgentProfiles().then(setAgentProfiles).catch(console.error);
+  }, [settings?.agentProfiles]);
 
   const handleSelectDirectory = async () => {
     try {
@@ -58,9 +74,90 @@ export function SettingsPanel() {
   const handleSaveGithubToken = async () => {
     try {
       await updateGithubToken(githubTokenInput || undefined);
+      setGithubTokenInput('');
       alert('GitHub token saved successfully!');
-    } catch {
-      alert('Failed to save GitHub token');
+    } catch (error) {
+      alert(`Failed to save GitHub token: ${error}`);
+    }
+  };
+
+  const handleRemoveGithubToken = async () => {
+    if (!confirm('Remove the saved GitHub token?')) return;
+    try {
+      await updateGithubToken(undefined);
+    } catch (error) {
+      alert(`Failed to remove GitHub token: ${error}`);
+    }
+  };
+
+  const handleUnlockCredentials = async () => {
+    try {
+      await unlockCredentials(passphraseInput);
+      setPassphraseInput('');
+    } catch (error) {
+      alert(`Failed to unlock credentials: ${error}`);
+    }
+  };
+
+  const handleTrackerHostChange = (index: number, changes: Partial<TrackerHost>) => {
+    setTrackerHostsInput(hosts => hosts.map((h, i) => (i === index ? { ...h, ...changes } : h)));
+  };
+
+  const handleSaveTrackerHosts = async () => {
+    try {
+      const hosts = trackerHostsInput
+        .filter(h => h.host.trim())
+        .map(h => ({ ...h, host: h.host.trim(), apiUrl: h.apiUrl?.trim() || undefined, token: h.token || undefined }));
+      await updateTrackerHosts(hosts);
+      setTrackerHostsInput(hosts);
+      alert('Issue trackers saved successfully!');
+    } catch (error) {
+      alert(`Failed to save issue trackers: ${error}`);
+    }
+  };
+
+  const handleCustomTerminalChange = (index: number, changes: Partial<TerminalProfile>) => {
+    setCustomTerminalsInput(profiles => profiles.map((p, i) => (i === index ? { ...p, ...changes } : p)));
+  };
+
+  // Argument templates are edited as space-separated text; placeholders are substituted after splitting
+  const splitArgs = (value: string) => value.split(/\s+/).filter(Boolean);
+
+  const handleSaveCustomTerminals = async () => {
+    try {
+      const profiles = customTerminalsInput
+        .filter(p => p.name.trim() && p.program.trim())
+        .map(p => ({ ...p, name: p.name.trim(), program: p.program.trim() }));
+      await updateTerminalProfiles(profiles);
+      setCustomTerminalsInput(profiles);
+      alert('Terminal profiles saved successfully!');
+    } catch (error) {
+      alert(`Failed to save terminal profiles: ${error}`);
+    }
+  };
+
+  const handleCustomAgentChange = (index: number, changes: Partial<AgentProfile>) => {
+    setCustomAgentsInput(profiles => profiles.map((p, i) => (i === index ? { ...p, ...changes } : p)));
+  };
+
+  // Environment variables are edited as space-separated KEY=VALUE pairs
+  const parseEnv = (value: string) => Object.fromEntries(
+    splitArgs(value)
+      .filter(pair => pair.includes('='))
+      .map(pair => [pair.slice(0, pair.indexOf('=')), pair.slice(pair.indexOf('=') + 1)])
+  );
+  const formatEnv = (env: Record<string, string>) => Object.entries(env).map(([k, v]) => `${k}=${v}`).join(' ');
+
+  const handleSaveCustomAgents = async () => {
+    try {
+      const profiles = customAgentsInput
+        .filter(p => p.name.trim() && p.program.trim())
+        .map(p => ({ ...p, name: p.name.trim(), program: p.program.trim() }));
+      await updateAgentProfiles(profiles);
+      setCustomAgentsInput(profiles);
+      alert('Agent profiles saved successfully!');
+    } catch (error) {
+      alert(`Failed to save agent profiles: ${error}`);
     }
   };
 
@@ -72,9 +169,9 @@ export function SettingsPanel() {
     try {
       const logPath = await tauri.getDebugLogPath();
       alert(`Debug log location:\n${logPath}\n\nOpening in Explorer...`);
-      // Open the temp folder
-      const tempDir = logPath.substring(0, logPath.lastIndexOf('\\'));
-      await tauri.openInExplorer(tempDir);
+      // Open the log folder
+      const logDir = logPath.substring(0, Math.max(logPath.lastIndexOf('\\'), logPath.lastIndexOf('/')));
+      await tauri.openInExplorer(logDir);
     } catch (error) {
       alert(`Failed to get debug log path: ${error}`);
     }
@@ -253,6 +350,31 @@ export function SettingsPanel() {
               </div>
             </label>
 
+            {settings?.githubIntegrationEnabled && credentialStatus?.locked && (
+              <div className="mt-4 pt-4 border-t border-gray-200">
+                <label className="block text-sm font-medium text-gray-700 mb-2">
+                  <Lock size={14} className="inline mr-1" />
+                  {credentialStatus.hasEncryptedFile ? 'Unlock Saved Tokens' : 'Set a Passphrase for Tokens'}
+                </label>
+                <p className="text-gray-600 text-xs mb-2">
+                  No system keyring is available, so tokens are kept in a file encrypted with this passphrase. It is needed once per app start.
+                </p>
+                <div className="flex gap-2">
+                  <input
+                    type="password"
+                    value={passphraseInput}
+                    onChange={(e) => setPassphraseInput(e.target.value)}
+                    onKeyDown={(e) => e.key === 'Enter' && handleUnlockCredentials()}
+                    placeholder="Passphrase"
+                    className="flex-1 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                  />
+                  <Button onClick={handleUnlockCredentials} variant="secondary" size="sm" disabled={!passphraseInput}>
+                    {credentialStatus.hasEncryptedFile ? 'Unlock' : 'Set Passphrase'}
+                  </Button>
+                </div>
+              </div>
+            )}
+
             {settings?.githubIntegrationEnabled && (
               <div className="mt-4 pt-4 border-t border-gray-200">
                 <label className="block text-sm font-medium text-gray-700 mb-2">
@@ -265,14 +387,292 @@ export function SettingsPanel() {
                   type="password"
                   value={githubTokenInput}
                   onChange={(e) => setGithubTokenInput(e.target.value)}
-                  placeholder="ghp_xxxxxxxxxxxx"
+                  placeholder={credentialStatus?.githubTokenSet ? 'Token saved. Enter a new one to replace it' : 'ghp_xxxxxxxxxxxx'}
                   className="w-full border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm mb-2"
                 />
-                <Button onClick={handleSaveGithubToken} variant="secondary" size="sm">
-                  Save Token
-                </Button>
+                <div className="flex gap-2">
+                  <Button onClick={handleSaveGithubToken} variant="secondary" size="sm" disabled={!githubTokenInput}>
+                    Save Token
+                  </Button>
+                  {credentialStatus?.githubTokenSet && (
+                    <Button onClick={handleRemoveGithubToken} variant="danger" size="sm">
+                      Remove Token
+                    </Button>
+                  )}
+                </div>
               </div>
             )}
+
+            {settings?.githubIntegrationEnabled && (
+              <div className="mt-4 pt-4 border-t border-gray-200">
+                <label className="block text-sm font-medium text-gray-700 mb-2">
+                  Self-hosted Issue Trackers
+                </label>
+                <p className="text-gray-600 text-xs mb-2">
+                  GitHub Enterprise, GitLab and Gitea hosts. The tracker is picked from the project's git remote, or from a "Tracker: gitlab" line in .vibe/metadata.md. Leave the API URL empty to use the host's standard API path.
+                </p>
+                {trackerHostsInput.map((host, index) => (
+                  <div key={index} className="flex gap-2 mb-2">
+                    <input
+                      value={host.host}
+                      onChange={(e) => handleTrackerHostChange(index, { host: e.target.value })}
+                      placeholder="gitlab.example.com"
+                      className="flex-1 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                    />
+                    <select
+                      value={host.kind}
+                      onChange={(e) => handleTrackerHostChange(index, { kind: e.target.value as TrackerKind })}
+                      className="border border-gray-300 rounded-lg px-2 py-2 text-sm"
+                    >
+                      <option value="github">GitHub Enterprise</option>
+                      <option value="gitlab">GitLab</option>
+                      <option value="gitea">Gitea</option>
+                    </select>
+                    <input
+                      value={host.apiUrl || ''}
+                      onChange={(e) => handleTrackerHostChange(index, { apiUrl: e.target.value })}
+                      placeholder="API URL (optional)"
+                      className="flex-1 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                    />
+                    <input
+                      type="password"
+                      value={host.token || ''}
+                      onChange={(e) => handleTrackerHostChange(index, { token: e.target.value })}
+                      placeholder={credentialStatus?.trackerTokenHosts.includes(host.host.trim()) ? 'Token saved' : 'Token'}
+                      className="flex-1 border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm"
+                    />
+                    <button
+                      onClick={() => setTrackerHostsInput(hosts => hosts.filter((_, i) => i !== index))}
+                      className="text-gray-400 hover:text-red-600"
+                      title="Remove tracker"
+                    >
+                      <Trash2 size={16} />
+                    </button>
+                  </div>
+                ))}
+                <div className="flex gap-2">
+                  <Button
+                    onClick={() => setTrackerHostsInput(hosts => [...hosts, { host: '', kind: 'gitlab' }])}
+                    variant="secondary"
+                    size="sm"
+                  >
+                    Add Tracker
+                  </Button>
+                  <Button onClick={handleSaveTrackerHosts} variant="secondary" size="sm">
+                    Save Trackers
+                  </Button>
+                </div>
+              </div>
+            )}
+          </div>
+        </div>
+
+        <div className="bg-white rounded-lg shadow p-6">
+          <h2 className="text-lg font-semibold text-gray-900 mb-4">
+            Terminal
+          </h2>
+
+          <p className="text-gray-600 text-sm mb-4">
+            Terminal emulator used on Linux to launch Claude Code, open a terminal and run scripts.
+          </p>
+
+          <select
+            value={settings?.terminalProfile || ''}
+            onChange={(e) => updateTerminalProfile(e.target.value || undefined).catch((error) => alert(`Failed to save terminal: ${error}`))}
+            className="border border-gray-300 rounded-lg px-3 py-2 text-sm mb-4"
+          >
+            <option value="">Automatic{terminalProfiles[0] ? ` (${terminalProfiles[0].name})` : ''}</option>
+            {terminalProfiles.map((profile) => (
+              <option key={profile.name} value={profile.name}>{profile.name}</option>
+            ))}
+          </select>
+
+          <label className="flex items-center cursor-pointer mb-2">
+            <div className="relative">
+              <input
+                type="checkbox"
+                checked={settings?.tmuxSessions ?? false}
+                onChange={() => updateTmuxSessions(!settings?.tmuxSessions).catch((error) => alert(`Failed to save tmux mode: ${error}`))}
+                className="sr-only"
+              />
+              <div className={`block w-14 h-8 rounded-full transition-colors ${
+                settings?.tmuxSessions ? 'bg-blue-600' : 'bg-gray-300'
+              }`}></div>
+              <div className={`absolute left-1 top-1 bg-white w-6 h-6 rounded-full transition-transform ${
+                settings?.tmuxSessions ? 'transform translate-x-6' : ''
+              }`}></div>
+            </div>
+            <div className="ml-3 text-gray-700 font-medium">
+              tmux sessions {settings?.tmuxSessions ? 'enabled' : 'disabled'}
+            </div>
+          </label>
+          <p className="text-gray-600 text-xs mb-4">
+            Runs Claude Code in a tmux session per project (vibe-&lt;folder&gt;-&lt;hash&gt;), so it keeps running when the terminal is closed and can be re-attached.
+          </p>
+
+          <label className="block text-sm font-medium text-gray-700 mb-2">
+            Custom Terminal Profiles
+          </label>
+          <p className="text-gray-600 text-xs mb-2">
+            Space-separated arguments. {'{cwd}'} is replaced with the project directory and {'{command}'} with the script to run, e.g. "--directory {'{cwd}'} bash -c {'{command}'}".
+          </p>
+          {customTerminalsInput.map((profile, index) => (
+            <div key={index} className="flex gap-2 mb-2">
+              <input
+                value={profile.name}
+                onChange={(e) => handleCustomTerminalChange(index, { name: e.target.value })}
+                placeholder="Name"
+                className="w-28 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+              />
+              <input
+                value={profile.program}
+                onChange={(e) => handleCustomTerminalChange(index, { program: e.target.value })}
+                placeholder="Program"
+                className="w-32 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+              />
+              <input
+                defaultValue={profile.commandArgs.join(' ')}
+                onChange={(e) => handleCustomTerminalChange(index, { commandArgs: splitArgs(e.target.value) })}
+                placeholder="Command arguments"
+                className="flex-1 border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm"
+              />
+              <input
+                defaultValue={profile.openArgs.join(' ')}
+                onChange={(e) => handleCustomTerminalChange(index, { openArgs: splitArgs(e.target.value) })}
+                placeholder="Open arguments"
+                className="flex-1 border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm"
+              />
+              <button
+                onClick={() => setCustomTerminalsInput(profiles => profiles.filter((_, i) => i !== index))}
+                className="text-gray-400 hover:text-red-600"
+                title="Remove profile"
+              >
+                <Trash2 size={16} />
+              </button>
+            </div>
+          ))}
+          <div className="flex gap-2">
+            <Button
+              onClick={() => setCustomTerminalsInput(profiles => [
+                ...profiles,
+                { name: '', program: '', commandArgs: ['-e', 'bash', '-c', '{command}'], openArgs: [] },
+              ])}
+              variant="secondary"
+              size="sm"
+            >
+              Add Profile
+            </Button>
+            <Button onClick={handleSaveCustomTerminals} variant="secondary" size="sm">
+              Save Profiles
+            </Button>
+          </div>
+        </div>
+
+        <div className="bg-white rounded-lg shadow p-6">
+          <h2 className="text-lg font-semibold text-gray-900 mb-4">
+            Agents
+          </h2>
+
+          <p className="text-gray-600 text-sm mb-4">
+            Agent CLI used to launch sessions, open embedded terminals and run issues headlessly. Projects can pick their own in their metadata.
+          </p>
+
+          <select
+            value={settings?.agentProfile || ''}
+            onChange={(e) => updateAgentProfile(e.target.value || undefined).catch((error) => alert(`Failed to save agent: ${error}`))}
+            className="border border-gray-300 rounded-lg px-3 py-2 text-sm mb-4"
+          >
+            <option value="">Default (claude)</option>
+            {agentProfiles.map((profile) => (
+              <option key={profile.name} value={profile.name}>{profile.name}</option>
+            ))}
+          </select>
+
+          <label className="block text-sm font-medium text-gray-700 mb-2">
+            Custom Agent Profiles
+          </label>
+          <p className="text-gray-600 text-xs mb-2">
+            Space-separated arguments for interactive sessions and for headless runs (which must print the result and exit). The prompt goes in {'{prompt}'} (argument), {'{prompt_file}'} (file) or is piped in (stdin); without a placeholder it's added at the end. Environment variables are KEY=VALUE pairs.
+          </p>
+          {customAgentsInput.map((profile, index) => (
+            <div key={index} className="border border-gray-200 rounded-lg p-3 mb-2 space-y-2">
+              <div className="flex gap-2">
+                <input
+                  value={profile.name}
+                  onChange={(e) => handleCustomAgentChange(index, { name: e.target.value })}
+                  placeholder="Name"
+                  className="w-28 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                />
+                <input
+                  value={profile.program}
+                  onChange={(e) => handleCustomAgentChange(index, { program: e.target.value })}
+                  placeholder="Program"
+                  className="flex-1 border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                />
+                <button
+                  onClick={() => setCustomAgentsInput(profiles => profiles.filter((_, i) => i !== index))}
+                  className="text-gray-400 hover:text-red-600"
+                  title="Remove profile"
+                >
+                  <Trash2 size={16} />
+                </button>
+              </div>
+              <div className="flex gap-2">
+                <input
+                  defaultValue={profile.args.join(' ')}
+                  onChange={(e) => handleCustomAgentChange(index, { args: splitArgs(e.target.value) })}
+                  placeholder="Interactive arguments"
+                  className="flex-1 border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm"
+                />
+                <select
+                  value={profile.promptMode}
+                  onChange={(e) => handleCustomAgentChange(index, { promptMode: e.target.value as PromptMode })}
+                  className="border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                >
+                  <option value="argument">argument</option>
+                  <option value="file">file</option>
+                  <option value="stdin">stdin</option>
+                </select>
+              </div>
+              <div className="flex gap-2">
+                <input
+                  defaultValue={profile.headlessArgs.join(' ')}
+                  onChange={(e) => handleCustomAgentChange(index, { headlessArgs: splitArgs(e.target.value) })}
+                  placeholder="Headless arguments"
+                  className="flex-1 border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm"
+                />
+                <select
+                  value={profile.headlessPromptMode}
+                  onChange={(e) => handleCustomAgentChange(index, { headlessPromptMode: e.target.value as PromptMode })}
+                  className="border border-gray-300 rounded-lg px-3 py-2 text-sm"
+                >
+                  <option value="argument">argument</option>
+                  <option value="file">file</option>
+                  <option value="stdin">stdin</option>
+                </select>
+              </div>
+              <input
+                defaultValue={formatEnv(profile.env)}
+                onChange={(e) => handleCustomAgentChange(index, { env: parseEnv(e.target.value) })}
+                placeholder="Environment, e.g. AGENT_MODEL=large"
+                className="w-full border border-gray-300 rounded-lg px-3 py-2 font-mono text-sm"
+              />
+            </div>
+          ))}
+          <div className="flex gap-2">
+            <Button
+              onClick={() => setCustomAgentsInput(profiles => [
+                ...profiles,
+                { name: '', program: '', args: ['{prompt}'], promptMode: 'argument', headlessArgs: [], headlessPromptMode: 'stdin', env: {} },
+              ])}
+              variant="secondary"
+              size="sm"
+            >
+              Add Profile
+            </Button>
+            <Button onClick={handleSaveCustomAgents} variant="secondary" size="sm">
+              Save Profiles
+            </Button>
           </div>
         </div>
 
@@ -289,6 +689,8 @@ export function SettingsPanel() {
             <FileText size={18} className="inline mr-2" />
             Open Debug Log
           </Button>
+
+          <LogViewer />
         </div>
       </main>
     </div>
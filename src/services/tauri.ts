@@ -1,5 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { Project, FeedbackItem, Issue, Settings } from '../store/types';
+import type { Project, FeedbackItem, Issue, Settings, Comment, SyncReport, SyncSummary, GithubMappingRules, GithubBoardConfig, GithubRepoOptions, CredentialStatus, OutboundOperation, RepoStatus, TerminalProfile, AgentProfile, LogEntry, LogLevel, WorkJob, WorkQueue, WorktreeInfo, DevServerInfo, DevServerLine, PortStatus, ProjectTask } from '../store/types';
 
 // Project commands
 export async function scanProjects(projectsDir: string): Promise<Project[]> {
@@ -10,8 +10,13 @@ export async function getProjectDetail(projectPath: string): Promise<Project> {
   return await invoke('get_project_detail', { projectPath });
 }
 
-export async function createNewProject(projectsDir: string, projectName: string, summary?: string): Promise<string> {
-  return await invoke('create_new_project', { projectsDir, projectName, summary });
+export async function createNewProject(
+  projectsDir: string,
+  projectName: string,
+  summary?: string,
+  githubRepo?: GithubRepoOptions
+): Promise<string> {
+  return await invoke('create_new_project', { projectsDir, projectName, summary, githubRepo });
 }
 
 export interface ProjectIdea {
@@ -138,6 +143,14 @@ export async function archiveAndCloseGithubFeedback(
   return await invoke('archive_and_close_github_feedback', { projectPath, feedbackId });
 }
 
+export async function addFeedbackComment(
+  projectPath: string,
+  feedbackId: string,
+  body: string
+): Promise<Comment> {
+  return await invoke('add_feedback_comment', { projectPath, feedbackId, body });
+}
+
 // Issue commands
 export async function getIssues(projectPath: string): Promise<Issue[]> {
   return await invoke('get_issues', { projectPath });
@@ -177,6 +190,14 @@ export async function migrateCompletedIssues(
   return await invoke('migrate_completed_issues', { projectPath });
 }
 
+export async function addIssueComment(
+  projectPath: string,
+  issueId: string,
+  body: string
+): Promise<Comment> {
+  return await invoke('add_issue_comment', { projectPath, issueId, body });
+}
+
 // Debug logging
 export async function logDebug(message: string): Promise<void> {
   try {
@@ -189,11 +210,12 @@ export async function logDebug(message: string): Promise<void> {
 // Launcher commands
 export async function launchClaudeCode(
   projectPath: string,
-  prompt: string
+  prompt: string,
+  context?: SessionContext
 ): Promise<void> {
   await logDebug('[tauri.ts] launchClaudeCode called with path: ' + projectPath);
   try {
-    await invoke('launch_claude_code', { projectPath, prompt });
+    await invoke('launch_claude_code', { projectPath, prompt, context: context ?? null });
     await logDebug('[tauri.ts] launchClaudeCode completed successfully');
   } catch (error) {
     console.error('[tauri.ts] launchClaudeCode invoke failed:', error);
@@ -219,6 +241,20 @@ export async function updateSettings(settings: Settings): Promise<void> {
   return await invoke('update_settings', { settings });
 }
 
+// Credential store commands
+export async function getCredentialStatus(): Promise<CredentialStatus> {
+  return await invoke('get_credential_status');
+}
+
+export async function unlockCredentials(passphrase: string): Promise<CredentialStatus> {
+  return await invoke('unlock_credentials', { passphrase });
+}
+
+// Leave host unset for the GitHub token; an empty token removes it
+export async function setCredentialToken(host: string | undefined, token: string | undefined): Promise<CredentialStatus> {
+  return await invoke('set_credential_token', { host, token });
+}
+
 export async function selectDirectory(): Promise<string | null> {
   return await invoke('select_directory');
 }
@@ -313,19 +349,67 @@ export interface AvailableScripts {
   has_dev: boolean;
   has_build: boolean;
   dev_script_name: string | null;
-  dev_script_type: string | null; // "npm", "bat", or "sh"
+  dev_script_type: string | null; // "npm", "bat", "sh" or "command" (a task's command line)
   dev_command: string | null;
   build_command: string | null;
+  package_manager: string | null; // npm, pnpm, yarn or bun when there's a package.json
+  tasks: ProjectTask[];           // Everything runnable, from all build files
 }
 
 export async function detectNpmScripts(projectPath: string): Promise<AvailableScripts> {
   return await invoke('detect_npm_scripts', { projectPath });
 }
 
+// Tasks from package.json, Cargo, pyproject.toml, go.mod, Makefiles, justfiles and deno.json
+export async function detectProjectTasks(projectPath: string): Promise<ProjectTask[]> {
+  return await invoke('detect_project_tasks', { projectPath });
+}
+
 export async function runNpmScript(projectPath: string, scriptName: string, scriptType?: string): Promise<void> {
   return await invoke('run_npm_script', { projectPath, scriptName, scriptType });
 }
 
+// Dev server commands; a project's server is replaced when another is started
+export async function startDevServer(projectPath: string, scriptName: string, scriptType?: string): Promise<DevServerInfo> {
+  return await invoke('start_dev_server', { projectPath, scriptName, scriptType });
+}
+
+export async function stopDevServer(projectPath: string): Promise<void> {
+  return await invoke('stop_dev_server', { projectPath });
+}
+
+export async function restartDevServer(projectPath: string): Promise<DevServerInfo> {
+  return await invoke('restart_dev_server', { projectPath });
+}
+
+// null if no dev server was started for the project since the app launched
+export async function getDevServerStatus(projectPath: string): Promise<DevServerInfo | null> {
+  return await invoke('get_dev_server_status', { projectPath });
+}
+
+// Most recent output lines, oldest first
+export async function getDevServerOutput(projectPath: string, lines?: number): Promise<DevServerLine[]> {
+  return await invoke('get_dev_server_output', { projectPath, lines });
+}
+
+export async function listDevServers(): Promise<DevServerInfo[]> {
+  return await invoke('list_dev_servers');
+}
+
+// The port the project's dev server runs on, assigned on first use
+export async function getProjectPort(projectPath: string): Promise<number> {
+  return await invoke('get_project_port', { projectPath });
+}
+
+// Pass null to have a free port assigned again
+export async function setProjectPort(projectPath: string, port: number | null): Promise<number> {
+  return await invoke('set_project_port', { projectPath, port });
+}
+
+export async function getPortStatus(port: number): Promise<PortStatus> {
+  return await invoke('get_port_status', { port });
+}
+
 export async function openInVscode(projectPath: string): Promise<void> {
   return await invoke('open_in_vscode', { projectPath });
 }
@@ -342,20 +426,209 @@ export async function getDebugLogPath(): Promise<string> {
   return await invoke('get_debug_log_path');
 }
 
+// Newest first; `level` is the least severe level included
+export async function queryLogs(filter: {
+  level?: LogLevel;
+  module?: string;
+  projectPath?: string;
+  since?: string;
+  until?: string;
+  limit?: number;
+}): Promise<LogEntry[]> {
+  return await invoke('query_logs', filter);
+}
+
 export interface SessionInfo {
+  id: string | null;
   projectPath: string;
   status: 'running' | 'idle' | 'not_started';
+  pid: number | null;            // Known once the terminal has started Claude
+  startedAt: string | null;
+  tmuxSession: string | null;    // Set when Claude runs in a tmux session
+}
+
+// A finished session; also the payload of the "session-stopped" event
+export interface SessionRecord {
+  id: string;
+  projectPath: string;
   pid: number | null;
+  startedAt: string;
+  endedAt: string;
+  durationSecs: number;
+  exitCode: number | null;       // null if the terminal was closed before Claude exited
+  outcome: 'exited' | 'closed' | 'failed_to_start';
+}
+
+// What a session was launched for, recorded with its transcript
+export interface SessionContext {
+  promptName?: string | null;    // Prompt template, e.g. 'issueFix'
+  issueIds?: string[];
+  feedbackIds?: string[];
+}
+
+export interface SessionCommit {
+  hash: string;
+  summary: string;
+}
+
+// Full record of a launched session, from .vibe/sessions/
+export interface SessionTranscript {
+  id: string;
+  projectPath: string;
+  workDir: string;               // The project or one of its worktrees
+  kind: 'terminal' | 'embedded';
+  prompt: string;
+  promptName: string | null;
+  issueIds: string[];
+  feedbackIds: string[];
+  startedAt: string;
+  endedAt: string | null;        // null while the session is running
+  outcome: SessionRecord['outcome'] | null;
+  exitCode: number | null;
+  headBefore: string | null;
+  headAfter: string | null;
+  commits: SessionCommit[];      // Oldest first
 }
 
 export async function getSessionStatus(projectPath: string): Promise<SessionInfo> {
   return await invoke('get_session_status', { projectPath });
 }
 
+// Installed built-in terminal profiles, then custom ones from settings
+export async function getTerminalProfiles(): Promise<TerminalProfile[]> {
+  return await invoke('get_terminal_profiles');
+}
+
+// Agent profile commands
+export async function getAgentProfiles(): Promise<AgentProfile[]> {
+  return await invoke('get_agent_profiles');
+}
+
+// null when the project uses the default from settings
+export async function getProjectAgentProfile(projectPath: string): Promise<string | null> {
+  return await invoke('get_project_agent_profile', { projectPath });
+}
+
+export async function setProjectAgentProfile(projectPath: string, profile: string | null): Promise<void> {
+  return await invoke('set_project_agent_profile', { projectPath, profile });
+}
+
+// Most recent first
+export async function getSessionHistory(projectPath: string): Promise<SessionRecord[]> {
+  return await invoke('get_session_history', { projectPath });
+}
+
+// Session transcripts, most recent first
+export async function listSessions(projectPath: string): Promise<SessionTranscript[]> {
+  return await invoke('list_sessions', { projectPath });
+}
+
+export async function listIssueSessions(projectPath: string, issueId: string): Promise<SessionTranscript[]> {
+  return await invoke('list_issue_sessions', { projectPath, issueId });
+}
+
 export async function focusClaudeTerminal(projectPath: string): Promise<void> {
   return await invoke('focus_claude_terminal', { projectPath });
 }
 
+// Recent output of the project's tmux session
+export async function getSessionOutput(projectPath: string, lines?: number): Promise<string> {
+  return await invoke('get_session_output', { projectPath, lines });
+}
+
+// Embedded terminal commands
+export interface PtySessionInfo {
+  id: string;
+  projectPath: string;
+  command: string;
+  args: string[];
+  pid: number | null;
+  cols: number;
+  rows: number;
+  status: 'running' | 'exited';
+  exitCode: number | null;
+  startedAt: string;
+}
+
+// Rendered screen of an embedded terminal; also the payload of the "pty-output" event
+export interface PtyScreen {
+  id: string;
+  lines: string[];
+  cursorRow: number;
+  cursorCol: number;
+  hideCursor: boolean;
+}
+
+// Payload of the "pty-exit" event
+export interface PtyExit {
+  id: string;
+  projectPath: string;
+  exitCode: number | null;
+}
+
+// Runs Claude Code when no command is given
+export async function spawnPty(
+  projectPath: string,
+  cols: number,
+  rows: number,
+  command?: string,
+  args?: string[]
+): Promise<PtySessionInfo> {
+  return await invoke('spawn_pty', { projectPath, command, args, cols, rows });
+}
+
+export async function writePty(id: string, data: string): Promise<void> {
+  return await invoke('write_pty', { id, data });
+}
+
+export async function resizePty(id: string, cols: number, rows: number): Promise<void> {
+  return await invoke('resize_pty', { id, cols, rows });
+}
+
+export async function killPty(id: string): Promise<void> {
+  return await invoke('kill_pty', { id });
+}
+
+export async function closePty(id: string): Promise<void> {
+  return await invoke('close_pty', { id });
+}
+
+export async function listPtySessions(projectPath: string): Promise<PtySessionInfo[]> {
+  return await invoke('list_pty_sessions', { projectPath });
+}
+
+export async function getPtyScreen(id: string): Promise<PtyScreen> {
+  return await invoke('get_pty_screen', { id });
+}
+
+// Headless agent runs, stored in .vibe/runs/
+export interface AgentRun {
+  id: string;
+  projectPath: string;
+  issueId: string;
+  promptName: string;
+  status: 'running' | 'succeeded' | 'failed';
+  startedAt: string;
+  finishedAt: string | null;
+  exitCode: number | null;
+  summary: string | null;        // The agent's final output on success
+  error: string | null;
+}
+
+// Runs the agent non-interactively on one issue; "agent-run-finished" is emitted when it ends
+export async function startHeadlessRun(projectPath: string, issueId: string, promptName?: string): Promise<AgentRun> {
+  return await invoke('start_headless_run', { projectPath, issueId, promptName });
+}
+
+// Most recent first
+export async function getAgentRuns(projectPath: string): Promise<AgentRun[]> {
+  return await invoke('get_agent_runs', { projectPath });
+}
+
+export async function getRunTranscript(projectPath: string, runId: string): Promise<string> {
+  return await invoke('get_run_transcript', { projectPath, runId });
+}
+
 // Watcher commands
 export interface FileModificationInfo {
   feedback_modified: boolean;
@@ -384,16 +657,23 @@ export async function getProjectFilesTimestamps(
 export async function fetchGithubIssues(
   projectPath: string,
   githubUrl: string
-): Promise<number> {
+): Promise<SyncReport> {
   return await invoke('fetch_github_issues', { projectPath, githubUrl });
 }
 
 export async function syncAllGithubIssues(
   projectsDir: string
-): Promise<number> {
+): Promise<SyncSummary> {
   return await invoke('sync_all_github_issues', { projectsDir });
 }
 
+export async function syncGithubIssues(
+  projectPath: string,
+  githubUrl: string
+): Promise<SyncReport> {
+  return await invoke('sync_github_issues', { projectPath, githubUrl });
+}
+
 export async function closeGithubIssue(
   githubUrl: string,
   issueNumber: number
@@ -401,6 +681,128 @@ export async function closeGithubIssue(
   return await invoke('close_github_issue', { githubUrl, issueNumber });
 }
 
+export async function getGithubMappingRules(
+  projectPath: string
+): Promise<GithubMappingRules> {
+  return await invoke('get_github_mapping_rules', { projectPath });
+}
+
+export async function updateGithubMappingRules(
+  projectPath: string,
+  rules: GithubMappingRules
+): Promise<void> {
+  return await invoke('update_github_mapping_rules', { projectPath, rules });
+}
+
+// GitHub Projects (v2) board sync
+export async function getGithubBoardConfig(projectPath: string): Promise<GithubBoardConfig | null> {
+  return await invoke('get_github_board_config', { projectPath });
+}
+
+// Pass null to turn board sync off
+export async function updateGithubBoardConfig(
+  projectPath: string,
+  config: GithubBoardConfig | null
+): Promise<void> {
+  return await invoke('update_github_board_config', { projectPath, config });
+}
+
+export async function syncGithubBoard(projectPath: string, githubUrl: string): Promise<SyncReport> {
+  return await invoke('sync_github_board', { projectPath, githubUrl });
+}
+
+// Returns true if the issue was created now, false if it was queued for retry
+export async function createGithubIssue(
+  projectPath: string,
+  githubUrl: string,
+  itemId: string
+): Promise<boolean> {
+  return await invoke('create_github_issue', { projectPath, githubUrl, itemId });
+}
+
+// Pull request and CI status
+export async function fetchRepoStatus(projectPath: string, githubUrl: string): Promise<RepoStatus> {
+  return await invoke('fetch_repo_status', { projectPath, githubUrl });
+}
+
+export async function getRepoStatus(projectPath: string): Promise<RepoStatus | null> {
+  return await invoke('get_repo_status', { projectPath });
+}
+
+// Outbound queue commands
+export async function getOutboundQueue(): Promise<OutboundOperation[]> {
+  return await invoke('get_outbound_queue');
+}
+
+export async function retryOutboundQueue(): Promise<OutboundOperation[]> {
+  return await invoke('retry_outbound_queue');
+}
+
+export async function clearOutboundQueue(ids?: string[]): Promise<OutboundOperation[]> {
+  return await invoke('clear_outbound_queue', { ids });
+}
+
+// Work queue commands; job changes are also emitted as "work-queue-progress" events
+export async function getWorkQueue(): Promise<WorkQueue> {
+  return await invoke('get_work_queue');
+}
+
+// Queues all pending and needs-rework issues by priority when no ids are given
+export async function enqueueIssues(projectPath: string, issueIds?: string[], promptName?: string): Promise<WorkJob[]> {
+  return await invoke('enqueue_issues', { projectPath, issueIds, promptName });
+}
+
+export async function pauseWorkQueue(): Promise<WorkQueue> {
+  return await invoke('pause_work_queue');
+}
+
+export async function resumeWorkQueue(): Promise<WorkQueue> {
+  return await invoke('resume_work_queue');
+}
+
+export async function cancelWorkJob(jobId: string): Promise<void> {
+  return await invoke('cancel_work_job', { jobId });
+}
+
+export async function updateWorkQueueSettings(maxConcurrent: number, timeoutSecs: number, maxAttempts: number): Promise<WorkQueue> {
+  return await invoke('update_work_queue_settings', { maxConcurrent, timeoutSecs, maxAttempts });
+}
+
+export async function clearFinishedWorkJobs(): Promise<WorkQueue> {
+  return await invoke('clear_finished_work_jobs');
+}
+
+// Worktree commands
+// Creates the issue's worktree if needed and starts the agent in it
+export async function startIssueInWorktree(projectPath: string, issueId: string, headless: boolean): Promise<WorktreeInfo> {
+  return await invoke('start_issue_in_worktree', { projectPath, issueId, headless });
+}
+
+export async function createWorktree(projectPath: string, issueId: string): Promise<WorktreeInfo> {
+  return await invoke('create_worktree', { projectPath, issueId });
+}
+
+export async function listWorktrees(projectPath: string): Promise<WorktreeInfo[]> {
+  return await invoke('list_worktrees', { projectPath });
+}
+
+export async function openWorktree(projectPath: string, issueId: string, target: 'terminal' | 'vscode' | 'explorer'): Promise<void> {
+  return await invoke('open_worktree', { projectPath, issueId, target });
+}
+
+export async function mergeWorktree(projectPath: string, issueId: string, strategy: 'fast-forward' | 'squash'): Promise<WorktreeInfo> {
+  return await invoke('merge_worktree', { projectPath, issueId, strategy });
+}
+
+export async function removeWorktree(projectPath: string, issueId: string, deleteBranch: boolean): Promise<void> {
+  return await invoke('remove_worktree', { projectPath, issueId, deleteBranch });
+}
+
+// Removes merged worktrees of completed issues; returns their issue ids
+export async function cleanupWorktrees(projectPath: string): Promise<string[]> {
+  return await invoke('cleanup_worktrees', { projectPath });
+}
+
 export async function toggleGithubSync(
   projectPath: string,
   enabled: boolean
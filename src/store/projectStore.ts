@@ -1,5 +1,5 @@
 import { create } from 'zustand';
-import type { Project, FeedbackItem, Issue } from './types';
+import type { Project, FeedbackItem, Issue, GithubRepoOptions } from './types';
 import * as tauri from '../services/tauri';
 import { generateIssueFixPrompt } from '../services/clipboard';
 
@@ -15,7 +15,7 @@ interface ProjectStore {
 
   // Actions
   loadProjects: () => Promise<void>;
-  createProject: (projectName: string, summary?: string) => Promise<string | undefined>;
+  createProject: (projectName: string, summary?: string, githubRepo?: GithubRepoOptions) => Promise<string | undefined>;
   saveProjectIdea: (projectPath: string, idea: {
     summary: string;
     problem: string;
@@ -86,9 +86,15 @@ export const useProjectStore = create<ProjectStore>((set, get) => ({
       // Auto-sync GitHub issues for all projects (runs in background)
       console.log('[GitHub Sync] Starting automatic GitHub sync...');
       try {
-        const count = await tauri.syncAllGithubIssues(settings.projectsDirectory);
-        console.log(`[GitHub Sync] Sync completed. Imported ${count} issue(s)`);
-        if (count > 0) {
+        const summary = await tauri.syncAllGithubIssues(settings.projectsDirectory);
+        console.log(`[GitHub Sync] Sync completed. Imported ${summary.imported} issue(s)`);
+        for (const result of summary.projects.filter((p) => p.status === 'failed')) {
+          console.error(`[GitHub Sync] ${result.projectName}: ${result.reason}`);
+        }
+        const changed = summary.projects.some(
+          (p) => p.report && (p.report.imported > 0 || p.report.pulled.length > 0)
+        );
+        if (changed) {
           console.log('[GitHub Sync] Reloading projects to show newly synced feedback...');
           // Reload projects to show newly synced feedback
           const updatedProjects = await tauri.scanProjects(settings.projectsDirectory);
@@ -104,7 +110,7 @@ export const useProjectStore = create<ProjectStore>((set, get) => ({
   },
 
   // Create new project
-  createProject: async (projectName: string, summary?: string) => {
+  createProject: async (projectName: string, summary?: string, githubRepo?: GithubRepoOptions) => {
     try {
       const settingsStore = await import('./settingsStore');
       const { settings } = settingsStore.useSettingsStore.getState();
@@ -113,7 +119,14 @@ export const useProjectStore = create<ProjectStore>((set, get) => ({
         throw new Error('Projects directory not configured');
       }
 
-      const projectPath = await tauri.createNewProject(settings.projectsDirectory, projectName, summary);
+      let projectPath: string;
+      try {
+        projectPath = await tauri.createNewProject(settings.projectsDirectory, projectName, summary, githubRepo);
+      } catch (error) {
+        // Publishing can fail after the project folder was created, so show it anyway
+        if (githubRepo) await get().loadProjects();
+        throw error;
+      }
 
       // Reload projects list to show the new project
       await get().loadProjects();
@@ -407,7 +420,7 @@ export const useProjectStore = create<ProjectStore>((set, get) => ({
       }
 
       // Launch Claude Code with or without prompt
-      await tauri.launchClaudeCode(projectPath, prompt);
+      await tauri.launchClaudeCode(projectPath, prompt, prompt ? { promptName: 'issueFix', feedbackIds } : undefined);
     } catch (error) {
       const errorMessage = error instanceof Error ? error.message : String(error);
       console.error('[projectStore] Failed to launch Claude Code:', errorMessage);
@@ -1,21 +1,30 @@
 import { create } from 'zustand';
-import type { Settings } from './types';
+import type { AgentProfile, CredentialStatus, Settings, TerminalProfile, TrackerHost } from './types';
 import * as tauri from '../services/tauri';
 import { soundEffects } from '../utils/sounds';
 
 interface SettingsStore {
   // State
   settings: Settings | null;
+  credentialStatus: CredentialStatus | null;
   isLoading: boolean;
 
   // Actions
   loadSettings: () => Promise<void>;
+  loadCredentialStatus: () => Promise<void>;
+  unlockCredentials: (passphrase: string) => Promise<void>;
   updateProjectsDirectory: (path: string) => Promise<void>;
   updateSoundEffectsEnabled: (enabled: boolean) => Promise<void>;
   updateLaunchOnStartup: (enabled: boolean) => Promise<void>;
   updateAutoRefineOnStartup: (enabled: boolean) => Promise<void>;
   updateGithubToken: (token: string | undefined) => Promise<void>;
   updateGithubIntegrationEnabled: (enabled: boolean) => Promise<void>;
+  updateTrackerHosts: (hosts: TrackerHost[]) => Promise<void>;
+  updateTerminalProfile: (name: string | undefined) => Promise<void>;
+  updateTerminalProfiles: (profiles: TerminalProfile[]) => Promise<void>;
+  updateTmuxSessions: (enabled: boolean) => Promise<void>;
+  updateAgentProfile: (name: string | undefined) => Promise<void>;
+  updateAgentProfiles: (profiles: AgentProfile[]) => Promise<void>;
   selectDirectory: () => Promise<string | null>;
 }
 
@@ -55,6 +64,7 @@ export const useSettingsStore = create<SettingsStore>((set, get) => {
   return {
     // Initial state
     settings: null,
+    credentialStatus: null,
     isLoading: false,
 
     // Load settings from backend
@@ -67,12 +77,27 @@ export const useSettingsStore = create<SettingsStore>((set, get) => {
         soundEffects.setEnabled(settings.soundEffectsEnabled ?? true);
 
         set({ settings, isLoading: false });
+        await get().loadCredentialStatus();
       } catch (error) {
         set({ isLoading: false });
         throw error;
       }
     },
 
+    // Load which tokens are stored (never the tokens themselves)
+    loadCredentialStatus: async () => {
+      try {
+        set({ credentialStatus: await tauri.getCredentialStatus() });
+      } catch (error) {
+        console.error('Failed to load credential status:', error);
+      }
+    },
+
+    // Unlock the encrypted credential store (sets the passphrase on first use)
+    unlockCredentials: async (passphrase: string) => {
+      set({ credentialStatus: await tauri.unlockCredentials(passphrase) });
+    },
+
     // Update projects directory
     updateProjectsDirectory: async (path: string) => {
       await updateSetting('projectsDirectory', path);
@@ -101,9 +126,9 @@ export const useSettingsStore = create<SettingsStore>((set, get) => {
       await updateSetting('autoRefineOnStartup', enabled);
     },
 
-    // Update GitHub token
+    // Update GitHub token (stored in the credential store, not in settings)
     updateGithubToken: async (token: string | undefined) => {
-      await updateSetting('githubToken', token);
+      set({ credentialStatus: await tauri.setCredentialToken(undefined, token) });
     },
 
     // Update GitHub integration enabled
@@ -111,6 +136,41 @@ export const useSettingsStore = create<SettingsStore>((set, get) => {
       await updateSetting('githubIntegrationEnabled', enabled);
     },
 
+    // Update self-hosted issue trackers
+    updateTrackerHosts: async (hosts: TrackerHost[]) => {
+      // Tokens are passed through once to be stored, then dropped from local state
+      await updateSetting('trackerHosts', hosts);
+      set(state => ({
+        settings: state.settings && { ...state.settings, trackerHosts: hosts.map(h => ({ ...h, token: undefined })) },
+      }));
+      await get().loadCredentialStatus();
+    },
+
+    // Update the terminal used on Linux (undefined picks the first installed one)
+    updateTerminalProfile: async (name: string | undefined) => {
+      await updateSetting('terminalProfile', name);
+    },
+
+    // Update custom terminal profiles
+    updateTerminalProfiles: async (profiles: TerminalProfile[]) => {
+      await updateSetting('terminalProfiles', profiles);
+    },
+
+    // Update tmux session mode
+    updateTmuxSessions: async (enabled: boolean) => {
+      await updateSetting('tmuxSessions', enabled);
+    },
+
+    // Update the default agent profile
+    updateAgentProfile: async (name: string | undefined) => {
+      await updateSetting('agentProfile', name);
+    },
+
+    // Update custom agent profiles
+    updateAgentProfiles: async (profiles: AgentProfile[]) => {
+      await updateSetting('agentProfiles', profiles);
+    },
+
     // Open directory picker
     selectDirectory: async () => {
       try {
@@ -23,6 +23,9 @@ export interface Project {
   hasGitRepo: boolean;           // Whether project has .git directory
   devCommand?: string;           // Custom dev command (overrides auto-detection)
   buildCommand?: string;         // Custom build command (overrides auto-detection)
+  openPullRequests?: number;     // From the cached GitHub status, unset if never fetched
+  ciStatus?: string;             // Default-branch CI: 'success', 'failure', 'pending', ...
+  repoStatusFetchedAt?: string;  // ISO 8601 timestamp
 }
 
 // Feedback Item Model
@@ -38,6 +41,13 @@ export interface FeedbackItem {
   relatedIssueId?: string;       // ID of the issue this bug report is related to
   githubIssueNumber?: number;    // GitHub issue number (if synced)
   githubIssueUrl?: string;       // GitHub issue URL (if synced)
+  updatedAt?: string;            // ISO 8601 timestamp of the last local edit
+  labels?: string[];             // Labels (mirrored from GitHub when synced)
+  comments?: Comment[];          // Discussion thread
+  githubSyncedAt?: string;       // When this item was last synced with GitHub
+  githubUpdatedAt?: string;      // GitHub's updated_at as of the last sync
+  githubDiscussionNumber?: number; // GitHub Discussion this was imported from
+  githubDiscussionUrl?: string;  // GitHub Discussion URL
 }
 
 // Issue Model (refined feedback ready for implementation)
@@ -59,6 +69,243 @@ export interface Issue {
   implementationSummary?: string; // Brief summary of what was implemented (for for-review issues)
   githubIssueNumber?: number;    // GitHub issue number (if created from GitHub issue)
   githubIssueUrl?: string;       // GitHub issue URL (if created from GitHub issue)
+  updatedAt?: string;            // ISO 8601 timestamp of the last local edit
+  labels?: string[];             // Labels (mirrored from GitHub when synced)
+  comments?: Comment[];          // Discussion thread
+  githubSyncedAt?: string;       // When this issue was last synced with GitHub
+  githubUpdatedAt?: string;      // GitHub's updated_at as of the last sync
+  lastRunError?: string;         // Why the last headless agent run failed
+}
+
+// Comment in a feedback item's or issue's thread
+export interface Comment {
+  id: string;                    // UUID
+  author: string;                // GitHub login, or "me" for local comments
+  body: string;
+  createdAt: string;             // ISO 8601 timestamp
+  githubCommentId?: number;      // Set once the comment exists on GitHub
+}
+
+// GitHub two-way sync
+export interface SyncChange {
+  issueNumber: number;
+  localId: string;
+  kind: 'issue' | 'feedback';
+  fields: ('title' | 'body' | 'state' | 'labels' | 'priority' | 'comments' | 'status')[];
+  winner?: 'local' | 'remote';   // Only set for conflicts
+}
+
+export interface SyncReport {
+  tracker?: TrackerKind;         // Which backend the project was synced with
+  since?: string;                // Cursor the fetch started from (absent for a full fetch)
+  fetched: number;
+  pullRequestsSkipped: number;
+  imported: number;
+  discussionsImported: number;
+  boardItemsAdded: number;       // Linked issues added to the project board
+  pulled: SyncChange[];
+  pushed: SyncChange[];
+  conflicts: SyncChange[];
+  errors: string[];
+  syncedAt: string;
+}
+
+export interface ProjectSyncResult {
+  projectName: string;
+  projectPath: string;
+  githubUrl?: string;
+  status: 'synced' | 'skipped' | 'failed';
+  reason?: string;               // Why the project was skipped or failed
+  report?: SyncReport;
+}
+
+export interface SyncSummary {
+  projectsScanned: number;
+  projectsWithMetadata: number;
+  projectsWithGithubUrl: number;
+  projectsWithSyncEnabled: number;
+  imported: number;
+  projects: ProjectSyncResult[];
+}
+
+// Per-project GitHub label/milestone mapping (.vibe/github-mapping.json)
+export interface GithubMappingRules {
+  priorityLabels: Record<string, 1 | 2 | 3 | 4 | 5>;      // e.g. { "P0": 1, "bug": 2 }
+  milestonePriorities: Record<string, 1 | 2 | 3 | 4 | 5>; // Used when no priority label matches
+  copyLabels: string[] | null;   // null copies every non-priority label
+  skipLabels: string[];          // Issues with these labels are never imported
+  defaultPriority: 1 | 2 | 3 | 4 | 5;
+  discussionCategories: string[]; // Discussions in these categories are imported as feedback
+}
+
+// GitHub Projects (v2) board whose status field tracks issue statuses (.vibe/github-project.json)
+export interface BoardStatusMapping {
+  status: Issue['status'];
+  option: string;                // Option name on the board, e.g. "In Progress"
+}
+
+export interface GithubBoardConfig {
+  owner: string;                 // User or organization that owns the board
+  number: number;                // Project number from the board URL
+  statusField: string;           // Single-select field, "Status" by default
+  statusOptions: BoardStatusMapping[]; // When pulling, the first status listed for an option wins
+  addMissingItems: boolean;      // Add linked issues that aren't on the board yet
+}
+
+// GitHub pull request and CI overview (cached in .vibe/github-status.json)
+export interface PullRequestSummary {
+  number: number;
+  title: string;
+  author?: string;
+  url: string;
+  draft: boolean;
+  reviewState: 'approved' | 'changes_requested' | 'commented' | 'pending';
+  mergeable?: boolean;           // Unset while GitHub is still computing it
+  updatedAt: string;
+}
+
+export interface CiStatus {
+  branch: string;
+  status: string;                // 'queued' | 'in_progress' | 'completed'
+  conclusion?: string;           // 'success', 'failure', 'cancelled', ... once completed
+  name?: string;
+  url?: string;
+  updatedAt?: string;
+}
+
+export interface RepoStatus {
+  defaultBranch: string;
+  pullRequests: PullRequestSummary[];
+  ci?: CiStatus;
+  fetchedAt: string;
+}
+
+// Options for publishing a new project to a new GitHub repository
+export interface GithubRepoOptions {
+  private: boolean;
+}
+
+// Outbound tracker operations that failed and are waiting to be retried
+export type OutboundAction =
+  | { type: 'close'; issueNumber: number; comment?: string }
+  | { type: 'comment'; issueNumber: number; body: string; localId?: string }
+  | { type: 'labels'; issueNumber: number; labels: string[] }
+  | { type: 'create'; title: string; body: string; labels: string[]; localId?: string };
+
+export interface OutboundOperation {
+  id: string;
+  projectPath?: string;
+  repoUrl: string;
+  action: OutboundAction;
+  status: 'pending' | 'failed';  // Failed operations are only retried on request
+  attempts: number;
+  createdAt: string;
+  lastAttemptAt?: string;
+  nextAttemptAt: string;
+  lastError?: string;
+}
+
+// Work queue of issues to run through the agent headlessly
+export interface WorkJob {
+  id: string;
+  projectPath: string;
+  issueId: string;
+  issueTitle: string;
+  promptName: string;
+  status: 'queued' | 'running' | 'succeeded' | 'failed' | 'cancelled';
+  attempts: number;
+  maxAttempts: number;
+  timeoutSecs: number;
+  createdAt: string;
+  startedAt?: string;
+  finishedAt?: string;
+  runId?: string;                // The latest headless run in .vibe/runs/
+  lastError?: string;
+}
+
+export interface WorkQueue {
+  paused: boolean;
+  maxConcurrent: number;         // Jobs running at once across projects (one per project)
+  timeoutSecs: number;           // Defaults for newly queued jobs
+  maxAttempts: number;
+  jobs: WorkJob[];
+}
+
+// Git worktree an issue is worked on in (.vibe/worktrees/<issue-short-id>)
+export interface WorktreeInfo {
+  issueId: string;
+  branch: string;                // vibe/<issue-short-id>
+  path: string;
+  baseBranch: string;            // Branch it was created from and merges back into
+  createdAt: string;
+  mergedAt: string | null;
+  exists: boolean;
+  dirty: boolean;                // Uncommitted changes in the worktree
+  ahead: number;                 // Commits not yet on the base branch
+}
+
+// Dev server Vibe Hub started for a project; also the "dev-server-changed" payload
+export interface DevServerInfo {
+  projectPath: string;
+  scriptName: string;
+  scriptType: 'npm' | 'bat' | 'sh' | 'command';   // 'command': the project's dev command
+  command: string;
+  port: number | null;           // The port it was told to use
+  pid: number | null;
+  status: 'running' | 'exited' | 'stopped';
+  exitCode: number | null;
+  startedAt: string;
+  url: string | null;            // Detected from its output
+  ready: boolean;                // Printed its URL or finished starting up
+}
+
+// A line of dev server output; also the "dev-server-output" payload
+export interface DevServerLine {
+  projectPath: string;
+  stream: 'stdout' | 'stderr';
+  text: string;
+  error: boolean;                // Looks like an error message
+  timestamp: string;
+}
+
+// Something a project can run, found in one of its build files
+export interface ProjectTask {
+  name: string;                  // e.g. "dev", "test", "tauri dev"
+  command: string;               // Command line that runs it from the project root
+  kind: string;                  // Tool that runs it: "npm", "pnpm", "cargo", "uv", "make", "sh", ...
+  source: string;                // File it was found in, relative to the project root
+}
+
+// A process listening on a port
+export interface PortOwner {
+  pid: number;
+  name: string;
+  projectPath: string | null;    // Set when it's a dev server Vibe Hub started
+}
+
+export interface PortStatus {
+  port: number;
+  available: boolean;
+  owner: PortOwner | null;
+}
+
+// Self-hosted issue tracker (GitHub Enterprise, GitLab, Gitea)
+export type TrackerKind = 'github' | 'gitlab' | 'gitea';
+
+export interface TrackerHost {
+  host: string;                  // e.g. "gitlab.example.com"
+  kind: TrackerKind;
+  apiUrl?: string;               // Defaults to the kind's standard API path on the host
+  token?: string;                // Write-only: moved to the credential store, never returned
+}
+
+// Where tokens are stored; the tokens themselves never reach the frontend
+export interface CredentialStatus {
+  backend: 'keyring' | 'encrypted-file';
+  locked: boolean;               // Encrypted file store waiting for its passphrase
+  hasEncryptedFile: boolean;     // A passphrase has been set before
+  githubTokenSet: boolean;
+  trackerTokenHosts: string[];   // Tracker hosts with a stored token
 }
 
 // Settings
@@ -67,8 +314,46 @@ export interface Settings {
   soundEffectsEnabled: boolean;
   launchOnStartup: boolean;
   autoRefineOnStartup: boolean;
-  githubToken?: string;
-  githubIntegrationEnabled: boolean;
+  githubIntegrationEnabled: boolean;  // Also gates GitLab/Gitea trackers
+  trackerHosts?: TrackerHost[];
+  terminalProfile?: string;      // Linux terminal to launch; unset uses the first one installed
+  terminalProfiles?: TerminalProfile[]; // User-defined, in addition to the built-in profiles
+  tmuxSessions?: boolean;        // Run Claude Code in a per-project tmux session (Linux)
+  agentProfile?: string;         // Default agent CLI; unset uses Claude Code
+  agentProfiles?: AgentProfile[]; // User-defined, in addition to the built-in profiles
+}
+
+// Linux terminal emulator; "{cwd}" and "{command}" in the args are filled in at launch
+export interface TerminalProfile {
+  name: string;
+  program: string;
+  commandArgs: string[];         // Runs "{command}" (a bash script)
+  openArgs: string[];            // Opens a shell in "{cwd}"
+}
+
+// How to start an agent CLI. "argument" mode replaces "{prompt}" in the args, "file" mode
+// replaces "{prompt_file}" with a file holding the prompt, "stdin" pipes the prompt in
+export type PromptMode = 'argument' | 'file' | 'stdin';
+
+export interface AgentProfile {
+  name: string;
+  program: string;
+  args: string[];                // Interactive sessions
+  promptMode: PromptMode;
+  headlessArgs: string[];        // Headless runs, which must print the result and exit
+  headlessPromptMode: PromptMode;
+  env: Record<string, string>;
+}
+
+// One line of the app log
+export type LogLevel = 'ERROR' | 'WARN' | 'INFO' | 'DEBUG' | 'TRACE';
+
+export interface LogEntry {
+  timestamp: string;             // ISO 8601, UTC
+  level: LogLevel;
+  module: string;                // e.g. "runs", "github_sync", "frontend"
+  message: string;
+  project?: string;              // Path of the project the entry is about
 }
 
 // Priority Labels
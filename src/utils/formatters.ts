@@ -53,3 +53,20 @@ export function formatDateTime(date: Date | string): string {
 
   return format(dateObj, 'MMM d, yyyy \'at\' h:mm a');
 }
+
+/**
+ * Format a duration in seconds (e.g., "1h 5m", "3m 20s", "45s")
+ */
+export function formatDuration(totalSeconds: number): string {
+  const hours = Math.floor(totalSeconds / 3600);
+  const minutes = Math.floor((totalSeconds % 3600) / 60);
+  const seconds = totalSeconds % 60;
+
+  if (hours > 0) {
+    return `${hours}h ${minutes}m`;
+  }
+  if (minutes > 0) {
+    return `${minutes}m ${seconds}s`;
+  }
+  return `${seconds}s`;
+}